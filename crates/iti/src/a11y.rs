@@ -0,0 +1,160 @@
+//! Keyboard focus management.
+//!
+//! Helpers shared by components that move focus around, such as
+//! [`Modal`](crate::components::modal::Modal): finding focusable elements,
+//! keeping Tab inside a container with a [`FocusTrap`], and putting focus
+//! back where it was afterwards.
+
+use std::cell::{Cell, RefCell};
+
+use wasm_bindgen::JsCast;
+
+use crate::util::queue::QueuedListener;
+
+/// CSS selector matching the elements that can receive keyboard focus.
+pub const FOCUSABLE: &str = "a[href], button:not([disabled]), input:not([disabled]), \
+    select:not([disabled]), textarea:not([disabled]), [tabindex]:not([tabindex=\"-1\"])";
+
+/// Returns the focusable elements inside `container`, in document order.
+pub fn focusable_elements(container: &web_sys::Element) -> Vec<web_sys::HtmlElement> {
+    let Ok(nodes) = container.query_selector_all(FOCUSABLE) else {
+        return vec![];
+    };
+    (0..nodes.length())
+        .filter_map(|i| nodes.get(i)?.dyn_into::<web_sys::HtmlElement>().ok())
+        .collect()
+}
+
+/// Focus the first focusable element inside `container`.
+///
+/// Returns `false` if there is none.
+pub fn focus_first_focusable(container: &web_sys::Element) -> bool {
+    match focusable_elements(container).first() {
+        Some(el) => el.focus().is_ok(),
+        None => false,
+    }
+}
+
+/// Returns the element that has focus, if any.
+pub fn active_element() -> Option<web_sys::HtmlElement> {
+    web_sys::window()?
+        .document()?
+        .active_element()?
+        .dyn_into::<web_sys::HtmlElement>()
+        .ok()
+}
+
+/// Keep Tab and Shift+Tab cycling through the focusable elements of
+/// `container`.
+///
+/// Call this with every `keydown` event; anything but Tab is ignored.
+/// Returns `true` if focus was moved, in which case the event's default
+/// action has been prevented.
+pub fn trap_tab(container: &web_sys::Element, ev: &web_sys::KeyboardEvent) -> bool {
+    if ev.key() != "Tab" {
+        return false;
+    }
+    let elements = focusable_elements(container);
+    let (Some(first), Some(last)) = (elements.first(), elements.last()) else {
+        return false;
+    };
+    let active = container
+        .owner_document()
+        .and_then(|doc| doc.active_element());
+    let is_inside = container.contains(active.as_ref().map(|el| el.unchecked_ref()));
+    let is_at = |el: &web_sys::HtmlElement| {
+        active
+            .as_ref()
+            .is_some_and(|active| active == el.unchecked_ref::<web_sys::Element>())
+    };
+    let wrap_to = if ev.shift_key() {
+        (!is_inside || is_at(first)).then_some(last)
+    } else {
+        (!is_inside || is_at(last)).then_some(first)
+    };
+    match wrap_to {
+        Some(el) => {
+            ev.prevent_default();
+            let _ = el.focus();
+            true
+        }
+        None => false,
+    }
+}
+
+thread_local! {
+    /// Ids of the live focus traps, innermost last.
+    static TRAPS: RefCell<Vec<usize>> = const { RefCell::new(Vec::new()) };
+    static NEXT_TRAP_ID: Cell<usize> = const { Cell::new(0) };
+}
+
+/// Constrains Tab focus to an element while it lives.
+///
+/// Creating a trap remembers the element that had focus; dropping it
+/// focuses that element again.
+///
+/// The trap listens for Tab keypresses anywhere in the document from
+/// creation until it is dropped. When traps are nested, e.g. for a dialog
+/// opened from another dialog, only the most recently created one keeps
+/// focus. Await [`FocusTrap::step`] to learn when it moved focus.
+pub struct FocusTrap {
+    id: usize,
+    container: web_sys::Element,
+    restore_focus: Option<web_sys::HtmlElement>,
+    /// Tab keypresses that moved focus, if there is a document.
+    trapped: Option<QueuedListener<()>>,
+}
+
+impl FocusTrap {
+    /// Trap focus within `container`.
+    ///
+    /// Focus itself isn't moved; see [`focus_first_focusable`].
+    pub fn new(container: &web_sys::Element) -> Self {
+        let id = NEXT_TRAP_ID.with(|next| next.replace(next.get() + 1));
+        TRAPS.with_borrow_mut(|traps| traps.push(id));
+        let trapped = container.owner_document().map(|document| {
+            let container = container.clone();
+            QueuedListener::new(&document, &["keydown"], move |ev| {
+                let is_innermost = TRAPS.with_borrow(|traps| traps.last() == Some(&id));
+                let ev = ev.dyn_into::<web_sys::KeyboardEvent>().ok()?;
+                (is_innermost && trap_tab(&container, &ev)).then_some(())
+            })
+        });
+        Self {
+            id,
+            container: container.clone(),
+            restore_focus: active_element(),
+            trapped,
+        }
+    }
+
+    /// Returns the element focus returns to when the trap is dropped.
+    pub fn restore_focus(&self) -> Option<&web_sys::HtmlElement> {
+        self.restore_focus.as_ref()
+    }
+
+    /// Keep a Tab `keydown` event inside the container. See [`trap_tab`].
+    ///
+    /// The trap already does this for keys pressed in its document; this
+    /// is for events it can't hear, e.g. from another document.
+    pub fn trap(&self, ev: &web_sys::KeyboardEvent) -> bool {
+        trap_tab(&self.container, ev)
+    }
+
+    /// Await a Tab keypress that the trap kept inside the container.
+    pub async fn step(&self) {
+        match &self.trapped {
+            Some(trapped) => trapped.next().await,
+            None => std::future::pending().await,
+        }
+    }
+}
+
+impl Drop for FocusTrap {
+    fn drop(&mut self) {
+        TRAPS.with_borrow_mut(|traps| traps.retain(|id| *id != self.id));
+        if let Some(el) = self.restore_focus.take() {
+            let _ = el.focus();
+        }
+    }
+}
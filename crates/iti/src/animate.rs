@@ -0,0 +1,120 @@
+//! Awaitable CSS transitions.
+//!
+//! Each function transitions an element's inline style and resolves once
+//! the transition has ended, so components can show and hide content with
+//! consistent animations instead of flipping styles instantly:
+//!
+//! ```ignore
+//! // `el` is a `web_sys::HtmlElement`, hidden once this resolves.
+//! animate::fade_out(&el, animate::DURATION_MILLIS).await;
+//! ```
+//!
+//! [`fade_in`] and [`slide`] show an element, [`fade_out`] and [`collapse`]
+//! hide it with `display: none`.
+//!
+//! ## Note
+//! When the user prefers reduced motion the end state is applied
+//! immediately.
+
+use futures_lite::FutureExt;
+use mogwai::{future::MogwaiFutureExt, prelude::*, web::WebEvent};
+
+/// The default duration of an animation.
+pub const DURATION_MILLIS: u64 = 150;
+
+/// Extra time to wait for `transitionend` before giving up on it.
+///
+/// The event never fires if nothing was transitioned, e.g. because the
+/// element isn't rendered.
+const GRACE_MILLIS: u64 = 50;
+
+const PREFERS_REDUCED_MOTION: &str = "(prefers-reduced-motion: reduce)";
+
+/// Returns `true` if the user asked for less motion.
+pub fn prefers_reduced_motion() -> bool {
+    web_sys::window()
+        .and_then(|window| window.match_media(PREFERS_REDUCED_MOTION).ok()?)
+        .is_some_and(|query| query.matches())
+}
+
+/// Force the browser to apply the styles set so far, so a transition
+/// starts from them.
+fn reflow(el: &web_sys::HtmlElement) {
+    let _ = el.offset_height();
+}
+
+/// Transition `property` of `el` to `value` over `duration` milliseconds,
+/// resolving when it ends.
+async fn transition(el: &web_sys::HtmlElement, property: &str, value: &str, duration: u64) {
+    let style = el.style();
+    if duration == 0 || prefers_reduced_motion() {
+        let _ = style.set_property(property, value);
+        return;
+    }
+
+    reflow(el);
+    let _ = style.set_property("transition", &format!("{property} {duration}ms ease"));
+    let _ = style.set_property(property, value);
+
+    let own_target: &web_sys::EventTarget = el.as_ref();
+    let listener = el.listen("transitionend");
+    let ended = async {
+        loop {
+            // transitionend bubbles up from children.
+            let ev = listener.next().await;
+            let is_own = ev
+                .dyn_ev(|e: &web_sys::TransitionEvent| {
+                    e.property_name() == property && e.target().as_ref() == Some(own_target)
+                })
+                .unwrap_or(false);
+            if is_own {
+                break;
+            }
+        }
+    };
+    ended
+        .or(mogwai::time::wait_millis(duration + GRACE_MILLIS).map(|_| ()))
+        .await;
+    let _ = style.remove_property("transition");
+}
+
+/// Make `el` visible, fading in its opacity from zero.
+pub async fn fade_in(el: &web_sys::HtmlElement, duration: u64) {
+    let style = el.style();
+    let _ = style.set_property("opacity", "0");
+    let _ = style.remove_property("display");
+    transition(el, "opacity", "1", duration).await;
+    let _ = style.remove_property("opacity");
+}
+
+/// Fade the opacity of `el` to zero, then hide it.
+pub async fn fade_out(el: &web_sys::HtmlElement, duration: u64) {
+    let style = el.style();
+    transition(el, "opacity", "0", duration).await;
+    let _ = style.set_property("display", "none");
+    let _ = style.remove_property("opacity");
+}
+
+/// Make `el` visible, sliding it open from zero height.
+pub async fn slide(el: &web_sys::HtmlElement, duration: u64) {
+    let style = el.style();
+    let _ = style.remove_property("display");
+    let _ = style.set_property("overflow", "hidden");
+    let _ = style.set_property("height", "0");
+    let height = el.scroll_height();
+    transition(el, "height", &format!("{height}px"), duration).await;
+    let _ = style.remove_property("height");
+    let _ = style.remove_property("overflow");
+}
+
+/// Slide `el` closed to zero height, then hide it.
+pub async fn collapse(el: &web_sys::HtmlElement, duration: u64) {
+    let style = el.style();
+    let height = el.offset_height();
+    let _ = style.set_property("overflow", "hidden");
+    let _ = style.set_property("height", &format!("{height}px"));
+    transition(el, "height", "0", duration).await;
+    let _ = style.set_property("display", "none");
+    let _ = style.remove_property("height");
+    let _ = style.remove_property("overflow");
+}
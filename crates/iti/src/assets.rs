@@ -5,36 +5,141 @@
 //!
 //! 1. **CDN links** — [`inject_cdn_links`] adds a `<link>` tag for Font
 //!    Awesome from a public CDN and injects iti's CSS as a `<style>` tag.
-//!    Requires an internet connection for the icon font.
+//!    Requires an internet connection for the icon font. The stylesheet
+//!    is checked against its Subresource Integrity hash; use
+//!    [`inject_cdn_links_with`] to load it from a mirror instead.
 //!
 //! 2. **Fully embedded** — With the `embed-assets` feature enabled,
 //!    [`embedded::inject_styles`] injects all CSS and fonts directly from
 //!    the WASM binary. No network connection required. Fonts are served
 //!    via Blob URLs created at runtime from compiled-in woff2/ttf bytes.
+//!    Apps that need only some of the assets can enable a subset of the
+//!    `embed-*` features instead (see [`embedded`]).
 //!
 //! 3. **Manual / Trunk** — Consumers can ignore this module entirely and
 //!    wire up assets themselves (e.g. with Trunk `data-trunk` directives
 //!    or plain `<link>` tags in their `index.html`).
 //!
+//! For more control, [`Loader`] picks where each stylesheet comes from:
+//!
+//! ```ignore
+//! iti::assets::Loader::new()
+//!     .fontawesome(Source::Skip)
+//!     .bootstrap_icons(Source::Cdn)
+//!     .custom_css(APP_CSS)
+//!     .inject();
+//! ```
+//!
+//! Pages with a strict Content-Security-Policy can use the
+//! `_with_nonce` variants ([`inject_cdn_links_with_nonce`],
+//! [`embedded::inject_styles_with_nonce`]), which put the page's nonce on
+//! every injected element. The embedded fonts and images are Blob URLs,
+//! so the policy also needs `blob:` in `font-src` and `img-src`.
+//!
+//! [`ensure_styles`] injects the styles at most once and waits until
+//! they are usable, for apps that show their content only then:
+//!
+//! ```ignore
+//! if let Err(e) = iti::assets::ensure_styles().await {
+//!     log::error!("{e}");
+//! }
+//! ```
+//!
+//! Components mounted inside shadow DOM (e.g. when exported as custom
+//! elements) need their styles in the shadow root instead:
+//! [`inject_cdn_links_into`] and [`embedded::inject_styles_into`].
+//!
 //! The DOM-injection and Blob-URL primitives live in the
 //! [`mogwai-embed`](https://crates.io/crates/mogwai-embed) crate and are
 //! re-exported here for convenience.
 
+use std::sync::Once;
+
+use futures_lite::FutureExt;
+use mogwai::{future::MogwaiFutureExt, prelude::*, web::prelude::wasm_bindgen_futures::JsFuture};
+use snafu::prelude::*;
+use wasm_bindgen::{JsCast, UnwrapThrowExt};
+
+use crate::capabilities::js_message;
+
 pub use mogwai_embed::blob::create_blob_url;
-pub use mogwai_embed::head::{append_link, append_style};
+pub use mogwai_embed::head::{
+    append_link, append_link_with_integrity, append_link_with_nonce, append_style,
+    append_style_with_nonce,
+};
 
 /// iti's unified stylesheet (always embedded — includes all component styles).
 pub const ITI_CSS: &str = include_str!("../../../assets/iti.css");
 
 /// CDN URLs for external dependencies.
 ///
-/// Only Font Awesome is loaded from a CDN. All other styles are provided
+/// Only icon fonts are loaded from a CDN. All other styles are provided
 /// by `iti.css`.
 pub mod cdn {
     /// Font Awesome 6.6.0 Free — all styles (includes `@font-face` for
     /// Solid, Regular, and Brands webfonts).
     pub const FONTAWESOME_CSS: &str =
         "https://cdnjs.cloudflare.com/ajax/libs/font-awesome/6.6.0/css/all.min.css";
+
+    /// Subresource Integrity hash of [`FONTAWESOME_CSS`].
+    pub const FONTAWESOME_CSS_INTEGRITY: &str =
+        "sha512-Kc323vGBEqzTmouAECnVceyQqyqdsSiqLQISBL29aUW4U/M7pSPA/gEUZQqv1cwx4OnYxTxve5UMg5GT6L4JJg==";
+
+    /// Bootstrap Icons 1.11.3 (includes `@font-face` for its webfont).
+    ///
+    /// Not loaded by [`inject_cdn_links`](super::inject_cdn_links); append
+    /// it yourself when using
+    /// [`IconSource::Bootstrap`](crate::components::icon::IconSource).
+    pub const BOOTSTRAP_ICONS_CSS: &str =
+        "https://cdn.jsdelivr.net/npm/bootstrap-icons@1.11.3/font/bootstrap-icons.min.css";
+}
+
+/// Where [`inject_cdn_links_with`] loads Font Awesome from, and the
+/// Subresource Integrity hash the browser checks it against.
+///
+/// Defaults to [`cdn::FONTAWESOME_CSS`] and its pinned hash.
+#[derive(Clone, Debug, PartialEq)]
+pub struct SriConfig {
+    fontawesome_href: String,
+    fontawesome_integrity: Option<String>,
+}
+
+impl Default for SriConfig {
+    fn default() -> Self {
+        Self {
+            fontawesome_href: cdn::FONTAWESOME_CSS.to_string(),
+            fontawesome_integrity: Some(cdn::FONTAWESOME_CSS_INTEGRITY.to_string()),
+        }
+    }
+}
+
+impl SriConfig {
+    /// Load Font Awesome from `href`, e.g. a self-hosted mirror, checked
+    /// against `integrity` (`"sha384-..."`). `None` skips the check.
+    pub fn with_fontawesome(mut self, href: impl Into<String>, integrity: Option<&str>) -> Self {
+        self.fontawesome_href = href.into();
+        self.fontawesome_integrity = integrity.map(str::to_string);
+        self
+    }
+
+    pub fn fontawesome_href(&self) -> &str {
+        &self.fontawesome_href
+    }
+
+    pub fn fontawesome_integrity(&self) -> Option<&str> {
+        self.fontawesome_integrity.as_deref()
+    }
+
+    /// Append the Font Awesome `<link>` to `<head>`.
+    fn append_fontawesome_link(&self, nonce: Option<&str>) {
+        match (self.fontawesome_integrity(), nonce) {
+            (Some(integrity), nonce) => {
+                append_link_with_integrity(&self.fontawesome_href, integrity, nonce)
+            }
+            (None, Some(nonce)) => append_link_with_nonce(&self.fontawesome_href, nonce),
+            (None, None) => append_link(&self.fontawesome_href),
+        }
+    }
 }
 
 /// Inject the design token CSS custom properties as a `<style>` element.
@@ -50,6 +155,11 @@ pub fn inject_color_tokens() {
     append_style(&crate::color::css_tokens());
 }
 
+/// Like [`inject_color_tokens`], with a CSP `nonce` on the `<style>`.
+pub fn inject_color_tokens_with_nonce(nonce: &str) {
+    append_style_with_nonce(&crate::color::css_tokens(), nonce);
+}
+
 /// Inject all required stylesheets using a CDN `<link>` for Font Awesome.
 ///
 /// Creates three elements in `<head>`:
@@ -59,11 +169,372 @@ pub fn inject_color_tokens() {
 ///
 /// Requires an internet connection to reach the Font Awesome CDN.
 pub fn inject_cdn_links() {
+    inject_cdn_links_with(&SriConfig::default());
+}
+
+/// Like [`inject_cdn_links`], loading Font Awesome as set in `config`.
+pub fn inject_cdn_links_with(config: &SriConfig) {
     inject_color_tokens();
-    append_link(cdn::FONTAWESOME_CSS);
+    config.append_fontawesome_link(None);
     append_style(ITI_CSS);
 }
 
+/// Like [`inject_cdn_links`], with a CSP `nonce` on every injected
+/// element.
+///
+/// The policy must also allow the CDN's origin in `style-src` and
+/// `font-src`.
+pub fn inject_cdn_links_with_nonce(nonce: &str) {
+    inject_color_tokens_with_nonce(nonce);
+    SriConfig::default().append_fontawesome_link(Some(nonce));
+    append_style_with_nonce(ITI_CSS, nonce);
+}
+
+/// Where [`Loader`] loads a stylesheet from.
+#[derive(Clone, Debug, PartialEq)]
+pub enum Source {
+    /// The pinned public CDN URL, checked against its Subresource
+    /// Integrity hash.
+    Cdn,
+    /// Compiled into the binary. Needs the `embed-css` feature; without
+    /// it, or for assets iti does not embed, [`Source::Cdn`] is used.
+    Embedded,
+    /// Your own URL, e.g. a self-hosted mirror, optionally checked against
+    /// an integrity hash (`"sha384-..."`).
+    Url {
+        href: String,
+        integrity: Option<String>,
+    },
+    /// Not loaded.
+    Skip,
+}
+
+/// Injects iti's styles, with a choice of where each stylesheet comes from.
+///
+/// iti.css and the color tokens are always injected, from the binary.
+/// By default Font Awesome is [`Source::Embedded`] with the `embed-css`
+/// feature and [`Source::Cdn`] without it, and Bootstrap Icons are
+/// skipped. Custom CSS is injected last, so it takes precedence.
+#[derive(Clone, Debug)]
+pub struct Loader {
+    fontawesome: Source,
+    bootstrap_icons: Source,
+    custom_css: Vec<String>,
+    nonce: Option<String>,
+}
+
+impl Default for Loader {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Loader {
+    pub fn new() -> Self {
+        Self {
+            fontawesome: if cfg!(feature = "embed-css") {
+                Source::Embedded
+            } else {
+                Source::Cdn
+            },
+            bootstrap_icons: Source::Skip,
+            custom_css: vec![],
+            nonce: None,
+        }
+    }
+
+    /// Set where Font Awesome is loaded from. Use [`Source::Skip`] for
+    /// apps that draw all their icons from Bootstrap Icons.
+    pub fn fontawesome(mut self, source: Source) -> Self {
+        self.fontawesome = source;
+        self
+    }
+
+    /// Set where Bootstrap Icons are loaded from, for
+    /// [`IconSource::Bootstrap`](crate::components::icon::IconSource).
+    ///
+    /// ## Note
+    /// Bootstrap Icons are not embedded, so [`Source::Embedded`] loads
+    /// them from the CDN.
+    pub fn bootstrap_icons(mut self, source: Source) -> Self {
+        self.bootstrap_icons = source;
+        self
+    }
+
+    /// Add a stylesheet of your own, injected after iti's.
+    pub fn custom_css(mut self, css: impl Into<String>) -> Self {
+        self.custom_css.push(css.into());
+        self
+    }
+
+    /// Put a CSP `nonce` on every injected element.
+    pub fn nonce(mut self, nonce: impl Into<String>) -> Self {
+        self.nonce = Some(nonce.into());
+        self
+    }
+
+    fn append_style(&self, css: &str) {
+        match &self.nonce {
+            Some(nonce) => append_style_with_nonce(css, nonce),
+            None => append_style(css),
+        }
+    }
+
+    fn append_link(&self, href: &str, integrity: Option<&str>) {
+        match (integrity, &self.nonce) {
+            (Some(integrity), nonce) => {
+                append_link_with_integrity(href, integrity, nonce.as_deref())
+            }
+            (None, Some(nonce)) => append_link_with_nonce(href, nonce),
+            (None, None) => append_link(href),
+        }
+    }
+
+    /// Inject the stylesheets into `<head>`.
+    pub fn inject(&self) {
+        match &self.nonce {
+            Some(nonce) => inject_color_tokens_with_nonce(nonce),
+            None => inject_color_tokens(),
+        }
+
+        #[cfg(feature = "embed-css")]
+        self.append_style(&embedded::embedded_css().0);
+        #[cfg(not(feature = "embed-css"))]
+        self.append_style(ITI_CSS);
+
+        match &self.fontawesome {
+            #[cfg(feature = "embed-css")]
+            Source::Embedded => self.append_style(&embedded::embedded_css().1),
+            #[cfg(not(feature = "embed-css"))]
+            Source::Embedded => {
+                self.append_link(cdn::FONTAWESOME_CSS, Some(cdn::FONTAWESOME_CSS_INTEGRITY))
+            }
+            Source::Cdn => {
+                self.append_link(cdn::FONTAWESOME_CSS, Some(cdn::FONTAWESOME_CSS_INTEGRITY))
+            }
+            Source::Url { href, integrity } => self.append_link(href, integrity.as_deref()),
+            Source::Skip => {}
+        }
+
+        match &self.bootstrap_icons {
+            Source::Cdn | Source::Embedded => self.append_link(cdn::BOOTSTRAP_ICONS_CSS, None),
+            Source::Url { href, integrity } => self.append_link(href, integrity.as_deref()),
+            Source::Skip => {}
+        }
+
+        for css in self.custom_css.iter() {
+            self.append_style(css);
+        }
+    }
+}
+
+/// Inject all required stylesheets into a shadow root, using a CDN
+/// `<link>` for Font Awesome.
+///
+/// Like [`inject_cdn_links`], but the component styles are added to
+/// `root` so they only apply inside it. Call it once per shadow root.
+///
+/// ## Note
+/// Browsers ignore `@font-face` and `:root` rules inside shadow roots,
+/// so those (along with the color tokens and Font Awesome's `<link>`)
+/// are added to the document `<head>` as well, once. Custom properties
+/// inherit into shadow roots, so the tokens still apply.
+pub fn inject_cdn_links_into(root: &web_sys::ShadowRoot) {
+    let config = SriConfig::default();
+    let (document_css, scoped_css) = split_document_rules(ITI_CSS);
+    static DOCUMENT: Once = Once::new();
+    DOCUMENT.call_once(|| {
+        inject_color_tokens();
+        config.append_fontawesome_link(None);
+        append_style(&document_css);
+    });
+    append_link_into(root, &config);
+    append_style_into(root, &scoped_css);
+}
+
+/// Append the Font Awesome `<link rel="stylesheet">` element to a shadow
+/// root.
+fn append_link_into(root: &web_sys::ShadowRoot, config: &SriConfig) {
+    let document = web_sys::window().unwrap_throw().document().unwrap_throw();
+    let link = document
+        .create_element("link")
+        .unwrap_throw()
+        .unchecked_into::<web_sys::HtmlLinkElement>();
+    if let Some(integrity) = config.fontawesome_integrity() {
+        link.set_integrity(integrity);
+        link.set_cross_origin(Some("anonymous"));
+    }
+    link.set_rel("stylesheet");
+    link.set_href(config.fontawesome_href());
+    root.append_child(&link).unwrap_throw();
+}
+
+/// Append a `<style>` element with the given CSS text to a shadow root.
+fn append_style_into(root: &web_sys::ShadowRoot, css: &str) {
+    let document = web_sys::window().unwrap_throw().document().unwrap_throw();
+    let style = document.create_element("style").unwrap_throw();
+    style.set_text_content(Some(css));
+    root.append_child(&style).unwrap_throw();
+}
+
+/// Split `css` into the rules that only take effect in the document —
+/// `@font-face` and `:root` rules — and everything else.
+fn split_document_rules(css: &str) -> (String, String) {
+    let bytes = css.as_bytes();
+    let mut document_css = String::new();
+    let mut scoped_css = String::new();
+    let mut depth = 0;
+    let mut start = 0;
+    let mut i = 0;
+    while i < bytes.len() {
+        match bytes[i] {
+            // Skip comments, which may contain braces
+            b'/' if bytes.get(i + 1) == Some(&b'*') => {
+                i = css[i + 2..]
+                    .find("*/")
+                    .map_or(bytes.len(), |end| i + 2 + end + 2);
+                continue;
+            }
+            b'{' => depth += 1,
+            b'}' if depth > 0 => {
+                depth -= 1;
+                if depth == 0 {
+                    let rule = &css[start..=i];
+                    // The selector follows any comments before the rule
+                    let prelude = rule[..rule.find('{').unwrap_or(0)]
+                        .rsplit("*/")
+                        .next()
+                        .unwrap_or_default()
+                        .trim();
+                    if prelude.starts_with("@font-face") || prelude.starts_with(":root") {
+                        document_css.push_str(rule);
+                        document_css.push('\n');
+                    } else {
+                        scoped_css.push_str(rule);
+                    }
+                    start = i + 1;
+                }
+            }
+            _ => {}
+        }
+        i += 1;
+    }
+    scoped_css.push_str(&css[start..]);
+    (document_css, scoped_css)
+}
+
+/// Asset loading errors.
+#[derive(Debug, Snafu)]
+pub enum Error {
+    #[snafu(display("DOM API unavailable"))]
+    NoDocument,
+    #[snafu(display("Could not load the stylesheet '{href}'"))]
+    Stylesheet { href: String },
+    #[snafu(display("Could not load the font '{font}': {message}"))]
+    Font { font: String, message: String },
+}
+
+/// Attribute set on `<html>` once iti's styles are injected, so that they
+/// are injected only once, even by different copies of iti on the page.
+/// Set to `"failed"` if the Font Awesome stylesheet fails to load.
+const INJECTED_ATTRIBUTE: &str = "data-iti-styles";
+
+/// Fonts that [`ensure_styles`] waits for, as CSS `font` shorthands.
+fn required_fonts() -> Vec<&'static str> {
+    let embedded = cfg!(feature = "embed-css");
+    let mut fonts = vec![];
+    if !embedded || cfg!(feature = "embed-platinum-fonts") {
+        fonts.push("1em ChicagoFLF");
+    }
+    if !embedded || cfg!(feature = "embed-solid") {
+        fonts.push("900 1em \"Font Awesome 6 Free\"");
+    }
+    if !embedded || cfg!(feature = "embed-regular") {
+        fonts.push("400 1em \"Font Awesome 6 Free\"");
+    }
+    fonts
+}
+
+/// Inject iti's styles, unless they already are, and wait until they are
+/// usable.
+///
+/// Uses [`embedded::inject_styles`] with the `embed-css` feature and
+/// [`inject_cdn_links`] without it. Resolves once the Font Awesome
+/// stylesheet (when loaded from the CDN) and iti's fonts have loaded, so
+/// content can be shown without unstyled text or blank icons. Returns an
+/// error if there is no document, or if the stylesheet or a font fails to
+/// load.
+///
+/// Safe to call from every component that needs the styles: only the
+/// first call injects them, and every call waits for them.
+///
+/// ## Note
+/// With the embedded assets, fonts whose `embed-*` feature is disabled
+/// are not waited for.
+pub async fn ensure_styles() -> Result<(), Error> {
+    let document = web_sys::window()
+        .and_then(|w| w.document())
+        .context(NoDocumentSnafu)?;
+    let html = document.document_element().context(NoDocumentSnafu)?;
+    match html.get_attribute(INJECTED_ATTRIBUTE).as_deref() {
+        None => {
+            let _ = html.set_attribute(INJECTED_ATTRIBUTE, "");
+            #[cfg(feature = "embed-css")]
+            embedded::inject_styles();
+            #[cfg(not(feature = "embed-css"))]
+            inject_cdn_links();
+        }
+        Some("failed") => {
+            return StylesheetSnafu {
+                href: cdn::FONTAWESOME_CSS,
+            }
+            .fail();
+        }
+        Some(_) => {}
+    }
+
+    if !cfg!(feature = "embed-css") {
+        let link = document
+            .query_selector(&format!("link[href=\"{}\"]", cdn::FONTAWESOME_CSS))
+            .ok()
+            .flatten()
+            .and_then(|el| el.dyn_into::<web_sys::HtmlLinkElement>().ok());
+        if let Some(link) = link {
+            if !stylesheet_loaded(&link).await {
+                let _ = html.set_attribute(INJECTED_ATTRIBUTE, "failed");
+                return StylesheetSnafu { href: link.href() }.fail();
+            }
+        }
+    }
+
+    let fonts = document.fonts();
+    for font in required_fonts() {
+        JsFuture::from(fonts.load(font))
+            .await
+            .map_err(|e| Error::Font {
+                font: font.to_string(),
+                message: js_message(&e),
+            })?;
+    }
+    if let Ok(ready) = fonts.ready() {
+        let _ = JsFuture::from(ready).await;
+    }
+    Ok(())
+}
+
+/// Wait for `link`'s stylesheet to load. Returns `false` if it failed.
+async fn stylesheet_loaded(link: &web_sys::HtmlLinkElement) -> bool {
+    if link.sheet().is_some() {
+        return true;
+    }
+    let load = link.listen("load");
+    let error = link.listen("error");
+    load.next()
+        .map(|_| true)
+        .or(error.next().map(|_| false))
+        .await
+}
+
 /// Fully embedded assets — available when the `embed-assets` feature is
 /// enabled.
 ///
@@ -76,8 +547,31 @@ pub fn inject_cdn_links() {
 /// Only woff2 fonts are included for Font Awesome — all WASM-capable
 /// browsers support woff2. Font Awesome Brands icons are excluded to
 /// save space; only Solid, Regular, and v4-compatibility fonts are
-/// embedded.
-#[cfg(feature = "embed-assets")]
+/// embedded. Enable the `embed-brands` feature to embed the Brands font
+/// too (about 115 KB more).
+///
+/// ## Choosing assets
+/// `embed-assets` turns on every feature below except `embed-brands`.
+/// To embed less, disable default features and pick from:
+///
+/// | Feature                | Embeds                                       | Size    |
+/// |------------------------|----------------------------------------------|---------|
+/// | `embed-css`            | iti and Font Awesome CSS, iti's SVGs         | ~200 KB |
+/// | `embed-solid`          | Font Awesome Solid font                      | ~155 KB |
+/// | `embed-regular`        | Font Awesome Regular font                    | ~25 KB  |
+/// | `embed-v4compat`       | Font Awesome v4 compatibility font           | ~5 KB   |
+/// | `embed-brands`         | Font Awesome Brands font                     | ~115 KB |
+/// | `embed-platinum-fonts` | Chicago and Apple Garamond                   | ~190 KB |
+/// | `embed-classic-icons`  | [`IconClassic`] PNGs                         | ~620 KB |
+///
+/// Every feature implies `embed-css`, which provides this module. A font
+/// that is not embedded keeps its relative `@font-face` URL
+/// (`../webfonts/...` or `fonts/...`), so it can still be served
+/// alongside the app. Without `embed-classic-icons`, [`IconClassic`]
+/// loads its images from `/icons-classic/`.
+///
+/// [`IconClassic`]: crate::components::icon_classic::IconClassic
+#[cfg(feature = "embed-css")]
 pub mod embedded {
     use mogwai_embed::blob::AssetRegistry;
     use std::sync::OnceLock;
@@ -94,17 +588,27 @@ pub mod embedded {
 
     // ── Fonts (woff2) ──────────────────────────────────────
 
+    #[cfg(feature = "embed-solid")]
     const FA_SOLID_WOFF2: &[u8] =
         include_bytes!("../../../assets/fontawesome/webfonts/fa-solid-900.woff2");
+    #[cfg(feature = "embed-regular")]
     const FA_REGULAR_WOFF2: &[u8] =
         include_bytes!("../../../assets/fontawesome/webfonts/fa-regular-400.woff2");
+    #[cfg(feature = "embed-v4compat")]
     const FA_V4COMPAT_WOFF2: &[u8] =
         include_bytes!("../../../assets/fontawesome/webfonts/fa-v4compatibility.woff2");
+    #[cfg(feature = "embed-brands")]
+    const FA_BRANDS_WOFF2: &[u8] =
+        include_bytes!("../../../assets/fontawesome/webfonts/fa-brands-400.woff2");
     // -- Fonts (ttf)
+    #[cfg(feature = "embed-platinum-fonts")]
     const CHICAGO_TTF: &[u8] = include_bytes!("../../../assets/fonts/ChicagoFLF.ttf");
+    #[cfg(feature = "embed-platinum-fonts")]
     const GARAMOND_LIGHT_TTF: &[u8] =
         include_bytes!("../../../assets/fonts/AppleGaramond-Light.ttf");
+    #[cfg(feature = "embed-platinum-fonts")]
     const GARAMOND_REGULAR_TTF: &[u8] = include_bytes!("../../../assets/fonts/AppleGaramond.ttf");
+    #[cfg(feature = "embed-platinum-fonts")]
     const GARAMOND_BOLD_TTF: &[u8] = include_bytes!("../../../assets/fonts/AppleGaramond-Bold.ttf");
 
     // ── SVG assets ──────────────────────────────────────────────
@@ -132,306 +636,457 @@ pub mod embedded {
     const SVG_TABLE_SORT_DESC: &[u8] = include_bytes!("../../../assets/svg/table-sort-desc.svg");
 
     // ── Classic Mac OS Icons (149 PNGs) ──
+    //
+    // Only embedded with the `embed-classic-icons` feature.
 
     // APPLICATIONS
+    #[cfg(feature = "embed-classic-icons")]
     const APPLICATIONS_ADOBE_ILLUSTRATOR_5_5: &[u8] =
         include_bytes!("../../../assets/icons-classic/applications/Adobe Illustrator 5.5.png");
+    #[cfg(feature = "embed-classic-icons")]
     const APPLICATIONS_ADOBE_PHOTOSHOP_5_0: &[u8] =
         include_bytes!("../../../assets/icons-classic/applications/Adobe Photoshop 5.0.png");
+    #[cfg(feature = "embed-classic-icons")]
     const APPLICATIONS_APPLE_FM_RADIO: &[u8] =
         include_bytes!("../../../assets/icons-classic/applications/Apple FM radio.png");
+    #[cfg(feature = "embed-classic-icons")]
     const APPLICATIONS_APPLE_FILE_SECURITY: &[u8] =
         include_bytes!("../../../assets/icons-classic/applications/Apple File security.png");
+    #[cfg(feature = "embed-classic-icons")]
     const APPLICATIONS_APPLE_SHARE_PREP: &[u8] =
         include_bytes!("../../../assets/icons-classic/applications/Apple Share Prep.png");
+    #[cfg(feature = "embed-classic-icons")]
     const APPLICATIONS_APPLE_VERIFIER: &[u8] =
         include_bytes!("../../../assets/icons-classic/applications/Apple verifier.png");
+    #[cfg(feature = "embed-classic-icons")]
     const APPLICATIONS_CALCULATOR: &[u8] =
         include_bytes!("../../../assets/icons-classic/applications/Calculator.png");
+    #[cfg(feature = "embed-classic-icons")]
     const APPLICATIONS_DISK_COPY: &[u8] =
         include_bytes!("../../../assets/icons-classic/applications/Disk copy.png");
+    #[cfg(feature = "embed-classic-icons")]
     const APPLICATIONS_DISK_FIRST_AID: &[u8] =
         include_bytes!("../../../assets/icons-classic/applications/Disk first aid.png");
+    #[cfg(feature = "embed-classic-icons")]
     const APPLICATIONS_GRAPHING_CALCULATOR: &[u8] =
         include_bytes!("../../../assets/icons-classic/applications/Graphing calculator.png");
+    #[cfg(feature = "embed-classic-icons")]
     const APPLICATIONS_KEY_CAPS: &[u8] =
         include_bytes!("../../../assets/icons-classic/applications/Key caps.png");
+    #[cfg(feature = "embed-classic-icons")]
     const APPLICATIONS_MAIL: &[u8] =
         include_bytes!("../../../assets/icons-classic/applications/Mail.png");
+    #[cfg(feature = "embed-classic-icons")]
     const APPLICATIONS_MICROSOFT_INTERNET_EXPLORER: &[u8] = include_bytes!(
         "../../../assets/icons-classic/applications/Microsoft Internet Explorer.png"
     );
+    #[cfg(feature = "embed-classic-icons")]
     const APPLICATIONS_NOTEPAD: &[u8] =
         include_bytes!("../../../assets/icons-classic/applications/Notepad.png");
+    #[cfg(feature = "embed-classic-icons")]
     const APPLICATIONS_QUICKTIME_PLAYER: &[u8] =
         include_bytes!("../../../assets/icons-classic/applications/Quicktime Player.png");
+    #[cfg(feature = "embed-classic-icons")]
     const APPLICATIONS_SCRAPBOOK: &[u8] =
         include_bytes!("../../../assets/icons-classic/applications/Scrapbook.png");
+    #[cfg(feature = "embed-classic-icons")]
     const APPLICATIONS_SCRIPT_EDITOR: &[u8] =
         include_bytes!("../../../assets/icons-classic/applications/Script editor.png");
+    #[cfg(feature = "embed-classic-icons")]
     const APPLICATIONS_SHERLOCK_2_0: &[u8] =
         include_bytes!("../../../assets/icons-classic/applications/Sherlock 2.0.png");
+    #[cfg(feature = "embed-classic-icons")]
     const APPLICATIONS_STICKIES: &[u8] =
         include_bytes!("../../../assets/icons-classic/applications/Stickies.png");
+    #[cfg(feature = "embed-classic-icons")]
     const APPLICATIONS_STUFFIT_EXPANDER: &[u8] =
         include_bytes!("../../../assets/icons-classic/applications/Stuffit expander.png");
+    #[cfg(feature = "embed-classic-icons")]
     const APPLICATIONS_WEBSITE: &[u8] =
         include_bytes!("../../../assets/icons-classic/applications/Website.png");
+    #[cfg(feature = "embed-classic-icons")]
     const APPLICATIONS_ITUNES: &[u8] =
         include_bytes!("../../../assets/icons-classic/applications/iTunes.png");
 
     // CONTROL-PANEL
+    #[cfg(feature = "embed-classic-icons")]
     const CONTROL_PANEL_ADOBE_GAMMA: &[u8] =
         include_bytes!("../../../assets/icons-classic/control-panel/Adobe gamma.png");
+    #[cfg(feature = "embed-classic-icons")]
     const CONTROL_PANEL_APPEARANCE: &[u8] =
         include_bytes!("../../../assets/icons-classic/control-panel/Appearance.png");
+    #[cfg(feature = "embed-classic-icons")]
     const CONTROL_PANEL_APPLE_MENU_OPTIONS: &[u8] =
         include_bytes!("../../../assets/icons-classic/control-panel/Apple menu options.png");
+    #[cfg(feature = "embed-classic-icons")]
     const CONTROL_PANEL_APPLETALK: &[u8] =
         include_bytes!("../../../assets/icons-classic/control-panel/Appletalk.png");
+    #[cfg(feature = "embed-classic-icons")]
     const CONTROL_PANEL_ATM: &[u8] =
         include_bytes!("../../../assets/icons-classic/control-panel/Atm.png");
+    #[cfg(feature = "embed-classic-icons")]
     const CONTROL_PANEL_COLORSYNC: &[u8] =
         include_bytes!("../../../assets/icons-classic/control-panel/Colorsync.png");
+    #[cfg(feature = "embed-classic-icons")]
     const CONTROL_PANEL_CONTROL_STRIP: &[u8] =
         include_bytes!("../../../assets/icons-classic/control-panel/Control strip.png");
+    #[cfg(feature = "embed-classic-icons")]
     const CONTROL_PANEL_DATE_AND_TIME: &[u8] =
         include_bytes!("../../../assets/icons-classic/control-panel/Date and time.png");
+    #[cfg(feature = "embed-classic-icons")]
     const CONTROL_PANEL_DIAL_ASSIST: &[u8] =
         include_bytes!("../../../assets/icons-classic/control-panel/Dial assist.png");
+    #[cfg(feature = "embed-classic-icons")]
     const CONTROL_PANEL_ENERGY_SAVER: &[u8] =
         include_bytes!("../../../assets/icons-classic/control-panel/Energy saver.png");
+    #[cfg(feature = "embed-classic-icons")]
     const CONTROL_PANEL_EXTENSIONS_MANAGER: &[u8] =
         include_bytes!("../../../assets/icons-classic/control-panel/Extensions manager.png");
+    #[cfg(feature = "embed-classic-icons")]
     const CONTROL_PANEL_FILE_EXCHANGE: &[u8] =
         include_bytes!("../../../assets/icons-classic/control-panel/File exchange.png");
+    #[cfg(feature = "embed-classic-icons")]
     const CONTROL_PANEL_FILE_SHARING: &[u8] =
         include_bytes!("../../../assets/icons-classic/control-panel/File sharing.png");
+    #[cfg(feature = "embed-classic-icons")]
     const CONTROL_PANEL_GENERAL_CONTROLS: &[u8] =
         include_bytes!("../../../assets/icons-classic/control-panel/General controls.png");
+    #[cfg(feature = "embed-classic-icons")]
     const CONTROL_PANEL_INTERNET: &[u8] =
         include_bytes!("../../../assets/icons-classic/control-panel/Internet.png");
+    #[cfg(feature = "embed-classic-icons")]
     const CONTROL_PANEL_KEYBOARD: &[u8] =
         include_bytes!("../../../assets/icons-classic/control-panel/Keyboard.png");
+    #[cfg(feature = "embed-classic-icons")]
     const CONTROL_PANEL_KEYCHAIN_ACCESS: &[u8] =
         include_bytes!("../../../assets/icons-classic/control-panel/Keychain access.png");
+    #[cfg(feature = "embed-classic-icons")]
     const CONTROL_PANEL_LAUNCHER: &[u8] =
         include_bytes!("../../../assets/icons-classic/control-panel/Launcher.png");
+    #[cfg(feature = "embed-classic-icons")]
     const CONTROL_PANEL_LOCATION_MANAGER: &[u8] =
         include_bytes!("../../../assets/icons-classic/control-panel/Location manager.png");
+    #[cfg(feature = "embed-classic-icons")]
     const CONTROL_PANEL_MEMORY: &[u8] =
         include_bytes!("../../../assets/icons-classic/control-panel/Memory.png");
+    #[cfg(feature = "embed-classic-icons")]
     const CONTROL_PANEL_MODEM: &[u8] =
         include_bytes!("../../../assets/icons-classic/control-panel/Modem.png");
+    #[cfg(feature = "embed-classic-icons")]
     const CONTROL_PANEL_MONITOR: &[u8] =
         include_bytes!("../../../assets/icons-classic/control-panel/Monitor.png");
+    #[cfg(feature = "embed-classic-icons")]
     const CONTROL_PANEL_MOUSE: &[u8] =
         include_bytes!("../../../assets/icons-classic/control-panel/Mouse.png");
+    #[cfg(feature = "embed-classic-icons")]
     const CONTROL_PANEL_MULTIPLE_USERS: &[u8] =
         include_bytes!("../../../assets/icons-classic/control-panel/Multiple users.png");
+    #[cfg(feature = "embed-classic-icons")]
     const CONTROL_PANEL_NUMBERS: &[u8] =
         include_bytes!("../../../assets/icons-classic/control-panel/Numbers.png");
+    #[cfg(feature = "embed-classic-icons")]
     const CONTROL_PANEL_QUICKTIME_SETTINGS: &[u8] =
         include_bytes!("../../../assets/icons-classic/control-panel/Quicktime settings.png");
+    #[cfg(feature = "embed-classic-icons")]
     const CONTROL_PANEL_REMOTE_ACCESS: &[u8] =
         include_bytes!("../../../assets/icons-classic/control-panel/Remote access.png");
+    #[cfg(feature = "embed-classic-icons")]
     const CONTROL_PANEL_SOFTWARE_UPDATE: &[u8] =
         include_bytes!("../../../assets/icons-classic/control-panel/Software update.png");
+    #[cfg(feature = "embed-classic-icons")]
     const CONTROL_PANEL_SOUND: &[u8] =
         include_bytes!("../../../assets/icons-classic/control-panel/Sound.png");
+    #[cfg(feature = "embed-classic-icons")]
     const CONTROL_PANEL_SPEECH: &[u8] =
         include_bytes!("../../../assets/icons-classic/control-panel/Speech.png");
+    #[cfg(feature = "embed-classic-icons")]
     const CONTROL_PANEL_STARTUP_DISK: &[u8] =
         include_bytes!("../../../assets/icons-classic/control-panel/Startup disk.png");
+    #[cfg(feature = "embed-classic-icons")]
     const CONTROL_PANEL_TCPIP: &[u8] =
         include_bytes!("../../../assets/icons-classic/control-panel/TCPIP.png");
+    #[cfg(feature = "embed-classic-icons")]
     const CONTROL_PANEL_TEXT: &[u8] =
         include_bytes!("../../../assets/icons-classic/control-panel/Text.png");
+    #[cfg(feature = "embed-classic-icons")]
     const CONTROL_PANEL_WEB_SHARING: &[u8] =
         include_bytes!("../../../assets/icons-classic/control-panel/Web sharing.png");
 
     // CONTROL-STRIP
+    #[cfg(feature = "embed-classic-icons")]
     const CONTROL_STRIP_APPLE_LOCATION: &[u8] =
         include_bytes!("../../../assets/icons-classic/control-strip/Apple location.png");
+    #[cfg(feature = "embed-classic-icons")]
     const CONTROL_STRIP_APPLE_TALK: &[u8] =
         include_bytes!("../../../assets/icons-classic/control-strip/Apple talk.png");
+    #[cfg(feature = "embed-classic-icons")]
     const CONTROL_STRIP_CD: &[u8] =
         include_bytes!("../../../assets/icons-classic/control-strip/CD.png");
+    #[cfg(feature = "embed-classic-icons")]
     const CONTROL_STRIP_FILE_SHARING: &[u8] =
         include_bytes!("../../../assets/icons-classic/control-strip/File sharing.png");
+    #[cfg(feature = "embed-classic-icons")]
     const CONTROL_STRIP_KEYCHAIN_STRIP: &[u8] =
         include_bytes!("../../../assets/icons-classic/control-strip/Keychain strip.png");
+    #[cfg(feature = "embed-classic-icons")]
     const CONTROL_STRIP_MONITOR_BITDEPTH: &[u8] =
         include_bytes!("../../../assets/icons-classic/control-strip/Monitor bitdepth.png");
+    #[cfg(feature = "embed-classic-icons")]
     const CONTROL_STRIP_MONITOR_RESOLUTION: &[u8] =
         include_bytes!("../../../assets/icons-classic/control-strip/Monitor resolution.png");
+    #[cfg(feature = "embed-classic-icons")]
     const CONTROL_STRIP_PRINTER: &[u8] =
         include_bytes!("../../../assets/icons-classic/control-strip/Printer.png");
+    #[cfg(feature = "embed-classic-icons")]
     const CONTROL_STRIP_REMOTE_ACCESS: &[u8] =
         include_bytes!("../../../assets/icons-classic/control-strip/Remote access.png");
+    #[cfg(feature = "embed-classic-icons")]
     const CONTROL_STRIP_SOUND_VOLUME: &[u8] =
         include_bytes!("../../../assets/icons-classic/control-strip/Sound volume.png");
+    #[cfg(feature = "embed-classic-icons")]
     const CONTROL_STRIP_WEB_SHARING: &[u8] =
         include_bytes!("../../../assets/icons-classic/control-strip/Web sharing.png");
+    #[cfg(feature = "embed-classic-icons")]
     const CONTROL_STRIP_ITUNES: &[u8] =
         include_bytes!("../../../assets/icons-classic/control-strip/iTunes.png");
 
     // FOLDER
+    #[cfg(feature = "embed-classic-icons")]
     const FOLDER_APPLE_MENU_ITEM: &[u8] =
         include_bytes!("../../../assets/icons-classic/folder/Apple menu item.png");
+    #[cfg(feature = "embed-classic-icons")]
     const FOLDER_APPLICATION_SUPPORT: &[u8] =
         include_bytes!("../../../assets/icons-classic/folder/Application Support.png");
+    #[cfg(feature = "embed-classic-icons")]
     const FOLDER_APPLICATIONS: &[u8] =
         include_bytes!("../../../assets/icons-classic/folder/Applications.png");
+    #[cfg(feature = "embed-classic-icons")]
     const FOLDER_ASSISTANT: &[u8] =
         include_bytes!("../../../assets/icons-classic/folder/Assistant.png");
+    #[cfg(feature = "embed-classic-icons")]
     const FOLDER_COLORSYNC_PROFILES: &[u8] =
         include_bytes!("../../../assets/icons-classic/folder/Colorsync profiles.png");
+    #[cfg(feature = "embed-classic-icons")]
     const FOLDER_CONTEXTUAL_MENU_ITEMS: &[u8] =
         include_bytes!("../../../assets/icons-classic/folder/Contextual menu items.png");
+    #[cfg(feature = "embed-classic-icons")]
     const FOLDER_CONTROL_PANELS: &[u8] =
         include_bytes!("../../../assets/icons-classic/folder/Control panels.png");
+    #[cfg(feature = "embed-classic-icons")]
     const FOLDER_CONTROL_STRIP: &[u8] =
         include_bytes!("../../../assets/icons-classic/folder/Control strip.png");
+    #[cfg(feature = "embed-classic-icons")]
     const FOLDER_DEFAULT: &[u8] =
         include_bytes!("../../../assets/icons-classic/folder/Default.png");
+    #[cfg(feature = "embed-classic-icons")]
     const FOLDER_EXTENSIONS: &[u8] =
         include_bytes!("../../../assets/icons-classic/folder/Extensions.png");
+    #[cfg(feature = "embed-classic-icons")]
     const FOLDER_EXTRAS: &[u8] = include_bytes!("../../../assets/icons-classic/folder/Extras.png");
+    #[cfg(feature = "embed-classic-icons")]
     const FOLDER_FAVORITES: &[u8] =
         include_bytes!("../../../assets/icons-classic/folder/Favorites.png");
+    #[cfg(feature = "embed-classic-icons")]
     const FOLDER_FONTS: &[u8] = include_bytes!("../../../assets/icons-classic/folder/Fonts.png");
+    #[cfg(feature = "embed-classic-icons")]
     const FOLDER_HELP: &[u8] = include_bytes!("../../../assets/icons-classic/folder/Help.png");
+    #[cfg(feature = "embed-classic-icons")]
     const FOLDER_INTERNET_SEARCH_SITES: &[u8] =
         include_bytes!("../../../assets/icons-classic/folder/Internet search sites.png");
+    #[cfg(feature = "embed-classic-icons")]
     const FOLDER_INTERNET: &[u8] =
         include_bytes!("../../../assets/icons-classic/folder/Internet.png");
+    #[cfg(feature = "embed-classic-icons")]
     const FOLDER_LANGUAGE_AND_REGION: &[u8] =
         include_bytes!("../../../assets/icons-classic/folder/Language and region.png");
+    #[cfg(feature = "embed-classic-icons")]
     const FOLDER_PREFERENCES: &[u8] =
         include_bytes!("../../../assets/icons-classic/folder/Preferences.png");
+    #[cfg(feature = "embed-classic-icons")]
     const FOLDER_RECENT_DOCUMENTS: &[u8] =
         include_bytes!("../../../assets/icons-classic/folder/Recent documents.png");
+    #[cfg(feature = "embed-classic-icons")]
     const FOLDER_SCRIPTS: &[u8] =
         include_bytes!("../../../assets/icons-classic/folder/Scripts.png");
+    #[cfg(feature = "embed-classic-icons")]
     const FOLDER_STARTUP_ITEMS: &[u8] =
         include_bytes!("../../../assets/icons-classic/folder/Startup items.png");
+    #[cfg(feature = "embed-classic-icons")]
     const FOLDER_SYSTEM: &[u8] = include_bytes!("../../../assets/icons-classic/folder/System.png");
+    #[cfg(feature = "embed-classic-icons")]
     const FOLDER_TEXT_ENCODINGS: &[u8] =
         include_bytes!("../../../assets/icons-classic/folder/Text encodings.png");
+    #[cfg(feature = "embed-classic-icons")]
     const FOLDER_UTILITIES: &[u8] =
         include_bytes!("../../../assets/icons-classic/folder/Utilities.png");
 
     // MENU-BAR
+    #[cfg(feature = "embed-classic-icons")]
     const MENU_BAR_APPLE_LOGO: &[u8] =
         include_bytes!("../../../assets/icons-classic/menu-bar/Apple logo.png");
+    #[cfg(feature = "embed-classic-icons")]
     const MENU_BAR_APPLE_SYSTEM: &[u8] =
         include_bytes!("../../../assets/icons-classic/menu-bar/Apple system.png");
+    #[cfg(feature = "embed-classic-icons")]
     const MENU_BAR_CALCULATOR: &[u8] =
         include_bytes!("../../../assets/icons-classic/menu-bar/Calculator.png");
+    #[cfg(feature = "embed-classic-icons")]
     const MENU_BAR_CHOOSER: &[u8] =
         include_bytes!("../../../assets/icons-classic/menu-bar/Chooser.png");
+    #[cfg(feature = "embed-classic-icons")]
     const MENU_BAR_CONTROL_PANELS: &[u8] =
         include_bytes!("../../../assets/icons-classic/menu-bar/Control panels.png");
+    #[cfg(feature = "embed-classic-icons")]
     const MENU_BAR_FAVORITES: &[u8] =
         include_bytes!("../../../assets/icons-classic/menu-bar/Favorites.png");
+    #[cfg(feature = "embed-classic-icons")]
     const MENU_BAR_FINDER: &[u8] =
         include_bytes!("../../../assets/icons-classic/menu-bar/Finder.png");
+    #[cfg(feature = "embed-classic-icons")]
     const MENU_BAR_KEY_CAPS: &[u8] =
         include_bytes!("../../../assets/icons-classic/menu-bar/Key caps.png");
+    #[cfg(feature = "embed-classic-icons")]
     const MENU_BAR_NETWORK_BROWSER: &[u8] =
         include_bytes!("../../../assets/icons-classic/menu-bar/Network browser.png");
+    #[cfg(feature = "embed-classic-icons")]
     const MENU_BAR_RECENT_APPLICATIONS: &[u8] =
         include_bytes!("../../../assets/icons-classic/menu-bar/Recent applications.png");
+    #[cfg(feature = "embed-classic-icons")]
     const MENU_BAR_RECENT_DOCUMENTS: &[u8] =
         include_bytes!("../../../assets/icons-classic/menu-bar/Recent documents.png");
+    #[cfg(feature = "embed-classic-icons")]
     const MENU_BAR_REMOTE_ACCESS: &[u8] =
         include_bytes!("../../../assets/icons-classic/menu-bar/Remote access.png");
+    #[cfg(feature = "embed-classic-icons")]
     const MENU_BAR_SCRAPBOOK: &[u8] =
         include_bytes!("../../../assets/icons-classic/menu-bar/Scrapbook.png");
+    #[cfg(feature = "embed-classic-icons")]
     const MENU_BAR_SHERLOCK_2_0: &[u8] =
         include_bytes!("../../../assets/icons-classic/menu-bar/Sherlock 2.0.png");
+    #[cfg(feature = "embed-classic-icons")]
     const MENU_BAR_STICKIES: &[u8] =
         include_bytes!("../../../assets/icons-classic/menu-bar/Stickies.png");
+    #[cfg(feature = "embed-classic-icons")]
     const MENU_BAR_SUITCASE: &[u8] =
         include_bytes!("../../../assets/icons-classic/menu-bar/Suitcase.png");
 
     // SYSTEM
+    #[cfg(feature = "embed-classic-icons")]
     const SYSTEM_BLANK_FILE: &[u8] =
         include_bytes!("../../../assets/icons-classic/system/Blank file.png");
+    #[cfg(feature = "embed-classic-icons")]
     const SYSTEM_CSW_6000_SERIES: &[u8] =
         include_bytes!("../../../assets/icons-classic/system/CSW 6000 Series.png");
+    #[cfg(feature = "embed-classic-icons")]
     const SYSTEM_CLIPBOARD: &[u8] =
         include_bytes!("../../../assets/icons-classic/system/Clipboard.png");
+    #[cfg(feature = "embed-classic-icons")]
     const SYSTEM_COLOR_SW_1500: &[u8] =
         include_bytes!("../../../assets/icons-classic/system/Color SW 1500.png");
+    #[cfg(feature = "embed-classic-icons")]
     const SYSTEM_COLOR_SW_2500: &[u8] =
         include_bytes!("../../../assets/icons-classic/system/Color SW 2500.png");
+    #[cfg(feature = "embed-classic-icons")]
     const SYSTEM_COLOR_SW_PRO: &[u8] =
         include_bytes!("../../../assets/icons-classic/system/Color SW Pro.png");
+    #[cfg(feature = "embed-classic-icons")]
     const SYSTEM_COLOR_PROFILE: &[u8] =
         include_bytes!("../../../assets/icons-classic/system/Color profile.png");
+    #[cfg(feature = "embed-classic-icons")]
     const SYSTEM_DRIVE_SETUP: &[u8] =
         include_bytes!("../../../assets/icons-classic/system/Drive setup.png");
+    #[cfg(feature = "embed-classic-icons")]
     const SYSTEM_EDIT: &[u8] = include_bytes!("../../../assets/icons-classic/system/Edit.png");
+    #[cfg(feature = "embed-classic-icons")]
     const SYSTEM_FLOPPY_DISK: &[u8] =
         include_bytes!("../../../assets/icons-classic/system/Floppy disk.png");
+    #[cfg(feature = "embed-classic-icons")]
     const SYSTEM_FOLDER_PANELS: &[u8] =
         include_bytes!("../../../assets/icons-classic/system/Folder panels.png");
+    #[cfg(feature = "embed-classic-icons")]
     const SYSTEM_FONT_FILE: &[u8] =
         include_bytes!("../../../assets/icons-classic/system/Font file.png");
+    #[cfg(feature = "embed-classic-icons")]
     const SYSTEM_FONT_SUITCASE: &[u8] =
         include_bytes!("../../../assets/icons-classic/system/Font suitcase.png");
+    #[cfg(feature = "embed-classic-icons")]
     const SYSTEM_HARD_DRIVE_SHARED: &[u8] =
         include_bytes!("../../../assets/icons-classic/system/Hard drive (Shared).png");
+    #[cfg(feature = "embed-classic-icons")]
     const SYSTEM_HARD_DRIVE_EXTERNAL: &[u8] =
         include_bytes!("../../../assets/icons-classic/system/Hard drive (external).png");
+    #[cfg(feature = "embed-classic-icons")]
     const SYSTEM_HARD_DRIVE_FINDER: &[u8] =
         include_bytes!("../../../assets/icons-classic/system/Hard drive (finder).png");
+    #[cfg(feature = "embed-classic-icons")]
     const SYSTEM_HARD_DRIVE_SHARED_FINDER: &[u8] =
         include_bytes!("../../../assets/icons-classic/system/Hard drive (shared finder).png");
+    #[cfg(feature = "embed-classic-icons")]
     const SYSTEM_HARD_DRIVE: &[u8] =
         include_bytes!("../../../assets/icons-classic/system/Hard drive.png");
+    #[cfg(feature = "embed-classic-icons")]
     const SYSTEM_HELP: &[u8] = include_bytes!("../../../assets/icons-classic/system/Help.png");
+    #[cfg(feature = "embed-classic-icons")]
     const SYSTEM_INTERNET_BROWSE: &[u8] =
         include_bytes!("../../../assets/icons-classic/system/Internet Browse.png");
+    #[cfg(feature = "embed-classic-icons")]
     const SYSTEM_INTERNET_SEARCH: &[u8] =
         include_bytes!("../../../assets/icons-classic/system/Internet search.png");
+    #[cfg(feature = "embed-classic-icons")]
     const SYSTEM_INTERNET_SETUP: &[u8] =
         include_bytes!("../../../assets/icons-classic/system/Internet setup.png");
+    #[cfg(feature = "embed-classic-icons")]
     const SYSTEM_LANGUAGE_FILE: &[u8] =
         include_bytes!("../../../assets/icons-classic/system/Language file.png");
+    #[cfg(feature = "embed-classic-icons")]
     const SYSTEM_LOG: &[u8] = include_bytes!("../../../assets/icons-classic/system/Log.png");
+    #[cfg(feature = "embed-classic-icons")]
     const SYSTEM_MAP_TCP_FILE: &[u8] =
         include_bytes!("../../../assets/icons-classic/system/Map tcp file.png");
+    #[cfg(feature = "embed-classic-icons")]
     const SYSTEM_NOTEPAD_FILE: &[u8] =
         include_bytes!("../../../assets/icons-classic/system/Notepad file.png");
+    #[cfg(feature = "embed-classic-icons")]
     const SYSTEM_PDF: &[u8] = include_bytes!("../../../assets/icons-classic/system/PDF.png");
+    #[cfg(feature = "embed-classic-icons")]
     const SYSTEM_QUESTION: &[u8] =
         include_bytes!("../../../assets/icons-classic/system/Question.png");
+    #[cfg(feature = "embed-classic-icons")]
     const SYSTEM_QUICKTIME_MOVIE: &[u8] =
         include_bytes!("../../../assets/icons-classic/system/Quicktime movie.png");
+    #[cfg(feature = "embed-classic-icons")]
     const SYSTEM_REGISTER_WITH_APPLE: &[u8] =
         include_bytes!("../../../assets/icons-classic/system/Register with Apple.png");
+    #[cfg(feature = "embed-classic-icons")]
     const SYSTEM_SCREEN: &[u8] = include_bytes!("../../../assets/icons-classic/system/Screen.png");
+    #[cfg(feature = "embed-classic-icons")]
     const SYSTEM_SETUP_ASSISTANT: &[u8] =
         include_bytes!("../../../assets/icons-classic/system/Setup assistant.png");
+    #[cfg(feature = "embed-classic-icons")]
     const SYSTEM_SOUND_SETTINGS: &[u8] =
         include_bytes!("../../../assets/icons-classic/system/Sound settings.png");
+    #[cfg(feature = "embed-classic-icons")]
     const SYSTEM_SYSTEM_FOLDER: &[u8] =
         include_bytes!("../../../assets/icons-classic/system/System folder.png");
+    #[cfg(feature = "embed-classic-icons")]
     const SYSTEM_TEACH_TEXT: &[u8] =
         include_bytes!("../../../assets/icons-classic/system/Teach text.png");
+    #[cfg(feature = "embed-classic-icons")]
     const SYSTEM_TEXT_FILE: &[u8] =
         include_bytes!("../../../assets/icons-classic/system/Text file.png");
+    #[cfg(feature = "embed-classic-icons")]
     const SYSTEM_TRASH_EMPTY: &[u8] =
         include_bytes!("../../../assets/icons-classic/system/Trash (Empty).png");
+    #[cfg(feature = "embed-classic-icons")]
     const SYSTEM_TRASH_FULL: &[u8] =
         include_bytes!("../../../assets/icons-classic/system/Trash (Full).png");
+    #[cfg(feature = "embed-classic-icons")]
     const SYSTEM_URL_ACCESS: &[u8] =
         include_bytes!("../../../assets/icons-classic/system/URL Access.png");
+    #[cfg(feature = "embed-classic-icons")]
     const SYSTEM_ITUNES_PLAYLIST: &[u8] =
         include_bytes!("../../../assets/icons-classic/system/iTunes playlist.png");
+    #[cfg(feature = "embed-classic-icons")]
     const SYSTEM_ITUNES_PLUGIN: &[u8] =
         include_bytes!("../../../assets/icons-classic/system/iTunes plugin.png");
 
@@ -442,21 +1097,56 @@ pub mod embedded {
 
     // ── CSS rewriting ───────────────────────────────────────────
 
+    /// Blob URLs for the embedded fonts, `None` for fonts whose feature is
+    /// disabled.
+    struct FontBlobUrls {
+        fa_solid: Option<String>,
+        fa_regular: Option<String>,
+        fa_v4compat: Option<String>,
+        fa_brands: Option<String>,
+        chicago: Option<String>,
+        garamond_light: Option<String>,
+        garamond_regular: Option<String>,
+        garamond_bold: Option<String>,
+    }
+
+    /// Replace `path` in `css` with `url`, leaving it as is without one.
+    fn replace_url(css: String, path: &str, url: Option<&str>) -> String {
+        match url {
+            Some(url) => css.replace(path, url),
+            None => css,
+        }
+    }
+
     /// Rewrite Font Awesome CSS to use Blob URLs for embedded fonts.
     ///
     /// Replaces woff2 relative paths with Blob URLs and strips the
-    /// ttf fallback entries (we only ship woff2).
-    fn rewrite_fontawesome_css(
-        css: &str,
-        solid_url: &str,
-        regular_url: &str,
-        v4compat_url: &str,
-    ) -> String {
+    /// ttf fallback entries (we only ship woff2). The paths of fonts that
+    /// are not embedded are left as is.
+    fn rewrite_fontawesome_css(css: &str, fonts: &FontBlobUrls) -> String {
+        let css = css.to_string();
+        // Replace woff2 paths with Blob URLs
+        let css = replace_url(
+            css,
+            "../webfonts/fa-solid-900.woff2",
+            fonts.fa_solid.as_deref(),
+        );
+        let css = replace_url(
+            css,
+            "../webfonts/fa-regular-400.woff2",
+            fonts.fa_regular.as_deref(),
+        );
+        let css = replace_url(
+            css,
+            "../webfonts/fa-v4compatibility.woff2",
+            fonts.fa_v4compat.as_deref(),
+        );
+        let css = replace_url(
+            css,
+            "../webfonts/fa-brands-400.woff2",
+            fonts.fa_brands.as_deref(),
+        );
         css
-            // Replace woff2 paths with Blob URLs
-            .replace("../webfonts/fa-solid-900.woff2", solid_url)
-            .replace("../webfonts/fa-regular-400.woff2", regular_url)
-            .replace("../webfonts/fa-v4compatibility.woff2", v4compat_url)
             // Strip ttf fallbacks (we only embed woff2)
             .replace(
                 ",url(../webfonts/fa-solid-900.ttf) format(\"truetype\")",
@@ -489,34 +1179,32 @@ pub mod embedded {
     /// Rewrite iti CSS to use Blob URLs for the embedded fonts and SVGs.
     ///
     /// Replaces the ttf paths for ChicagoFLF and Apple Garamond
-    /// with Blob URLs. Also replaces all `url("svg/...")` references with
-    /// Blob URLs so that consumers using the `embed-assets` feature do
-    /// not need to ship the `assets/svg/` directory separately.
-    fn rewrite_iti_css(
-        css: &str,
-        chicago_url: &str,
-        garamond_light_url: &str,
-        garamond_regular_url: &str,
-        garamond_bold_url: &str,
-        svg_urls: &SvgBlobUrls,
-    ) -> String {
-        css.replace(
+    /// with Blob URLs, when embedded. Also replaces all `url("svg/...")`
+    /// references with Blob URLs so that consumers using the `embed-css`
+    /// feature do not need to ship the `assets/svg/` directory separately.
+    fn rewrite_iti_css(css: &str, fonts: &FontBlobUrls, svg_urls: &SvgBlobUrls) -> String {
+        let font_url = |url: &Option<String>| url.as_ref().map(|url| format!("url(\"{url}\")"));
+        let css = replace_url(
+            css.to_string(),
             "url('fonts/ChicagoFLF.ttf')",
-            &format!("url(\"{chicago_url}\")"),
-        )
-        .replace(
+            font_url(&fonts.chicago).as_deref(),
+        );
+        let css = replace_url(
+            css,
             "url('fonts/AppleGaramond-Light.ttf')",
-            &format!("url(\"{garamond_light_url}\")"),
-        )
-        .replace(
+            font_url(&fonts.garamond_light).as_deref(),
+        );
+        let css = replace_url(
+            css,
             "url('fonts/AppleGaramond.ttf')",
-            &format!("url(\"{garamond_regular_url}\")"),
-        )
-        .replace(
+            font_url(&fonts.garamond_regular).as_deref(),
+        );
+        let css = replace_url(
+            css,
             "url('fonts/AppleGaramond-Bold.ttf')",
-            &format!("url(\"{garamond_bold_url}\")"),
-        )
-        .replace(
+            font_url(&fonts.garamond_bold).as_deref(),
+        );
+        css.replace(
             "url(\"svg/checkbox-checkmark.svg\")",
             &format!("url(\"{}\")", svg_urls.checkbox_checkmark),
         )
@@ -553,18 +1241,100 @@ pub mod embedded {
     /// 2. iti unified CSS (with `@font-face` rewritten to Blob URLs)
     /// 3. Font Awesome 6 CSS (with `@font-face` rewritten to Blob URLs)
     ///
-    /// Font Awesome Brands icons are **not** embedded to save binary
-    /// space. Brand icon classes (`.fa-brands`) will render as blank
-    /// unless the consumer loads the Brands font separately.
+    /// Font Awesome Brands icons are **not** embedded by default to save
+    /// binary space. Brand icon classes (`.fa-brands`) will render as
+    /// blank unless the `embed-brands` feature is enabled or the consumer
+    /// loads the Brands font separately. The same goes for any other font
+    /// whose `embed-*` feature is disabled.
     pub fn inject_styles() {
+        let (iti_css, fa_css) = embedded_css();
+        // Inject everything as <style> elements — zero network requests
+        inject_color_tokens();
+        append_style(iti_css);
+        append_style(fa_css);
+    }
+
+    /// Like [`inject_styles`], with a CSP `nonce` on every injected
+    /// `<style>`.
+    ///
+    /// The policy must also allow `blob:` in `font-src` and `img-src`.
+    pub fn inject_styles_with_nonce(nonce: &str) {
+        let (iti_css, fa_css) = embedded_css();
+        inject_color_tokens_with_nonce(nonce);
+        append_style_with_nonce(iti_css, nonce);
+        append_style_with_nonce(fa_css, nonce);
+    }
+
+    /// Inject all required styles from the embedded assets into a shadow
+    /// root.
+    ///
+    /// Like [`inject_styles`], but the component styles are added to
+    /// `root` so they only apply inside it. Call it once per shadow root.
+    ///
+    /// ## Note
+    /// Browsers ignore `@font-face` and `:root` rules inside shadow
+    /// roots, so those (along with the color tokens) are added to the
+    /// document `<head>` as well, once. Custom properties inherit into
+    /// shadow roots, so the tokens still apply.
+    pub fn inject_styles_into(root: &web_sys::ShadowRoot) {
+        let (iti_css, fa_css) = embedded_css();
+        let (iti_document_css, iti_scoped_css) = split_document_rules(iti_css);
+        let (fa_document_css, fa_scoped_css) = split_document_rules(fa_css);
+        static DOCUMENT: Once = Once::new();
+        DOCUMENT.call_once(|| {
+            inject_color_tokens();
+            append_style(&iti_document_css);
+            append_style(&fa_document_css);
+        });
+        append_style_into(root, &iti_scoped_css);
+        append_style_into(root, &fa_scoped_css);
+    }
+
+    /// Returns the rewritten CSS from [`rewritten_css`], creating its
+    /// Blob URLs only once.
+    pub(super) fn embedded_css() -> &'static (String, String) {
+        static CSS: OnceLock<(String, String)> = OnceLock::new();
+        CSS.get_or_init(rewritten_css)
+    }
+
+    /// Returns iti's CSS and Font Awesome's CSS, rewritten to use Blob
+    /// URLs for the embedded assets.
+    fn rewritten_css() -> (String, String) {
         // Create Blob URLs for each embedded font
-        let fa_solid_url = create_blob_url(FA_SOLID_WOFF2, "font/woff2");
-        let fa_regular_url = create_blob_url(FA_REGULAR_WOFF2, "font/woff2");
-        let fa_v4compat_url = create_blob_url(FA_V4COMPAT_WOFF2, "font/woff2");
-        let chicago_url = create_blob_url(CHICAGO_TTF, "font/ttf");
-        let garamond_light_url = create_blob_url(GARAMOND_LIGHT_TTF, "font/ttf");
-        let garamond_regular_url = create_blob_url(GARAMOND_REGULAR_TTF, "font/ttf");
-        let garamond_bold_url = create_blob_url(GARAMOND_BOLD_TTF, "font/ttf");
+        let fonts = FontBlobUrls {
+            #[cfg(feature = "embed-solid")]
+            fa_solid: Some(create_blob_url(FA_SOLID_WOFF2, "font/woff2")),
+            #[cfg(not(feature = "embed-solid"))]
+            fa_solid: None,
+            #[cfg(feature = "embed-regular")]
+            fa_regular: Some(create_blob_url(FA_REGULAR_WOFF2, "font/woff2")),
+            #[cfg(not(feature = "embed-regular"))]
+            fa_regular: None,
+            #[cfg(feature = "embed-v4compat")]
+            fa_v4compat: Some(create_blob_url(FA_V4COMPAT_WOFF2, "font/woff2")),
+            #[cfg(not(feature = "embed-v4compat"))]
+            fa_v4compat: None,
+            #[cfg(feature = "embed-brands")]
+            fa_brands: Some(create_blob_url(FA_BRANDS_WOFF2, "font/woff2")),
+            #[cfg(not(feature = "embed-brands"))]
+            fa_brands: None,
+            #[cfg(feature = "embed-platinum-fonts")]
+            chicago: Some(create_blob_url(CHICAGO_TTF, "font/ttf")),
+            #[cfg(feature = "embed-platinum-fonts")]
+            garamond_light: Some(create_blob_url(GARAMOND_LIGHT_TTF, "font/ttf")),
+            #[cfg(feature = "embed-platinum-fonts")]
+            garamond_regular: Some(create_blob_url(GARAMOND_REGULAR_TTF, "font/ttf")),
+            #[cfg(feature = "embed-platinum-fonts")]
+            garamond_bold: Some(create_blob_url(GARAMOND_BOLD_TTF, "font/ttf")),
+            #[cfg(not(feature = "embed-platinum-fonts"))]
+            chicago: None,
+            #[cfg(not(feature = "embed-platinum-fonts"))]
+            garamond_light: None,
+            #[cfg(not(feature = "embed-platinum-fonts"))]
+            garamond_regular: None,
+            #[cfg(not(feature = "embed-platinum-fonts"))]
+            garamond_bold: None,
+        };
 
         // Create Blob URLs for each embedded SVG asset referenced from
         // iti.css. These are one-shot URLs scoped to the lifetime of
@@ -579,25 +1349,9 @@ pub mod embedded {
         };
 
         // Rewrite CSS @font-face declarations to use Blob URLs
-        let fa_css = rewrite_fontawesome_css(
-            FONTAWESOME_CSS,
-            &fa_solid_url,
-            &fa_regular_url,
-            &fa_v4compat_url,
-        );
-        let iti_css = rewrite_iti_css(
-            ITI_CSS,
-            &chicago_url,
-            &garamond_light_url,
-            &garamond_regular_url,
-            &garamond_bold_url,
-            &svg_urls,
-        );
-
-        // Inject everything as <style> elements — zero network requests
-        inject_color_tokens();
-        append_style(&iti_css);
-        append_style(&fa_css);
+        let fa_css = rewrite_fontawesome_css(FONTAWESOME_CSS, &fonts);
+        let iti_css = rewrite_iti_css(ITI_CSS, &fonts, &svg_urls);
+        (iti_css, fa_css)
     }
 
     /// Get a Blob URL for the ascending table sort arrow SVG.
@@ -631,6 +1385,7 @@ pub mod embedded {
     /// Uses a lazy static [`AssetRegistry`] to avoid recreating Blob URLs
     /// for the same icon multiple times. Icons are loaded on-demand from
     /// the compiled constants via the embedded icon lookup.
+    #[cfg(feature = "embed-classic-icons")]
     pub fn blob_url_for_classic_icon(
         glyph: &crate::components::icon_classic::IconClassicGlyph,
     ) -> String {
@@ -648,6 +1403,7 @@ pub mod embedded {
     }
 
     /// Get embedded classic icon bytes by glyph.
+    #[cfg(feature = "embed-classic-icons")]
     fn get_classic_icon_bytes(
         glyph: &crate::components::icon_classic::IconClassicGlyph,
     ) -> Option<&'static [u8]> {
@@ -0,0 +1,133 @@
+//! A typed event bus.
+//!
+//! [`Bus`] lets parts of an app that don't hold references to each other
+//! communicate, e.g. a navbar button opening a sidebar. Every
+//! [`Subscriber`] receives its own copy of each event sent after it
+//! subscribed, one at a time from [`Subscriber::step`].
+//!
+//! ```ignore
+//! #[derive(Clone)]
+//! enum SidebarEvent {
+//!     Toggle,
+//! }
+//!
+//! // In the navbar:
+//! Bus::<SidebarEvent>::global().send(SidebarEvent::Toggle);
+//!
+//! // In the sidebar:
+//! let mut events = Bus::<SidebarEvent>::global().subscribe();
+//! loop {
+//!     match events.step().await {
+//!         SidebarEvent::Toggle => sidebar.toggle(),
+//!     }
+//! }
+//! ```
+
+use std::{
+    any::{Any, TypeId},
+    cell::RefCell,
+    collections::HashMap,
+    rc::{Rc, Weak},
+};
+
+use crate::util::queue::Queue;
+
+thread_local! {
+    static GLOBAL_BUSES: RefCell<HashMap<TypeId, Box<dyn Any>>> = RefCell::new(HashMap::new());
+}
+
+/// Sends events of type `T` to all of its subscribers.
+///
+/// Cloning a bus gives another handle to the same bus.
+pub struct Bus<T> {
+    subscribers: Rc<RefCell<Vec<Weak<RefCell<Queue<T>>>>>>,
+}
+
+impl<T> Clone for Bus<T> {
+    fn clone(&self) -> Self {
+        Self {
+            subscribers: self.subscribers.clone(),
+        }
+    }
+}
+
+impl<T> Default for Bus<T> {
+    fn default() -> Self {
+        Self {
+            subscribers: Default::default(),
+        }
+    }
+}
+
+impl<T: Clone + 'static> Bus<T> {
+    /// Create a new bus, separate from every other.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Returns the app-wide bus for events of type `T`.
+    ///
+    /// Every call with the same `T` returns a handle to the same bus.
+    pub fn global() -> Self {
+        GLOBAL_BUSES.with(|buses| {
+            buses
+                .borrow_mut()
+                .entry(TypeId::of::<T>())
+                .or_insert_with(|| Box::new(Bus::<T>::new()))
+                .downcast_ref::<Bus<T>>()
+                .cloned()
+                .unwrap_or_default()
+        })
+    }
+
+    /// Start receiving the events sent on this bus from now on.
+    pub fn subscribe(&self) -> Subscriber<T> {
+        let queue = Queue::new();
+        self.subscribers.borrow_mut().push(Rc::downgrade(&queue));
+        Subscriber { queue }
+    }
+
+    /// Send `event` to every subscriber, returning how many there were.
+    pub fn send(&self, event: T) -> usize {
+        let mut subscribers = self.subscribers.borrow_mut();
+        subscribers.retain(|queue| queue.strong_count() > 0);
+        for queue in subscribers.iter().filter_map(Weak::upgrade) {
+            queue.borrow_mut().push(event.clone());
+        }
+        subscribers.len()
+    }
+
+    /// Returns the number of live subscribers.
+    pub fn subscriber_count(&self) -> usize {
+        self.subscribers
+            .borrow()
+            .iter()
+            .filter(|queue| queue.strong_count() > 0)
+            .count()
+    }
+}
+
+/// Receives the events sent on a [`Bus`].
+///
+/// Events are queued until they are taken with [`Subscriber::step`], so
+/// none are missed between steps. Dropping the subscriber unsubscribes it.
+pub struct Subscriber<T> {
+    queue: Rc<RefCell<Queue<T>>>,
+}
+
+impl<T> Subscriber<T> {
+    /// Returns the number of events waiting to be taken.
+    pub fn len(&self) -> usize {
+        self.queue.borrow().len()
+    }
+
+    /// Returns `true` if no events are waiting.
+    pub fn is_empty(&self) -> bool {
+        self.queue.borrow().is_empty()
+    }
+
+    /// Await the next event.
+    pub async fn step(&mut self) -> T {
+        Queue::next(&self.queue).await
+    }
+}
@@ -0,0 +1,235 @@
+//! Capability traits for side effects.
+//!
+//! Components that reach outside the page, like
+//! [`CopyButton`](crate::components::copy::CopyButton) and
+//! [`Loadable`](crate::components::loadable::Loadable), do so through a
+//! trait with a browser implementation, so the side effect can be swapped
+//! out, e.g. for a fake in a test harness.
+
+use std::{cell::RefCell, collections::HashMap, future::Future, rc::Rc};
+
+use mogwai::web::prelude::wasm_bindgen_futures::JsFuture;
+use serde::{de::DeserializeOwned, Serialize};
+use snafu::prelude::*;
+use wasm_bindgen::{JsCast, JsValue};
+
+/// All capability errors.
+#[derive(Debug, Snafu)]
+pub enum Error {
+    #[snafu(display("No clipboard available"))]
+    NoClipboard,
+    #[snafu(display("Could not write to the clipboard: {message}"))]
+    ClipboardWrite { message: String },
+    #[snafu(display("DOM API unavailable"))]
+    NoWindow,
+    #[snafu(display("Could not fetch '{url}': {message}"))]
+    Fetch { url: String, message: String },
+    #[snafu(display("Request for '{url}' failed with status {status}"))]
+    HttpStatus { url: String, status: u16 },
+    #[snafu(display("Error serializing the request body for '{url}': {source}"))]
+    SerializeBody {
+        url: String,
+        source: serde_json::Error,
+    },
+    #[snafu(display("Error parsing the response from '{url}': {source}"))]
+    ParseResponse {
+        url: String,
+        source: serde_json::Error,
+    },
+}
+
+/// Returns the message of a JS exception.
+pub(crate) fn js_message(e: &JsValue) -> String {
+    e.dyn_ref::<js_sys::Error>()
+        .map(|e| String::from(e.message()))
+        .unwrap_or_else(|| format!("{e:?}"))
+}
+
+/// Writes text to the system clipboard.
+pub trait Clipboard {
+    fn write_text(&self, text: &str) -> impl Future<Output = Result<(), Error>>;
+}
+
+/// The browser's `navigator.clipboard`.
+///
+/// ## Note
+/// Browsers only allow writing from a secure context (https or localhost)
+/// and usually only in response to a user action such as a click.
+#[derive(Clone, Copy, Debug, Default)]
+pub struct WebClipboard;
+
+impl Clipboard for WebClipboard {
+    async fn write_text(&self, text: &str) -> Result<(), Error> {
+        let clipboard = web_sys::window()
+            .context(NoClipboardSnafu)?
+            .navigator()
+            .clipboard();
+        let promise = clipboard.write_text(text);
+        JsFuture::from(promise)
+            .await
+            .map_err(|e| Error::ClipboardWrite {
+                message: js_message(&e),
+            })?;
+        Ok(())
+    }
+}
+
+/// Makes JSON requests over HTTP.
+pub trait Http {
+    /// `GET` the JSON at `url`.
+    fn get<T: DeserializeOwned>(&self, url: &str) -> impl Future<Output = Result<T, Error>>;
+
+    /// `POST` `body` as JSON to `url`, returning the JSON response.
+    fn post<B: Serialize, T: DeserializeOwned>(
+        &self,
+        url: &str,
+        body: &B,
+    ) -> impl Future<Output = Result<T, Error>>;
+}
+
+/// The browser's `fetch`.
+///
+/// Responses with a status outside 200-299 are returned as
+/// [`Error::HttpStatus`].
+#[derive(Clone, Copy, Debug, Default)]
+pub struct WebHttp;
+
+impl WebHttp {
+    async fn fetch<T: DeserializeOwned>(
+        &self,
+        method: &str,
+        url: &str,
+        body: Option<String>,
+    ) -> Result<T, Error> {
+        let fetch_error = |e: JsValue| Error::Fetch {
+            url: url.to_string(),
+            message: js_message(&e),
+        };
+
+        let window = web_sys::window().context(NoWindowSnafu)?;
+        let headers = web_sys::Headers::new().map_err(fetch_error)?;
+        headers
+            .set("Accept", "application/json")
+            .map_err(fetch_error)?;
+        let init = web_sys::RequestInit::new();
+        init.set_method(method);
+        if let Some(body) = body {
+            headers
+                .set("Content-Type", "application/json")
+                .map_err(fetch_error)?;
+            init.set_body(&JsValue::from_str(&body));
+        }
+        init.set_headers(&headers);
+        let request = web_sys::Request::new_with_str_and_init(url, &init).map_err(fetch_error)?;
+
+        let response: web_sys::Response = JsFuture::from(window.fetch_with_request(&request))
+            .await
+            .map_err(fetch_error)?
+            .unchecked_into();
+        ensure!(
+            response.ok(),
+            HttpStatusSnafu {
+                url,
+                status: response.status(),
+            }
+        );
+        let text = JsFuture::from(response.text().map_err(fetch_error)?)
+            .await
+            .map_err(fetch_error)?
+            .as_string()
+            .unwrap_or_default();
+        serde_json::from_str(&text).context(ParseResponseSnafu { url })
+    }
+}
+
+impl Http for WebHttp {
+    async fn get<T: DeserializeOwned>(&self, url: &str) -> Result<T, Error> {
+        self.fetch("GET", url, None).await
+    }
+
+    async fn post<B: Serialize, T: DeserializeOwned>(
+        &self,
+        url: &str,
+        body: &B,
+    ) -> Result<T, Error> {
+        let body = serde_json::to_string(body).context(SerializeBodySnafu { url })?;
+        self.fetch("POST", url, Some(body)).await
+    }
+}
+
+/// A request made to a [`MockHttp`].
+#[derive(Clone, Debug, PartialEq)]
+pub struct MockRequest {
+    pub method: &'static str,
+    pub url: String,
+    /// The JSON body of a `POST`.
+    pub body: Option<String>,
+}
+
+/// An [`Http`] that answers from canned responses, for tests and demos.
+///
+/// Requests to a URL without a response fail with status 404. Cloning a
+/// mock gives another handle to the same responses and requests.
+#[derive(Clone, Debug, Default)]
+pub struct MockHttp {
+    responses: Rc<RefCell<HashMap<String, Result<String, u16>>>>,
+    requests: Rc<RefCell<Vec<MockRequest>>>,
+}
+
+impl MockHttp {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Answer requests to `url` with `body` as JSON.
+    ///
+    /// ## Panics
+    /// Panics if `body` can't be serialized.
+    pub fn respond(&self, url: impl Into<String>, body: &impl Serialize) {
+        let json = serde_json::to_string(body).expect("could not serialize mock response");
+        self.responses.borrow_mut().insert(url.into(), Ok(json));
+    }
+
+    /// Answer requests to `url` with an error `status`.
+    pub fn fail(&self, url: impl Into<String>, status: u16) {
+        self.responses.borrow_mut().insert(url.into(), Err(status));
+    }
+
+    /// Returns the requests made so far, oldest first.
+    pub fn requests(&self) -> Vec<MockRequest> {
+        self.requests.borrow().clone()
+    }
+
+    fn answer<T: DeserializeOwned>(
+        &self,
+        method: &'static str,
+        url: &str,
+        body: Option<String>,
+    ) -> Result<T, Error> {
+        self.requests.borrow_mut().push(MockRequest {
+            method,
+            url: url.to_string(),
+            body,
+        });
+        let response = self.responses.borrow().get(url).cloned();
+        match response.unwrap_or(Err(404)) {
+            Ok(json) => serde_json::from_str(&json).context(ParseResponseSnafu { url }),
+            Err(status) => HttpStatusSnafu { url, status }.fail(),
+        }
+    }
+}
+
+impl Http for MockHttp {
+    async fn get<T: DeserializeOwned>(&self, url: &str) -> Result<T, Error> {
+        self.answer("GET", url, None)
+    }
+
+    async fn post<B: Serialize, T: DeserializeOwned>(
+        &self,
+        url: &str,
+        body: &B,
+    ) -> Result<T, Error> {
+        let body = serde_json::to_string(body).context(SerializeBodySnafu { url })?;
+        self.answer("POST", url, Some(body))
+    }
+}
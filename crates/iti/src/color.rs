@@ -169,3 +169,38 @@ pub fn css_tokens() -> String {
 "
     )
 }
+
+/// Generate the CSS rules for a [`Flavor::Custom`] named `name`.
+///
+/// `color` is any CSS color value, including a variable from an existing
+/// palette such as `var(--bs-brand)`. It is stored as `--iti-{name}` and
+/// the tint and alert background are mixed from it, the same way the
+/// built-in flavors are in `iti.css`.
+///
+/// Inject the result with [`crate::assets::append_style`]:
+///
+/// ```ignore
+/// iti::assets::append_style(&iti::color::flavor_css("brand", "#E4572E"));
+/// let button = Button::new("Buy", Some(Flavor::Custom("brand")));
+/// ```
+///
+/// [`Flavor::Custom`]: crate::components::Flavor::Custom
+pub fn flavor_css(name: &str, color: &str) -> String {
+    format!(
+        "\
+:root {{
+\t--iti-{name}:      {color};
+\t--iti-{name}-tint: color-mix(in srgb, var(--iti-{name}) 25%, var(--iti-bg-medium));
+\t--iti-{name}-bg:   color-mix(in srgb, var(--iti-{name}) 12%, var(--iti-bg-light));
+}}
+
+.flavor-{name}             {{ background-color: var(--iti-{name}-tint); }}
+.btn-outline-{name}        {{ border-color: var(--iti-{name}); color: var(--iti-{name}); }}
+.alert-{name}              {{ padding: 1em; width: 100%; background-color: var(--iti-{name}-bg); border-left: 5px solid var(--iti-{name}); }}
+.text-{name}               {{ color: var(--iti-{name}) !important; }}
+.text-bg-{name}            {{ background-color: var(--iti-{name}) !important; color: #fff !important; }}
+.border-{name}             {{ border-color: var(--iti-{name}) !important; }}
+.list-group-item-{name}    {{ border-left: 3px solid var(--iti-{name}) !important; }}
+"
+    )
+}
@@ -1,48 +1,240 @@
 //! Alert messages.
+use std::time::Duration;
+
+use futures_lite::FutureExt;
 use mogwai::prelude::*;
 
-use super::Flavor;
+use super::{
+    icon::{Icon, IconGlyph, IconSize},
+    Flavor,
+};
+
+/// Event emitted by an [`Alert`].
+pub enum AlertEvent {
+    /// The close button was clicked and the alert was hidden.
+    Dismissed,
+}
+
+/// Length of the `.fade` opacity transition in `iti.css`.
+const FADE_MILLIS: u64 = 150;
+
+struct AlertState {
+    is_dismissible: bool,
+    /// `None` when not transitioning, otherwise whether the `show` class is applied.
+    fade: Option<bool>,
+}
+
+impl AlertState {
+    fn class_name(&self) -> String {
+        let mut class = "alert".to_string();
+        if self.is_dismissible {
+            class.push_str(" alert-dismissible");
+        }
+        match self.fade {
+            Some(true) => class.push_str(" fade show"),
+            Some(false) => class.push_str(" fade"),
+            None => {}
+        }
+        class
+    }
+}
+
+/// The icon shown by default for each flavor.
+fn flavor_glyph(flavor: Flavor) -> IconGlyph {
+    match flavor {
+        Flavor::Success => IconGlyph::CircleCheck,
+        Flavor::Danger => IconGlyph::CircleXmark,
+        Flavor::Warning => IconGlyph::TriangleExclamation,
+        Flavor::Primary
+        | Flavor::Secondary
+        | Flavor::Info
+        | Flavor::Light
+        | Flavor::Dark
+        | Flavor::Link
+        | Flavor::Custom(_) => IconGlyph::CircleInfo,
+    }
+}
 
 /// A div-based alert message.
 ///
-/// Its text is settable.
+/// Its text is settable, or its body can be replaced with arbitrary content.
 /// Its flavor is settable.
+/// It can show a leading icon and an `alert-heading` title.
 /// It can be hidden and revealed.
+/// It can be made dismissible, in which case a close button is shown and
+/// [`Alert::step`] resolves when it is clicked.
+/// It can be shown temporarily with [`Alert::show_for`], fading out when
+/// the time is up.
 #[derive(ViewChild, ViewProperties)]
 pub struct Alert<V: View> {
     #[child]
     #[properties]
     div: V::Element,
     text: V::Text,
+    text_wrapper: V::Element,
+    heading: V::Element,
+    heading_text: V::Text,
+    body: V::Element,
+    body_child: ProxyChild<V>,
+    body_placeholder: V::Element,
+    icon: Icon<V>,
+    icon_wrapper: V::Element,
+    has_icon: bool,
+    icon_glyph: Option<IconGlyph>,
     flavor: Proxy<Flavor>,
+    state: Proxy<AlertState>,
+    close_click: V::EventListener,
 }
 
 impl<V: View> Alert<V> {
     pub fn new(initial_text: impl AsRef<str>, flavor: Flavor) -> Self {
+        let icon = Icon::new(flavor_glyph(flavor), IconSize::Large);
         let mut flavor = Proxy::new(flavor);
+        let mut state = Proxy::new(AlertState {
+            is_dismissible: false,
+            fade: None,
+        });
+
+        rsx! {
+            let body_placeholder = span() {}
+        }
+        let body_child = ProxyChild::new(&body_placeholder);
 
         rsx! {
             let div = div(
-                class = "alert",
+                class = state(s => s.class_name()),
                 role = "alert",
             ) {
-                span(class = flavor(flav => format!("alert-{flav}"))) {
-                    let text = ""
+                div(class = flavor(flav => format!("alert-main alert-{flav}"))) {
+                    let icon_wrapper = span(class = "alert-icon") {
+                        {&icon}
+                    }
+                    div(class = "alert-content") {
+                        let heading = h4(class = "alert-heading", style:display = "none") {
+                            let heading_text = ""
+                        }
+                        let body = div(class = "alert-body") {
+                            let text_wrapper = span() {
+                                let text = ""
+                            }
+                            {&body_child}
+                        }
+                    }
                 }
+                button(
+                    type = "button",
+                    class = "btn-close",
+                    aria_label = "Close",
+                    style:display = state(s => if s.is_dismissible { "flex" } else { "none" }),
+                    on:click = close_click,
+                ) {}
             }
         }
 
         text.set_text(initial_text);
 
-        Self { div, text, flavor }
+        // Icon is hidden by default
+        icon_wrapper.remove_child(&icon);
+
+        Self {
+            div,
+            text,
+            text_wrapper,
+            heading,
+            heading_text,
+            body,
+            body_child,
+            body_placeholder,
+            icon,
+            icon_wrapper,
+            has_icon: false,
+            icon_glyph: None,
+            flavor,
+            state,
+            close_click,
+        }
     }
 
+    /// Set the body text.
+    ///
+    /// The text is shown unless the body has been replaced with
+    /// [`Alert::set_body`].
     pub fn set_text(&self, text: impl AsRef<str>) {
         self.text.set_text(text);
     }
 
+    /// Replace the body text with arbitrary content.
+    pub fn set_body(&mut self, content: &impl ViewChild<V>) {
+        self.text_wrapper.set_style("display", "none");
+        self.body_child.replace(&self.body, content);
+    }
+
+    /// Remove any content set with [`Alert::set_body`] and show the body
+    /// text again.
+    pub fn clear_body(&mut self) {
+        self.body_child.replace(&self.body, &self.body_placeholder);
+        self.text_wrapper.remove_style("display");
+    }
+
+    /// Set the heading text and show the heading.
+    pub fn set_heading(&self, heading: impl AsRef<str>) {
+        self.heading_text.set_text(heading);
+        self.heading.remove_style("display");
+    }
+
+    /// Hide the heading.
+    pub fn hide_heading(&self) {
+        self.heading.set_style("display", "none");
+    }
+
     pub fn set_flavor(&mut self, flavor: Flavor) {
         self.flavor.set(flavor);
+        if self.icon_glyph.is_none() {
+            self.icon.set_glyph(flavor_glyph(flavor));
+        }
+    }
+
+    /// Show or hide the leading icon.
+    pub fn set_has_icon(&mut self, has_icon: bool) {
+        if has_icon && !self.has_icon {
+            self.icon_wrapper.append_child(&self.icon);
+        } else if !has_icon && self.has_icon {
+            self.icon_wrapper.remove_child(&self.icon);
+        }
+        self.has_icon = has_icon;
+    }
+
+    /// Override the leading icon's glyph.
+    ///
+    /// Pass `None` to go back to the glyph picked for the current flavor
+    /// (e.g. [`IconGlyph::CircleCheck`] for [`Flavor::Success`]).
+    ///
+    /// ## Note
+    /// This does not show the icon; see [`Alert::set_has_icon`].
+    pub fn set_icon(&mut self, glyph: Option<IconGlyph>) {
+        self.icon_glyph = glyph;
+        self.icon
+            .set_glyph(glyph.unwrap_or_else(|| flavor_glyph(*self.flavor)));
+    }
+
+    /// Access the icon component.
+    pub fn get_icon(&self) -> &Icon<V> {
+        &self.icon
+    }
+
+    /// Mutably access the icon component.
+    pub fn get_icon_mut(&mut self) -> &mut Icon<V> {
+        &mut self.icon
+    }
+
+    /// Show or hide the close button.
+    pub fn set_is_dismissible(&mut self, is_dismissible: bool) {
+        self.state.modify(|s| s.is_dismissible = is_dismissible);
+    }
+
+    /// Returns `true` if the close button is shown.
+    pub fn is_dismissible(&self) -> bool {
+        self.state.is_dismissible
     }
 
     pub fn set_is_visible(&self, is_visible: bool) {
@@ -52,4 +244,146 @@ impl<V: View> Alert<V> {
             self.div.set_style("visibility", "hidden");
         }
     }
+
+    /// Reveal the alert for the given duration, then fade it out.
+    ///
+    /// Resolves once the alert is fully hidden. If the alert is dismissible
+    /// and its close button is clicked before the time is up, it starts
+    /// fading out immediately.
+    ///
+    /// ## Note
+    /// A dismissal that ends the timeout early is consumed here and is not
+    /// reported by [`Alert::step`].
+    pub async fn show_for(&mut self, duration: Duration) {
+        // Start transparent so the browser has a frame to transition from.
+        self.state.modify(|s| s.fade = Some(false));
+        self.set_is_visible(true);
+        mogwai::time::wait_millis(10).await;
+        self.state.modify(|s| s.fade = Some(true));
+
+        let timeout = async {
+            mogwai::time::wait_millis(duration.as_millis() as u64).await;
+        };
+        let dismissed = async {
+            self.close_click.next().await;
+        };
+        timeout.or(dismissed).await;
+
+        self.state.modify(|s| s.fade = Some(false));
+        mogwai::time::wait_millis(FADE_MILLIS).await;
+        self.set_is_visible(false);
+        self.state.modify(|s| s.fade = None);
+    }
+
+    /// Await the close button being clicked.
+    ///
+    /// The alert hides itself before returning [`AlertEvent::Dismissed`].
+    /// Call [`Alert::set_is_visible`] to reveal it again.
+    ///
+    /// ## Note
+    /// If the alert is not dismissible this will wait indefinitely.
+    pub async fn step(&self) -> AlertEvent {
+        self.close_click.next().await;
+        self.set_is_visible(false);
+        AlertEvent::Dismissed
+    }
+}
+
+#[cfg(feature = "library")]
+pub mod library {
+    use futures_lite::FutureExt;
+    use mogwai::future::MogwaiFutureExt;
+
+    use super::*;
+    use crate::library::log_event;
+
+    /// Gallery sandbox for the [`Alert`] component.
+    #[derive(ViewChild)]
+    pub struct AlertLibraryItem<V: View> {
+        #[child]
+        pub wrapper: V::Element,
+        alert: Alert<V>,
+        reset_click: V::EventListener,
+        show_for_click: V::EventListener,
+        dismissals: usize,
+    }
+
+    impl<V: View> Default for AlertLibraryItem<V> {
+        fn default() -> Self {
+            let mut alert = Alert::new("Click the close button to dismiss me.", Flavor::Info);
+            alert.set_is_dismissible(true);
+            alert.set_has_icon(true);
+            alert.set_heading("Heads up!");
+
+            rsx! {
+                let wrapper = div() {
+                    div(class = "mb-3") {
+                        {&alert}
+                    }
+                    div(class = "btn-group") {
+                        button(
+                            type = "button",
+                            class = "btn",
+                            on:click = reset_click,
+                        ) {
+                            "Show alert"
+                        }
+                        button(
+                            type = "button",
+                            class = "btn",
+                            on:click = show_for_click,
+                        ) {
+                            "Show for 3 seconds"
+                        }
+                    }
+                }
+            }
+
+            Self {
+                wrapper,
+                alert,
+                reset_click,
+                show_for_click,
+                dismissals: 0,
+            }
+        }
+    }
+
+    impl<V: View> AlertLibraryItem<V> {
+        pub async fn step(&mut self) {
+            enum Action {
+                Dismissed,
+                Reset,
+                ShowFor,
+            }
+
+            let ev = self
+                .alert
+                .step()
+                .map(|AlertEvent::Dismissed| Action::Dismissed)
+                .or(self.reset_click.next().map(|_| Action::Reset))
+                .or(self.show_for_click.next().map(|_| Action::ShowFor))
+                .await;
+
+            match ev {
+                Action::Dismissed => {
+                    self.dismissals += 1;
+                    log_event("Alert", "Dismissed");
+                }
+                Action::Reset => {
+                    self.alert.set_text(format!(
+                        "Dismissed {} time{}. Click the close button to dismiss me again.",
+                        self.dismissals,
+                        if self.dismissals == 1 { "" } else { "s" }
+                    ));
+                    self.alert.set_is_visible(true);
+                }
+                Action::ShowFor => {
+                    self.alert
+                        .set_text("This alert will fade away in 3 seconds.");
+                    self.alert.show_for(Duration::from_secs(3)).await;
+                }
+            }
+        }
+    }
 }
@@ -1,6 +1,11 @@
 //! Badge component.
 //!
 //! A small label for counts, tags, and status indicators.
+//!
+//! A badge can also be positioned over the top-right corner of its parent,
+//! for the classic unread-count pattern. See [`Badge::positioned`],
+//! [`Button::set_badge`](super::button::Button::set_badge) and
+//! [`Icon::set_badge`](super::icon::Icon::set_badge).
 use mogwai::prelude::*;
 
 use super::Flavor;
@@ -8,11 +13,31 @@ use super::Flavor;
 struct BadgeState {
     flavor: Flavor,
     pill: bool,
+    positioned: bool,
+    dot: bool,
+}
+
+impl BadgeState {
+    fn class(&self) -> String {
+        let pill = if self.pill { " rounded-pill" } else { "" };
+        let positioned = if self.positioned {
+            " position-absolute top-0 start-100 translate-middle"
+        } else {
+            ""
+        };
+        let dot = if self.dot { " badge-dot" } else { "" };
+        format!("badge text-bg-{}{pill}{positioned}{dot}", self.flavor)
+    }
 }
 
 /// A Bootstrap badge (`<span class="badge">`).
 ///
-/// Supports reactive text, flavor, and an optional pill (rounded) style.
+/// Supports reactive text, flavor, an optional pill (rounded) style, and
+/// corner positioning.
+///
+/// It can also display a numeric count with an overflow cap (see
+/// [`Badge::set_count`]), or act as a text-less dot for presence
+/// indicators (see [`Badge::set_is_dot`]).
 #[derive(ViewChild, ViewProperties)]
 pub struct Badge<V: View> {
     #[child]
@@ -20,6 +45,8 @@ pub struct Badge<V: View> {
     span: V::Element,
     text: V::Text,
     state: Proxy<BadgeState>,
+    count: Option<u32>,
+    max_count: u32,
 }
 
 impl<V: View> Badge<V> {
@@ -27,14 +54,13 @@ impl<V: View> Badge<V> {
         let mut state = Proxy::new(BadgeState {
             flavor,
             pill: false,
+            positioned: false,
+            dot: false,
         });
 
         rsx! {
             let span = span(
-                class = state(s => {
-                    let pill = if s.pill { " rounded-pill" } else { "" };
-                    format!("badge text-bg-{}{pill}", s.flavor)
-                }),
+                class = state(s => s.class()),
             ) {
                 let text = ""
             }
@@ -42,7 +68,34 @@ impl<V: View> Badge<V> {
 
         text.set_text(initial_text);
 
-        Self { span, text, state }
+        Self {
+            span,
+            text,
+            state,
+            count: None,
+            max_count: 99,
+        }
+    }
+
+    /// Create a badge showing the given count.
+    ///
+    /// See [`Badge::set_count`].
+    pub fn with_count(count: u32, flavor: Flavor) -> Self {
+        let mut badge = Self::new("", flavor);
+        badge.set_count(count);
+        badge
+    }
+
+    /// Create a pill badge positioned over the top-right corner of its
+    /// parent.
+    ///
+    /// The parent must be positioned (e.g. `position: relative`) for the
+    /// badge to land on its corner.
+    pub fn positioned(initial_text: impl AsRef<str>, flavor: Flavor) -> Self {
+        let mut badge = Self::new(initial_text, flavor);
+        badge.set_pill(true);
+        badge.set_is_positioned(true);
+        badge
     }
 
     pub fn set_text(&self, text: impl AsRef<str>) {
@@ -56,4 +109,51 @@ impl<V: View> Badge<V> {
     pub fn set_pill(&mut self, pill: bool) {
         self.state.modify(|s| s.pill = pill);
     }
+
+    /// Position the badge over the top-right corner of its parent.
+    pub fn set_is_positioned(&mut self, positioned: bool) {
+        self.state.modify(|s| s.positioned = positioned);
+    }
+
+    /// Display a numeric count.
+    ///
+    /// Counts above the maximum (99 by default, see
+    /// [`Badge::set_max_count`]) render as e.g. `"99+"`. A count of zero
+    /// hides the badge; any other count reveals it.
+    pub fn set_count(&mut self, count: u32) {
+        self.count = Some(count);
+        if count > self.max_count {
+            self.text.set_text(format!("{}+", self.max_count));
+        } else {
+            self.text.set_text(count.to_string());
+        }
+        self.set_is_visible(count > 0);
+    }
+
+    /// Returns the count last set with [`Badge::set_count`], if any.
+    pub fn count(&self) -> Option<u32> {
+        self.count
+    }
+
+    /// Set the maximum count displayed before overflowing to `"{max}+"`.
+    pub fn set_max_count(&mut self, max_count: u32) {
+        self.max_count = max_count;
+        if let Some(count) = self.count {
+            self.set_count(count);
+        }
+    }
+
+    /// Render the badge as a small dot without text, for presence
+    /// indicators.
+    pub fn set_is_dot(&mut self, dot: bool) {
+        self.state.modify(|s| s.dot = dot);
+    }
+
+    pub fn set_is_visible(&self, is_visible: bool) {
+        if is_visible {
+            self.span.remove_style("display");
+        } else {
+            self.span.set_style("display", "none");
+        }
+    }
 }
@@ -3,34 +3,329 @@
 //! Provides [`Button`] (the standard Platinum button) and [`PrimaryButton`]
 //! (a default-action button wrapped in the distinctive Mac OS 9 outer ring).
 //!
-//! Buttons may have an icon, a progress spinner, and a reactive text/flavor.
+//! Buttons may have an icon (on either side of the text), a progress spinner,
+//! and a reactive text/flavor.
 //! Use `step()` to await the next click event.
+use std::{
+    cell::{Cell, RefCell},
+    future::Future,
+};
+
 use mogwai::prelude::*;
 
 use crate::components::{
+    badge::Badge,
     icon::{Icon, IconGlyph, IconSize},
     Flavor,
 };
 
+/// Size modifier for a [`Button`].
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub enum ButtonSize {
+    Small,
+    #[default]
+    Default,
+    Large,
+}
+
+impl ButtonSize {
+    fn class_suffix(&self) -> &str {
+        match self {
+            ButtonSize::Small => " btn-sm",
+            ButtonSize::Default => "",
+            ButtonSize::Large => " btn-lg",
+        }
+    }
+}
+
+/// Builder for constructing a [`Button`] with a fluent API.
+///
+/// ```ignore
+/// let save = Button::builder()
+///     .text("Save")
+///     .flavor(Flavor::Success)
+///     .glyph(IconGlyph::Check)
+///     .size(ButtonSize::Small)
+///     .outline(true)
+///     .build();
+/// ```
+///
+/// Unlike [`Button::new`], a built button has no icon unless
+/// [`ButtonBuilder::glyph`] is called.
+pub struct ButtonBuilder<V: View> {
+    text: String,
+    flavor: Option<Flavor>,
+    glyph: Option<IconGlyph>,
+    icon_placement: IconPlacement,
+    size: ButtonSize,
+    is_outline: bool,
+    is_toggle: bool,
+    href: Option<String>,
+    target: Option<String>,
+    label: Option<String>,
+    loading_text: Option<String>,
+    _view: std::marker::PhantomData<V>,
+}
+
+impl<V: View> Default for ButtonBuilder<V> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<V: View> ButtonBuilder<V> {
+    pub fn new() -> Self {
+        Self {
+            text: String::new(),
+            flavor: None,
+            glyph: None,
+            icon_placement: IconPlacement::Start,
+            size: ButtonSize::Default,
+            is_outline: false,
+            is_toggle: false,
+            href: None,
+            target: None,
+            label: None,
+            loading_text: None,
+            _view: std::marker::PhantomData,
+        }
+    }
+
+    pub fn text(mut self, text: impl Into<String>) -> Self {
+        self.text = text.into();
+        self
+    }
+
+    pub fn flavor(mut self, flavor: Flavor) -> Self {
+        self.flavor = Some(flavor);
+        self
+    }
+
+    /// Show an icon with the given glyph.
+    pub fn glyph(mut self, glyph: IconGlyph) -> Self {
+        self.glyph = Some(glyph);
+        self
+    }
+
+    pub fn icon_placement(mut self, placement: IconPlacement) -> Self {
+        self.icon_placement = placement;
+        self
+    }
+
+    pub fn size(mut self, size: ButtonSize) -> Self {
+        self.size = size;
+        self
+    }
+
+    pub fn outline(mut self, is_outline: bool) -> Self {
+        self.is_outline = is_outline;
+        self
+    }
+
+    /// Make a toggle button. See [`Button::new_toggle`].
+    pub fn toggle(mut self, is_toggle: bool) -> Self {
+        self.is_toggle = is_toggle;
+        self
+    }
+
+    /// Render as a link. See [`Button::new_link`].
+    pub fn href(mut self, href: impl Into<String>) -> Self {
+        self.href = Some(href.into());
+        self
+    }
+
+    /// Set the link target. Only used together with [`ButtonBuilder::href`].
+    pub fn target(mut self, target: impl Into<String>) -> Self {
+        self.target = Some(target.into());
+        self
+    }
+
+    /// Set the `aria-label` and `title`. See [`Button::set_label`].
+    pub fn label(mut self, label: impl Into<String>) -> Self {
+        self.label = Some(label.into());
+        self
+    }
+
+    /// See [`Button::set_loading_text`].
+    pub fn loading_text(mut self, loading_text: impl Into<String>) -> Self {
+        self.loading_text = Some(loading_text.into());
+        self
+    }
+
+    /// Build the button.
+    ///
+    /// If no text is set but a glyph is, this builds an icon-only button,
+    /// or link button (see [`Button::icon_only`]).
+    pub fn build(self) -> Button<V> {
+        let ButtonBuilder {
+            text,
+            flavor,
+            glyph,
+            icon_placement,
+            size,
+            is_outline,
+            is_toggle,
+            href,
+            target,
+            label,
+            loading_text,
+            _view,
+        } = self;
+
+        let mut button = match &href {
+            Some(href) => Button::new_link(&text, href, flavor),
+            None => Button::new(&text, flavor),
+        };
+        let is_icon_only = glyph.is_some() && text.is_empty();
+        match glyph {
+            Some(glyph) if is_icon_only => {
+                button.set_is_icon_only(glyph, label.as_deref().unwrap_or_default());
+            }
+            Some(glyph) => {
+                button.get_icon_mut().set_glyph(glyph);
+                button.set_icon_placement(icon_placement);
+            }
+            None => button.set_has_icon(false),
+        }
+        button.set_size(size);
+        button.set_is_outline(is_outline);
+        if is_toggle {
+            button.set_active(false);
+        }
+        if let Some(target) = target {
+            button.set_target(target);
+        }
+        // Icon-only buttons got their label along with the glyph.
+        if let Some(label) = label.filter(|_| !is_icon_only) {
+            button.set_label(label);
+        }
+        button.set_loading_text(loading_text.as_deref());
+        button
+    }
+}
+
+/// Which side of a [`Button`]'s text its icon is placed on.
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub enum IconPlacement {
+    #[default]
+    Start,
+    End,
+}
+
+struct ButtonState {
+    flavor: Option<Flavor>,
+    size: ButtonSize,
+    is_outline: bool,
+    is_active: bool,
+    is_icon_only: bool,
+}
+
+impl ButtonState {
+    fn class(&self) -> String {
+        let base = match (self.flavor, self.is_outline) {
+            (Some(Flavor::Link), _) => "btn btn-link".to_string(),
+            (Some(flav), true) => format!("btn btn-outline-{flav}"),
+            (None, true) => "btn btn-outline-secondary".to_string(),
+            (Some(flav), false) => format!("btn flavor-{flav}"),
+            (None, false) => "btn".to_string(),
+        };
+        let active = if self.is_active { " active" } else { "" };
+        let icon_only = if self.is_icon_only { " btn-icon" } else { "" };
+        format!("{base}{}{active}{icon_only}", self.size.class_suffix())
+    }
+}
+
+/// Ends a [`Button`]'s loading state when dropped, see
+/// [`run_with_spinner`].
+struct LoadingGuard<'a, V: View> {
+    button: &'a mut Button<V>,
+}
+
+impl<V: View> Drop for LoadingGuard<'_, V> {
+    fn drop(&mut self) {
+        self.button.set_loading(false);
+    }
+}
+
+/// Await the given future with `button` in its loading state.
+///
+/// The loading state ends when the future completes, or when this
+/// future is dropped before it does. See [`Button::set_loading`].
+///
+/// For a [`PrimaryButton`] pass [`PrimaryButton::button_mut`].
+pub async fn run_with_spinner<V: View, T>(
+    button: &mut Button<V>,
+    fut: impl Future<Output = T>,
+) -> T {
+    button.set_loading(true);
+    let _loading = LoadingGuard { button };
+    fut.await
+}
+
 /// A Platinum-styled button with icon, spinner, and reactive text/flavor.
+///
+/// Its size and outline style are also reactive, see [`Button::set_size`]
+/// and [`Button::set_is_outline`].
+///
+/// A button can also act as an on/off control with a pressed state, see
+/// [`Button::new_toggle`] and [`Button::set_active`].
+///
+/// Compact toolbar buttons showing only a glyph are made with
+/// [`Button::icon_only`].
 #[derive(ViewChild, ViewProperties)]
 pub struct Button<V: View> {
     #[child]
     #[properties]
     button: V::Element,
     icon: Icon<V>,
-    flavor: Proxy<Option<Flavor>>,
+    state: Proxy<ButtonState>,
     text: Proxy<String>,
     on_click: V::EventListener,
     spinner: V::Element,
     spinner_attached: bool,
     icon_wrapper: V::Element,
     has_icon: bool,
+    icon_placement: IconPlacement,
+    text_wrapper: V::Element,
+    is_loading: bool,
+    loading_text: Option<String>,
+    text_before_loading: Option<String>,
+    /// Whether the button was disabled before loading started.
+    was_disabled: bool,
+    is_link: bool,
+    /// The `href` of a link button, kept while it is disabled.
+    href: RefCell<Option<String>>,
+    is_disabled: Cell<bool>,
+    badge: Option<Badge<V>>,
 }
 
 impl<V: View> Button<V> {
     pub fn new(text: impl AsRef<str>, flavor: Option<Flavor>) -> Self {
-        let mut flavor = Proxy::new(flavor);
+        Self::from_parts(text, flavor, None)
+    }
+
+    /// Start building a button. See [`ButtonBuilder`].
+    pub fn builder() -> ButtonBuilder<V> {
+        ButtonBuilder::new()
+    }
+
+    /// Create a button that renders as an `<a class="btn">` pointing at `href`.
+    ///
+    /// Clicks are still reported by [`Button::step`], but the browser's
+    /// default navigation is left alone, so middle-click and
+    /// "open in new tab" keep working.
+    pub fn new_link(text: impl AsRef<str>, href: impl AsRef<str>, flavor: Option<Flavor>) -> Self {
+        Self::from_parts(text, flavor, Some(href.as_ref()))
+    }
+
+    fn from_parts(text: impl AsRef<str>, flavor: Option<Flavor>, href: Option<&str>) -> Self {
+        let mut state = Proxy::new(ButtonState {
+            flavor,
+            size: ButtonSize::Default,
+            is_outline: false,
+            is_active: false,
+            is_icon_only: false,
+        });
         let mut text = Proxy::new(text.as_ref().to_string());
         let icon = {
             let i = Icon::new(IconGlyph::Plus, IconSize::Regular);
@@ -38,28 +333,37 @@ impl<V: View> Button<V> {
             i
         };
         rsx! {
-            let button = button(
-                type = "button",
-                class = flavor(
-                    maybe_flav => {
-                        match maybe_flav {
-                            Some(Flavor::Link) => "btn btn-link".to_string(),
-                            Some(flav) => format!("btn flavor-{flav}"),
-                            None => "btn".to_string(),
-                        }
-                    }
-                ),
-                style:cursor = "pointer",
-                on:click = on_click,
-            ) {
-                let icon_wrapper = span() {
-                    {&icon}
-                }
-                span() {
-                    {text(t => t)}
-                }
+            let icon_wrapper = span() {
+                {&icon}
             }
         }
+        rsx! {
+            let text_wrapper = span() {
+                {text(t => t)}
+            }
+        }
+        let button = if let Some(href) = href {
+            rsx! {
+                let a = a(
+                    class = state(s => s.class()),
+                    href = href,
+                    role = "button",
+                ) {}
+            }
+            a
+        } else {
+            rsx! {
+                let button = button(
+                    type = "button",
+                    class = state(s => s.class()),
+                    style:cursor = "pointer",
+                ) {}
+            }
+            button
+        };
+        button.append_child(&icon_wrapper);
+        button.append_child(&text_wrapper);
+        let on_click = button.listen("click");
 
         rsx! {
             let spinner = span(
@@ -71,7 +375,7 @@ impl<V: View> Button<V> {
 
         Button {
             button,
-            flavor,
+            state,
             text,
             on_click,
             spinner,
@@ -79,9 +383,66 @@ impl<V: View> Button<V> {
             icon,
             icon_wrapper,
             has_icon: true,
+            icon_placement: IconPlacement::Start,
+            text_wrapper,
+            is_loading: false,
+            loading_text: None,
+            text_before_loading: None,
+            was_disabled: false,
+            is_link: href.is_some(),
+            href: RefCell::new(href.map(str::to_string)),
+            is_disabled: Cell::new(false),
+            badge: None,
         }
     }
 
+    /// Create a button that shows only the given glyph.
+    ///
+    /// The text span is hidden and `aria_label` is used as both the
+    /// `aria-label` and the `title` (tooltip) of the button.
+    pub fn icon_only(glyph: IconGlyph, aria_label: impl AsRef<str>) -> Self {
+        let mut button = Self::new("", None);
+        button.set_is_icon_only(glyph, aria_label);
+        button
+    }
+
+    /// Show only `glyph`, as in [`Button::icon_only`].
+    fn set_is_icon_only(&mut self, glyph: IconGlyph, aria_label: impl AsRef<str>) {
+        self.icon.set_glyph(glyph);
+        self.icon.remove_class("me-1");
+        self.text_wrapper.set_style("display", "none");
+        self.state.modify(|s| s.is_icon_only = true);
+        self.set_label(aria_label);
+    }
+
+    /// Set the `aria-label` and `title` of the button.
+    ///
+    /// This is what screen readers announce for an [`Button::icon_only`]
+    /// button.
+    pub fn set_label(&self, label: impl AsRef<str>) {
+        self.button.set_property("aria-label", label.as_ref());
+        self.button.set_property("title", label.as_ref());
+    }
+
+    /// Create a toggle button, starting out unpressed.
+    ///
+    /// Toggle buttons carry `aria-pressed`. Clicks do not change the pressed
+    /// state on their own; call [`Button::toggle`] after [`Button::step`]
+    /// resolves:
+    ///
+    /// ```ignore
+    /// let mut bold = Button::new_toggle("Bold", None);
+    /// loop {
+    ///     bold.step().await;
+    ///     bold.toggle();
+    /// }
+    /// ```
+    pub fn new_toggle(text: impl AsRef<str>, flavor: Option<Flavor>) -> Self {
+        let mut button = Self::new(text, flavor);
+        button.set_active(false);
+        button
+    }
+
     pub fn get_icon(&self) -> &Icon<V> {
         &self.icon
     }
@@ -91,11 +452,76 @@ impl<V: View> Button<V> {
     }
 
     pub fn enable(&self) {
-        self.button.remove_property("disabled");
+        self.is_disabled.set(false);
+        if self.is_link {
+            self.button.remove_property("aria-disabled");
+            self.button.remove_property("tabindex");
+            if let Some(href) = self.href.borrow().as_deref() {
+                self.button.set_property("href", href);
+            }
+        } else {
+            self.button.remove_property("disabled");
+        }
     }
 
+    /// Disable the button.
+    ///
+    /// Link buttons (see [`Button::new_link`]) have no `disabled` attribute,
+    /// so they get `aria-disabled` and are taken out of the tab order
+    /// instead. Their `href` is removed until they are enabled again, so
+    /// they can't be followed at all, not even with a middle-click.
     pub fn disable(&self) {
-        self.button.set_property("disabled", "");
+        self.is_disabled.set(true);
+        if self.is_link {
+            self.button.set_property("aria-disabled", "true");
+            self.button.set_property("tabindex", "-1");
+            self.button.remove_property("href");
+        } else {
+            self.button.set_property("disabled", "");
+        }
+    }
+
+    /// Returns `true` if the button is disabled.
+    pub fn is_disabled(&self) -> bool {
+        self.is_disabled.get()
+    }
+
+    /// Returns `true` if this button renders as an `<a>`.
+    pub fn is_link(&self) -> bool {
+        self.is_link
+    }
+
+    /// Set the `href` of a link button.
+    ///
+    /// A disabled link button takes the `href` once it is enabled.
+    ///
+    /// ## Note
+    /// This has no effect on buttons that were not created with
+    /// [`Button::new_link`].
+    pub fn set_href(&self, href: impl AsRef<str>) {
+        if self.is_link {
+            if !self.is_disabled() {
+                self.button.set_property("href", href.as_ref());
+            }
+            *self.href.borrow_mut() = Some(href.as_ref().to_string());
+        }
+    }
+
+    /// Set the `target` of a link button, e.g. `"_blank"`.
+    ///
+    /// A `"_blank"` target also sets `rel="noopener noreferrer"`.
+    ///
+    /// ## Note
+    /// This has no effect on buttons that were not created with
+    /// [`Button::new_link`].
+    pub fn set_target(&self, target: impl AsRef<str>) {
+        if self.is_link {
+            let target = target.as_ref();
+            self.button.set_property("target", target);
+            if target == "_blank" {
+                self.button.set_property("rel", "noopener noreferrer");
+            }
+        }
     }
 
     pub fn start_spinner(&mut self) {
@@ -116,8 +542,85 @@ impl<V: View> Button<V> {
         self.text.set(text.as_ref().into());
     }
 
+    /// Set the text shown while the button is loading.
+    ///
+    /// Pass `None` (the default) to keep the current text while loading.
+    pub fn set_loading_text(&mut self, loading_text: Option<&str>) {
+        self.loading_text = loading_text.map(str::to_string);
+    }
+
+    /// Put the button into or out of its loading state.
+    ///
+    /// While loading the button is disabled, shows its spinner and, if
+    /// [`Button::set_loading_text`] was called, shows the loading text.
+    /// Leaving the loading state restores the previous text, stops the
+    /// spinner and enables the button again, unless it was disabled before
+    /// loading started.
+    ///
+    /// ## Note
+    /// Calling [`Button::set_text`] while loading sets the text that is shown
+    /// during loading, and is overwritten when loading ends.
+    pub fn set_loading(&mut self, is_loading: bool) {
+        if self.is_loading == is_loading {
+            return;
+        }
+        self.is_loading = is_loading;
+        if is_loading {
+            self.was_disabled = self.is_disabled();
+            self.disable();
+            self.start_spinner();
+            if let Some(loading_text) = self.loading_text.clone() {
+                self.text_before_loading = Some((*self.text).clone());
+                self.text.set(loading_text);
+            }
+        } else {
+            if let Some(text) = self.text_before_loading.take() {
+                self.text.set(text);
+            }
+            self.stop_spinner();
+            if !self.was_disabled {
+                self.enable();
+            }
+        }
+    }
+
+    /// Returns `true` if the button is in its loading state.
+    pub fn is_loading(&self) -> bool {
+        self.is_loading
+    }
+
     pub fn set_flavor(&mut self, flavor: Option<Flavor>) {
-        self.flavor.set(flavor);
+        self.state.modify(|s| s.flavor = flavor);
+    }
+
+    pub fn set_size(&mut self, size: ButtonSize) {
+        self.state.modify(|s| s.size = size);
+    }
+
+    /// Render the button with a flavored border and no fill.
+    ///
+    /// Has no effect on [`Flavor::Link`] buttons.
+    pub fn set_is_outline(&mut self, is_outline: bool) {
+        self.state.modify(|s| s.is_outline = is_outline);
+    }
+
+    /// Set the pressed state, applying the `active` class and `aria-pressed`.
+    pub fn set_active(&mut self, is_active: bool) {
+        self.state.modify(|s| s.is_active = is_active);
+        self.button
+            .set_property("aria-pressed", if is_active { "true" } else { "false" });
+    }
+
+    /// Returns `true` if the button is pressed.
+    pub fn is_active(&self) -> bool {
+        self.state.is_active
+    }
+
+    /// Flip the pressed state, returning the new state.
+    pub fn toggle(&mut self) -> bool {
+        let is_active = !self.is_active();
+        self.set_active(is_active);
+        is_active
     }
 
     /// Show or hide the icon, reclaiming the layout space.
@@ -130,8 +633,79 @@ impl<V: View> Button<V> {
         }
     }
 
+    /// Attach a badge to the button's top-right corner, replacing any
+    /// previous one, which is returned.
+    ///
+    /// Pass `None` to remove the badge. The badge is made positioned
+    /// (see [`Badge::set_is_positioned`]).
+    pub fn set_badge(&mut self, badge: Option<Badge<V>>) -> Option<Badge<V>> {
+        let previous = self.badge.take();
+        if let Some(previous) = previous.as_ref() {
+            self.button.remove_child(previous);
+        }
+        if let Some(mut badge) = badge {
+            badge.set_is_positioned(true);
+            self.button.set_style("position", "relative");
+            self.button.append_child(&badge);
+            self.badge = Some(badge);
+        }
+        previous
+    }
+
+    pub fn get_badge(&self) -> Option<&Badge<V>> {
+        self.badge.as_ref()
+    }
+
+    pub fn get_badge_mut(&mut self) -> Option<&mut Badge<V>> {
+        self.badge.as_mut()
+    }
+
+    /// Place the icon before or after the text.
+    pub fn set_icon_placement(&mut self, placement: IconPlacement) {
+        if self.icon_placement == placement {
+            return;
+        }
+        self.icon_placement = placement;
+        // Icon-only buttons have no text to space the icon from.
+        let has_text = !self.state.is_icon_only;
+        // Re-appending moves the existing nodes into the new order.
+        match placement {
+            IconPlacement::Start => {
+                self.icon.remove_class("ms-1");
+                if has_text {
+                    self.icon.add_class("me-1");
+                }
+                self.button.append_child(&self.icon_wrapper);
+                self.button.append_child(&self.text_wrapper);
+            }
+            IconPlacement::End => {
+                self.icon.remove_class("me-1");
+                if has_text {
+                    self.icon.add_class("ms-1");
+                }
+                self.button.append_child(&self.text_wrapper);
+                self.button.append_child(&self.icon_wrapper);
+            }
+        }
+        if self.spinner_attached {
+            self.button.append_child(&self.spinner);
+        }
+    }
+
+    pub fn icon_placement(&self) -> IconPlacement {
+        self.icon_placement
+    }
+
+    /// Await the next click.
+    ///
+    /// Clicks while the button is disabled are ignored.
     pub async fn step(&self) -> V::Event {
-        self.on_click.next().await
+        loop {
+            let event = self.on_click.next().await;
+            if !self.is_disabled() {
+                return event;
+            }
+        }
     }
 }
 
@@ -180,6 +754,26 @@ impl<V: View> PrimaryButton<V> {
         self.button.set_flavor(flavor);
     }
 
+    pub fn set_size(&mut self, size: ButtonSize) {
+        self.button.set_size(size);
+    }
+
+    pub fn set_is_outline(&mut self, is_outline: bool) {
+        self.button.set_is_outline(is_outline);
+    }
+
+    pub fn set_active(&mut self, is_active: bool) {
+        self.button.set_active(is_active);
+    }
+
+    pub fn is_active(&self) -> bool {
+        self.button.is_active()
+    }
+
+    pub fn toggle(&mut self) -> bool {
+        self.button.toggle()
+    }
+
     pub fn enable(&self) {
         self.button.enable();
     }
@@ -188,6 +782,10 @@ impl<V: View> PrimaryButton<V> {
         self.button.disable();
     }
 
+    pub fn is_disabled(&self) -> bool {
+        self.button.is_disabled()
+    }
+
     pub fn start_spinner(&mut self) {
         self.button.start_spinner();
     }
@@ -196,10 +794,38 @@ impl<V: View> PrimaryButton<V> {
         self.button.stop_spinner();
     }
 
+    pub fn set_loading_text(&mut self, loading_text: Option<&str>) {
+        self.button.set_loading_text(loading_text);
+    }
+
+    pub fn set_loading(&mut self, is_loading: bool) {
+        self.button.set_loading(is_loading);
+    }
+
+    pub fn is_loading(&self) -> bool {
+        self.button.is_loading()
+    }
+
     pub fn set_has_icon(&mut self, has_icon: bool) {
         self.button.set_has_icon(has_icon);
     }
 
+    pub fn set_icon_placement(&mut self, placement: IconPlacement) {
+        self.button.set_icon_placement(placement);
+    }
+
+    pub fn set_badge(&mut self, badge: Option<Badge<V>>) -> Option<Badge<V>> {
+        self.button.set_badge(badge)
+    }
+
+    pub fn get_badge(&self) -> Option<&Badge<V>> {
+        self.button.get_badge()
+    }
+
+    pub fn get_badge_mut(&mut self) -> Option<&mut Badge<V>> {
+        self.button.get_badge_mut()
+    }
+
     pub fn get_icon(&self) -> &Icon<V> {
         self.button.get_icon()
     }
@@ -215,12 +841,29 @@ impl<V: View> PrimaryButton<V> {
 
 #[cfg(feature = "library")]
 pub mod library {
-    use std::pin::Pin;
-
-    use futures_lite::{FutureExt, Stream};
+    use futures_lite::FutureExt;
     use mogwai::future::MogwaiFutureExt;
 
     use super::*;
+    use crate::library::{log_event, Knob, KnobEvent, Knobs};
+
+    const FLAVORS: [(&str, Flavor); 9] = [
+        ("Primary", Flavor::Primary),
+        ("Secondary", Flavor::Secondary),
+        ("Success", Flavor::Success),
+        ("Danger", Flavor::Danger),
+        ("Warning", Flavor::Warning),
+        ("Info", Flavor::Info),
+        ("Light", Flavor::Light),
+        ("Dark", Flavor::Dark),
+        ("Link", Flavor::Link),
+    ];
+
+    const SIZES: [(&str, ButtonSize); 3] = [
+        ("Small", ButtonSize::Small),
+        ("Default", ButtonSize::Default),
+        ("Large", ButtonSize::Large),
+    ];
 
     #[derive(ViewChild)]
     pub struct ButtonLibraryItem<V: View> {
@@ -229,7 +872,12 @@ pub mod library {
         clicks: usize,
         button: Button<V>,
         primary_button: PrimaryButton<V>,
-        flavor_changes: Pin<Box<dyn Stream<Item = Flavor>>>,
+        save_button: Button<V>,
+        knobs: Knobs<V>,
+        flavor: Knob<Flavor>,
+        size: Knob<ButtonSize>,
+        is_outline: Knob<bool>,
+        is_disabled: Knob<bool>,
     }
 
     impl<V: View> Default for ButtonLibraryItem<V> {
@@ -238,81 +886,166 @@ pub mod library {
             disabled_btn.set_has_icon(false);
             disabled_btn.disable();
 
+            let small_btn = Button::builder()
+                .text("Small")
+                .flavor(Flavor::Success)
+                .size(ButtonSize::Small)
+                .build();
+
+            let large_btn = Button::builder()
+                .text("Large")
+                .flavor(Flavor::Success)
+                .size(ButtonSize::Large)
+                .build();
+
+            let outline_btns = [Flavor::Primary, Flavor::Danger, Flavor::Info]
+                .into_iter()
+                .map(|flavor| {
+                    let mut btn = Button::new(format!("Outline {flavor}"), Some(flavor));
+                    btn.set_has_icon(false);
+                    btn.set_is_outline(true);
+                    btn
+                })
+                .collect::<Vec<_>>();
+
+            let icon_btns = [
+                (IconGlyph::Plus, "Add"),
+                (IconGlyph::Pen, "Edit"),
+                (IconGlyph::Trash, "Delete"),
+            ]
+            .into_iter()
+            .map(|(glyph, label)| Button::icon_only(glyph, label))
+            .collect::<Vec<_>>();
+
+            let mut next_btn = Button::new("Next", None);
+            next_btn.get_icon_mut().set_glyph(IconGlyph::ArrowRight);
+            next_btn.set_icon_placement(IconPlacement::End);
+
+            let mut save_button = Button::new("Save", Some(Flavor::Success));
+            save_button.get_icon_mut().set_glyph(IconGlyph::Check);
+            save_button.set_loading_text(Some("Saving\u{2026}"));
+
+            let mut inbox_btn = Button::new("Inbox", None);
+            inbox_btn.get_icon_mut().set_glyph(IconGlyph::Envelope);
+            inbox_btn.set_badge(Some(Badge::positioned("3", Flavor::Danger)));
+
+            let mut docs_link = Button::new_link(
+                "Docs",
+                "https://docs.rs/mogwai",
+                Some(Flavor::Info),
+            );
+            docs_link.get_icon_mut().set_glyph(IconGlyph::ArrowRight);
+            docs_link.set_icon_placement(IconPlacement::End);
+            docs_link.set_target("_blank");
+
             let mut disabled_primary = PrimaryButton::new("Disabled Primary", None);
             disabled_primary.set_has_icon(false);
             disabled_primary.disable();
 
+            let mut knobs = Knobs::default();
+            let flavor = knobs.select("Flavor", FLAVORS, 0);
+            let size = knobs.select("Size", SIZES, 1);
+            let is_outline = knobs.toggle("Outline", false);
+            let is_disabled = knobs.toggle("Disabled", false);
+
             rsx! {
                 let wrapper = fieldset() {
                     div(class = "mb-3") {
                         h4() { "Standard Buttons" }
                         div(class = "d-flex gap-2 flex-wrap align-items-center mb-2") {
                             let button = {Button::new("0 clicks", Some(Flavor::Primary))}
+                            {&save_button}
                             {&disabled_btn}
                         }
                     }
                     div(class = "mb-3") {
-                        h4() { "Primary (Ringed) Buttons" }
+                        h4() { "Sizes, Outlines and Icons" }
                         div(class = "d-flex gap-2 flex-wrap align-items-center mb-2") {
-                            let primary_button = {PrimaryButton::new("Primary Action", None)}
-                            {&disabled_primary}
+                            {&small_btn}
+                            {&large_btn}
+                        }
+                        div(class = "d-flex gap-2 flex-wrap align-items-center mb-2") {
+                            {&outline_btns}
+                        }
+                        div(class = "d-flex gap-2 flex-wrap align-items-center mb-2") {
+                            {&icon_btns}
+                            {&next_btn}
+                            {&docs_link}
+                            {&inbox_btn}
                         }
                     }
                     div(class = "mb-3") {
-                        h4() { "Flavor Controls" }
-                        ul() {
-                            li() {
-                                a(
-                                    href = "#",
-                                    on:click = click_primary
-                                ){
-                                    "Change to primary"
-                                }
-                            }
-                            li() {
-                                a(
-                                    href = "#",
-                                    on:click = click_warning
-                                ) {
-                                    "Change to warning"
-                                }
-                            }
+                        h4() { "Primary (Ringed) Buttons" }
+                        div(class = "d-flex gap-2 flex-wrap align-items-center mb-2") {
+                            let primary_button = {PrimaryButton::new("Primary Action", None)}
+                            {&disabled_primary}
                         }
                     }
+                    {&knobs}
                 }
             }
 
-            let flavor_changes = Box::pin(futures_lite::stream::unfold(
-                (click_primary, click_warning),
-                |(prim, warn)| async move {
-                    let flav = prim
-                        .next()
-                        .map(|_| Flavor::Primary)
-                        .or(warn.next().map(|_| Flavor::Warning))
-                        .await;
-                    Some((flav, (prim, warn)))
-                },
-            ));
             Self {
                 wrapper,
                 clicks: 0,
                 button,
                 primary_button,
-                flavor_changes,
+                save_button,
+                knobs,
+                flavor,
+                size,
+                is_outline,
+                is_disabled,
             }
         }
     }
 
     impl<V: View> ButtonLibraryItem<V> {
+        /// Apply a change of one of the knobs to the "clicks" and "Primary
+        /// Action" buttons.
+        fn apply_knob(&mut self, event: KnobEvent) {
+            if let Some(flavor) = self.flavor.changed(&event) {
+                self.button.set_flavor(Some(flavor));
+                self.primary_button.set_flavor(Some(flavor));
+            }
+            if let Some(size) = self.size.changed(&event) {
+                self.button.set_size(size);
+                self.primary_button.set_size(size);
+            }
+            if let Some(is_outline) = self.is_outline.changed(&event) {
+                self.button.set_is_outline(is_outline);
+                self.primary_button.set_is_outline(is_outline);
+            }
+            if let Some(is_disabled) = self.is_disabled.changed(&event) {
+                if is_disabled {
+                    self.button.disable();
+                    self.primary_button.disable();
+                } else {
+                    self.button.enable();
+                    self.primary_button.enable();
+                }
+            }
+        }
+
         pub async fn step(&mut self) {
-            use futures_lite::StreamExt;
-            let btn_fut = self.button.step().map(|e| Ok(Some(e)));
-            let primary_fut = self.primary_button.step().map(|e| Ok(Some(e)));
-            let flavor_fut = self.flavor_changes.next().map(Err);
+            enum Action {
+                Click(&'static str),
+                Save,
+                Knob(KnobEvent),
+            }
+
+            let btn_fut = self.button.step().map(|_| Action::Click("Button"));
+            let primary_fut = self
+                .primary_button
+                .step()
+                .map(|_| Action::Click("PrimaryButton"));
+            let save_fut = self.save_button.step().map(|_| Action::Save);
+            let knob_fut = self.knobs.step().map(Action::Knob);
 
-            match btn_fut.or(primary_fut).or(flavor_fut).await {
-                Ok(Some(_event)) => {
+            match btn_fut.or(primary_fut).or(save_fut).or(knob_fut).await {
+                Action::Click(source) => {
                     log::debug!("got click");
+                    log_event(source, "Clicked");
                     self.clicks += 1;
                     let text = if self.clicks == 1 {
                         "1 click".into()
@@ -322,11 +1055,11 @@ pub mod library {
                     self.button.set_text(&text);
                     self.primary_button.set_text(text);
                 }
-                Err(Some(flav)) => {
-                    self.button.set_flavor(Some(flav));
-                    self.primary_button.set_flavor(Some(flav));
+                Action::Save => {
+                    log_event("Button", "Clicked Save");
+                    run_with_spinner(&mut self.save_button, mogwai::time::wait_millis(1500)).await;
                 }
-                _ => unreachable!("button library step"),
+                Action::Knob(event) => self.apply_knob(event),
             }
         }
     }
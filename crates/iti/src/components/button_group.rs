@@ -4,7 +4,10 @@
 //! Generic over the child type `T`, which is typically [`super::button::Button`]
 //! but can be any [`ViewChild`].
 //!
-//! Supports reactive size and vertical/horizontal orientation.
+//! Supports reactive size and vertical/horizontal orientation, and an
+//! exclusive-selection mode for segmented controls.
+//!
+//! Several groups can be laid out together in a [`ButtonToolbar`].
 use std::future::Future;
 
 use mogwai::prelude::*;
@@ -46,11 +49,42 @@ impl ButtonGroupState {
     }
 }
 
+/// How a [`ButtonGroup`] treats clicks on its buttons.
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub enum ButtonGroupMode {
+    /// Buttons are independent; clicks are only reported.
+    #[default]
+    Independent,
+    /// At most one button is active at a time. Clicking a button marks it
+    /// active and deselects the others, like a segmented control.
+    Exclusive,
+}
+
 /// Event emitted when a button group item is clicked.
 #[derive(Debug)]
-pub struct ButtonGroupEvent<V: View> {
-    pub index: usize,
-    pub event: V::Event,
+pub enum ButtonGroupEvent<V: View> {
+    /// A button was clicked without changing the selection.
+    ///
+    /// In [`ButtonGroupMode::Exclusive`] this is emitted when the already
+    /// selected button is clicked again.
+    Clicked { index: usize, event: V::Event },
+    /// In [`ButtonGroupMode::Exclusive`], the button at `index` became the
+    /// selected one.
+    SelectionChanged {
+        index: usize,
+        previous: Option<usize>,
+        event: V::Event,
+    },
+}
+
+impl<V: View> ButtonGroupEvent<V> {
+    /// The index of the clicked button.
+    pub fn index(&self) -> usize {
+        match self {
+            ButtonGroupEvent::Clicked { index, .. } => *index,
+            ButtonGroupEvent::SelectionChanged { index, .. } => *index,
+        }
+    }
 }
 
 /// A Bootstrap button group that owns its children.
@@ -61,6 +95,8 @@ pub struct ButtonGroup<V: View> {
     div: V::Element,
     buttons: Vec<Button<V>>,
     state: Proxy<ButtonGroupState>,
+    mode: ButtonGroupMode,
+    selected: Option<usize>,
 }
 
 impl<V: View> Default for ButtonGroup<V> {
@@ -81,6 +117,8 @@ impl<V: View> Default for ButtonGroup<V> {
             div,
             buttons: Vec::new(),
             state,
+            mode: ButtonGroupMode::Independent,
+            selected: None,
         }
     }
 }
@@ -114,6 +152,11 @@ impl<V: View> ButtonGroup<V> {
         if let Some(existing) = self.buttons.get(index) {
             self.div.insert_child_before(existing, Some(&item));
             self.buttons.insert(index, item);
+            if let Some(selected) = self.selected.as_mut() {
+                if *selected >= index {
+                    *selected += 1;
+                }
+            }
         } else {
             self.div.append_child(&item);
             self.buttons.push(item);
@@ -127,6 +170,11 @@ impl<V: View> ButtonGroup<V> {
     pub fn remove(&mut self, index: usize) -> Button<V> {
         let b = self.buttons.remove(index);
         self.div.remove_child(&b);
+        self.selected = match self.selected {
+            Some(selected) if selected == index => None,
+            Some(selected) if selected > index => Some(selected - 1),
+            selected => selected,
+        };
         b
     }
 
@@ -153,20 +201,68 @@ impl<V: View> ButtonGroup<V> {
         self.state.modify(|s| s.is_vertical = is_vertical);
     }
 
-    fn item_click_events(&self) -> impl Future<Output = ButtonGroupEvent<V>> + '_ {
+    /// Sets how clicks on the buttons are treated.
+    ///
+    /// Switching to [`ButtonGroupMode::Independent`] clears the selection.
+    pub fn set_mode(&mut self, mode: ButtonGroupMode) {
+        self.mode = mode;
+        if mode == ButtonGroupMode::Independent {
+            self.set_selected(None);
+        }
+    }
+
+    pub fn mode(&self) -> ButtonGroupMode {
+        self.mode
+    }
+
+    /// Returns the index of the selected button in
+    /// [`ButtonGroupMode::Exclusive`].
+    pub fn selected(&self) -> Option<usize> {
+        self.selected
+    }
+
+    /// Select the button at the given index, deselecting all others.
+    ///
+    /// Pass `None` to deselect all buttons. Out-of-bounds indices deselect
+    /// all buttons.
+    pub fn set_selected(&mut self, index: Option<usize>) {
+        let index = index.filter(|i| *i < self.buttons.len());
+        for (i, button) in self.buttons.iter_mut().enumerate() {
+            button.set_active(Some(i) == index);
+        }
+        self.selected = index;
+    }
+
+    fn item_click_events(&self) -> impl Future<Output = (usize, V::Event)> + '_ {
         use mogwai::future::*;
 
-        let events = self.buttons.iter().enumerate().map(|(index, item)| {
-            item.step()
-                .map(move |event| ButtonGroupEvent { index, event })
-        });
+        let events = self
+            .buttons
+            .iter()
+            .enumerate()
+            .map(|(index, item)| item.step().map(move |event| (index, event)));
         race_all(events)
     }
 
     /// Awaits the next click on any child and returns a [`ButtonGroupEvent`]
     /// indicating which item was clicked.
-    pub async fn step(&self) -> ButtonGroupEvent<V> {
-        self.item_click_events().await
+    ///
+    /// In [`ButtonGroupMode::Exclusive`] the clicked button is selected
+    /// before this returns.
+    pub async fn step(&mut self) -> ButtonGroupEvent<V> {
+        let (index, event) = self.item_click_events().await;
+        match self.mode {
+            ButtonGroupMode::Exclusive if self.selected != Some(index) => {
+                let previous = self.selected;
+                self.set_selected(Some(index));
+                ButtonGroupEvent::SelectionChanged {
+                    index,
+                    previous,
+                    event,
+                }
+            }
+            _ => ButtonGroupEvent::Clicked { index, event },
+        }
     }
 
     /// Returns an iterator over the items.
@@ -190,31 +286,138 @@ impl<V: View> FromIterator<Button<V>> for ButtonGroup<V> {
     }
 }
 
+/// Event emitted by a [`ButtonToolbar`].
+#[derive(Debug)]
+pub struct ButtonToolbarEvent<V: View> {
+    /// Index of the group within the toolbar (input groups are not counted).
+    pub group: usize,
+    /// The event from that group.
+    pub event: ButtonGroupEvent<V>,
+}
+
+/// A Bootstrap `btn-toolbar` that lays out [`ButtonGroup`]s and input groups
+/// in a wrapping row.
+///
+/// Events from all of its button groups are funnelled into
+/// [`ButtonToolbar::step`], tagged with the group's index.
+#[derive(ViewChild, ViewProperties)]
+pub struct ButtonToolbar<V: View> {
+    #[child]
+    #[properties]
+    div: V::Element,
+    groups: Vec<ButtonGroup<V>>,
+    input_groups: Vec<V::Element>,
+}
+
+impl<V: View> Default for ButtonToolbar<V> {
+    fn default() -> Self {
+        rsx! {
+            let div = div(
+                class = "btn-toolbar gap-2",
+                role = "toolbar",
+            ) {}
+        }
+
+        Self {
+            div,
+            groups: Vec::new(),
+            input_groups: Vec::new(),
+        }
+    }
+}
+
+impl<V: View> ButtonToolbar<V> {
+    /// Append a button group and return its group index.
+    pub fn push_group(&mut self, group: ButtonGroup<V>) -> usize {
+        let index = self.groups.len();
+        self.div.append_child(&group);
+        self.groups.push(group);
+        index
+    }
+
+    /// Append arbitrary content wrapped in an `input-group`.
+    ///
+    /// ## Note
+    /// The toolbar only owns the wrapper; the caller keeps ownership of
+    /// `content` and is responsible for its events.
+    pub fn push_input_group(&mut self, content: &impl ViewChild<V>) {
+        rsx! {
+            let wrapper = div(class = "input-group") {
+                {content}
+            }
+        }
+        self.div.append_child(&wrapper);
+        self.input_groups.push(wrapper);
+    }
+
+    /// Returns a reference to the group at the given index.
+    pub fn get(&self, index: usize) -> Option<&ButtonGroup<V>> {
+        self.groups.get(index)
+    }
+
+    /// Returns a mutable reference to the group at the given index.
+    pub fn get_mut(&mut self, index: usize) -> Option<&mut ButtonGroup<V>> {
+        self.groups.get_mut(index)
+    }
+
+    /// Returns the number of button groups in the toolbar.
+    pub fn len(&self) -> usize {
+        self.groups.len()
+    }
+
+    /// Returns `true` if the toolbar contains no button groups.
+    pub fn is_empty(&self) -> bool {
+        self.groups.is_empty()
+    }
+
+    /// Awaits the next event from any of the toolbar's button groups.
+    ///
+    /// ## Note
+    /// If the toolbar has no button groups this will wait indefinitely.
+    pub async fn step(&mut self) -> ButtonToolbarEvent<V> {
+        use mogwai::future::*;
+
+        if self.groups.is_empty() {
+            return std::future::pending().await;
+        }
+        let events = self.groups.iter_mut().enumerate().map(|(group, g)| {
+            g.step()
+                .map(move |event| ButtonToolbarEvent { group, event })
+        });
+        race_all(events).await
+    }
+}
+
 #[cfg(feature = "library")]
 pub mod library {
     use futures_lite::FutureExt;
+    use mogwai::future::MogwaiFutureExt;
 
     use super::*;
-    use crate::components::{button::Button, icon::IconGlyph, Flavor};
-
-    const SIZES: [ButtonGroupSize; 3] = [
-        ButtonGroupSize::Small,
-        ButtonGroupSize::Default,
-        ButtonGroupSize::Large,
+    use crate::{
+        components::{button::Button, icon::IconGlyph, Flavor},
+        library::{log_event, Knob, KnobEvent, Knobs},
+    };
+
+    const SIZES: [(&str, ButtonGroupSize); 3] = [
+        ("Small", ButtonGroupSize::Small),
+        ("Default", ButtonGroupSize::Default),
+        ("Large", ButtonGroupSize::Large),
     ];
 
-    const SIZE_LABELS: [&str; 3] = ["Small", "Default", "Large"];
-
     /// Library sandbox for [`ButtonGroup`].
     #[derive(ViewChild)]
     pub struct ButtonGroupLibraryItem<V: View> {
         #[child]
         pub wrapper: V::Element,
         subject_group: ButtonGroup<V>,
+        segmented_group: ButtonGroup<V>,
+        toolbar: ButtonToolbar<V>,
         controls_group: ButtonGroup<V>,
+        knobs: Knobs<V>,
+        size: Knob<ButtonGroupSize>,
+        is_vertical: Knob<bool>,
         status_text: V::Text,
-        size_index: usize,
-        is_vertical: bool,
         count: usize,
     }
 
@@ -231,14 +434,53 @@ pub mod library {
                 subject_group.push(btn);
             }
 
+            let mut segmented_group: ButtonGroup<V> = ["Day", "Week", "Month"]
+                .into_iter()
+                .map(|label| {
+                    let mut btn = Button::new(label, None);
+                    btn.set_has_icon(false);
+                    btn
+                })
+                .collect();
+            segmented_group.set_mode(ButtonGroupMode::Exclusive);
+            segmented_group.set_selected(Some(0));
+
+            let mut toolbar = ButtonToolbar::default();
+            toolbar.push_group(
+                [(IconGlyph::ArrowLeft, "Back"), (IconGlyph::ArrowRight, "Forward")]
+                    .into_iter()
+                    .map(|(glyph, label)| Button::icon_only(glyph, label))
+                    .collect(),
+            );
+            let mut align_group: ButtonGroup<V> = ["Left", "Center", "Right"]
+                .into_iter()
+                .map(|label| {
+                    let mut btn = Button::new(label, None);
+                    btn.set_has_icon(false);
+                    btn
+                })
+                .collect();
+            align_group.set_mode(ButtonGroupMode::Exclusive);
+            toolbar.push_group(align_group);
+            rsx! {
+                let search = input(
+                    type = "text",
+                    class = "form-control",
+                    placeholder = "Search\u{2026}",
+                ) {}
+            }
+            toolbar.push_input_group(&search);
+
             let mut controls_group: ButtonGroup<V> = ButtonGroup::default();
             controls_group.extend([
                 Button::new("Add button", None),
                 Button::new("Remove last", None),
-                Button::new("Cycle size", None),
-                Button::new("Toggle vertical", None),
             ]);
 
+            let mut knobs = Knobs::default();
+            let size = knobs.select("Size", SIZES, 1);
+            let is_vertical = knobs.toggle("Vertical", false);
+
             let status_text = V::Text::new("Click a button in the group");
 
             rsx! {
@@ -246,22 +488,32 @@ pub mod library {
                     div(class = "mb-3") {
                         {&subject_group}
                     }
+                    div(class = "mb-3") {
+                        {&segmented_group}
+                    }
+                    div(class = "mb-3") {
+                        {&toolbar}
+                    }
                     div(class = "mb-3") {
                         p(class = "text-muted") {
                             {&status_text}
                         }
                     }
                     {&controls_group}
+                    {&knobs}
                 }
             }
 
             Self {
                 wrapper,
                 subject_group,
+                segmented_group,
+                toolbar,
                 controls_group,
+                knobs,
+                size,
+                is_vertical,
                 status_text,
-                size_index: 1,
-                is_vertical: false,
                 count: 3,
             }
         }
@@ -274,17 +526,49 @@ pub mod library {
             enum Group<V: View> {
                 Control(ButtonGroupEvent<V>),
                 Subject(ButtonGroupEvent<V>),
+                Segmented(ButtonGroupEvent<V>),
+                Toolbar(ButtonToolbarEvent<V>),
+                Knob(KnobEvent),
             }
-            let control = async { Group::Control(self.controls_group.step().await) };
-            let subject = async { Group::Subject(self.subject_group.step().await) };
-            let event = control.or(subject).await;
+            let event = self
+                .controls_group
+                .step()
+                .map(Group::Control)
+                .or(self.subject_group.step().map(Group::Subject))
+                .or(self.segmented_group.step().map(Group::Segmented))
+                .or(self.toolbar.step().map(Group::Toolbar))
+                .or(self.knobs.step().map(Group::Knob))
+                .await;
 
             match event {
                 Group::Subject(ev) => {
+                    log_event("ButtonGroup", format!("Clicked {}", ev.index()));
+                    self.status_text
+                        .set_text(format!("Clicked button at index {}", ev.index()));
+                }
+                Group::Segmented(ButtonGroupEvent::SelectionChanged {
+                    index, previous, ..
+                }) => {
+                    log_event("ButtonGroup", format!("Selected {index}"));
+                    self.status_text.set_text(format!(
+                        "Selection changed from {previous:?} to {index}"
+                    ));
+                }
+                Group::Segmented(ButtonGroupEvent::Clicked { index, .. }) => {
                     self.status_text
-                        .set_text(format!("Clicked button at index {}", ev.index));
+                        .set_text(format!("Index {index} is already selected"));
                 }
-                Group::Control(ev) => match ev.index {
+                Group::Toolbar(ButtonToolbarEvent { group, event }) => {
+                    log_event(
+                        "ButtonToolbar",
+                        format!("Clicked group {group}, button {}", event.index()),
+                    );
+                    self.status_text.set_text(format!(
+                        "Toolbar group {group}, button {}",
+                        event.index()
+                    ));
+                }
+                Group::Control(ev) => match ev.index() {
                     0 => {
                         self.count += 1;
                         let flavors = [
@@ -316,23 +600,22 @@ pub mod library {
                             self.status_text.set_text("No buttons to remove");
                         }
                     }
-                    2 => {
-                        self.size_index = (self.size_index + 1) % SIZES.len();
-                        self.subject_group.set_size(SIZES[self.size_index]);
-                        self.status_text
-                            .set_text(format!("Size: {}", SIZE_LABELS[self.size_index]));
+                    _ => unreachable!(),
+                },
+                Group::Knob(event) => {
+                    if let Some(size) = self.size.changed(&event) {
+                        self.subject_group.set_size(size);
+                        self.status_text.set_text(format!("Size: {size:?}"));
                     }
-                    3 => {
-                        self.is_vertical = !self.is_vertical;
-                        self.subject_group.set_is_vertical(self.is_vertical);
-                        self.status_text.set_text(if self.is_vertical {
+                    if let Some(is_vertical) = self.is_vertical.changed(&event) {
+                        self.subject_group.set_is_vertical(is_vertical);
+                        self.status_text.set_text(if is_vertical {
                             "Orientation: vertical"
                         } else {
                             "Orientation: horizontal"
                         });
                     }
-                    _ => unreachable!(),
-                },
+                }
             }
         }
     }
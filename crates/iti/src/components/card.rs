@@ -1,12 +1,56 @@
 //! Card component.
 //!
-//! A Bootstrap card container with optional header, body, and footer sections.
+//! A Bootstrap card container with optional header, body, and footer sections,
+//! and optional image caps.
+//!
+//! A card can also be made collapsible, with a chevron toggle in its header,
+//! or topped with nav-tabs that switch its body (see [`TabbedCard`]).
+//!
+//! Several cards can be laid out side by side with [`CardGroup`].
+use std::collections::HashMap;
+
 use mogwai::prelude::*;
 
+use super::{
+    icon::{Icon, IconGlyph, IconSize},
+    pane::Panes,
+    tab::{TabList, TabListEvent},
+};
+use crate::id::Id;
+
+/// Event emitted by a collapsible [`Card`].
+pub enum CardEvent {
+    /// The body was shown.
+    Expanded,
+    /// The body was hidden.
+    Collapsed,
+}
+
+/// Header toggle of a collapsible [`Card`].
+struct CardToggle<V: View> {
+    button: V::Element,
+    chevron: Icon<V>,
+    click: V::EventListener,
+}
+
+fn show_image<V: View>(img: &V::Element, src: &str, alt: &str) {
+    img.set_property("src", src);
+    img.set_property("alt", alt);
+    img.remove_style("display");
+}
+
 /// A Bootstrap card.
 ///
 /// Provides a structured container with optional header, body, and footer
 /// sections. Each section can hold arbitrary content via [`ProxyChild`].
+///
+/// An image can be shown above or below the content with
+/// [`Card::set_image_top`] / [`Card::set_image_bottom`], or behind the body
+/// with [`Card::with_image_overlay`]. Images are lazy-loaded.
+///
+/// A card made with [`Card::collapsible`] has a chevron toggle in its header
+/// that shows and hides the body and footer; [`Card::step`] reports each
+/// change.
 #[derive(ViewChild, ViewProperties)]
 pub struct Card<V: View> {
     #[child]
@@ -16,9 +60,13 @@ pub struct Card<V: View> {
     header_inner: V::Element,
     body: V::Element,
     footer: V::Element,
+    image_top: V::Element,
+    image_bottom: V::Element,
     header_child: ProxyChild<V>,
     body_child: ProxyChild<V>,
     footer_child: ProxyChild<V>,
+    toggle: Option<CardToggle<V>>,
+    is_expanded: bool,
 }
 
 impl<V: View> Card<V> {
@@ -39,6 +87,11 @@ impl<V: View> Card<V> {
 
         rsx! {
             let div = div(class = "card") {
+                let image_top = img(
+                    class = "card-img-top",
+                    loading = "lazy",
+                    style:display = "none",
+                ) {}
                 let header = div(class = "card-header") {
                     let header_inner = div(class = "card-header-inner") {
                         {&header_child}
@@ -50,6 +103,11 @@ impl<V: View> Card<V> {
                 let footer = div(class = "card-footer") {
                     {&footer_child}
                 }
+                let image_bottom = img(
+                    class = "card-img-bottom",
+                    loading = "lazy",
+                    style:display = "none",
+                ) {}
             }
         }
 
@@ -59,17 +117,143 @@ impl<V: View> Card<V> {
             header_inner,
             body,
             footer,
+            image_top,
+            image_bottom,
             header_child,
             body_child,
             footer_child,
+            toggle: None,
+            is_expanded: true,
         }
     }
 
+    /// Create a collapsible card with the given title in its header.
+    ///
+    /// The card starts out expanded.
+    pub fn collapsible(title: impl AsRef<str>) -> Self {
+        let mut card = Self::new();
+        let chevron = Icon::new(IconGlyph::ChevronDown, IconSize::Regular);
+        let title = V::Text::new(title);
+        rsx! {
+            let button = button(
+                type = "button",
+                class = "card-collapse-toggle",
+                aria_expanded = "true",
+                on:click = click,
+            ) {
+                {&chevron}
+                span(class = "ms-1") {
+                    {title}
+                }
+            }
+        }
+        card.set_header(&button);
+        card.div.add_class("card-collapsible");
+        card.toggle = Some(CardToggle {
+            button,
+            chevron,
+            click,
+        });
+        card
+    }
+
+    /// Returns `true` unless the card has been collapsed.
+    pub fn is_expanded(&self) -> bool {
+        self.is_expanded
+    }
+
+    /// Show or hide the body and footer.
+    pub fn set_is_expanded(&mut self, is_expanded: bool) {
+        self.is_expanded = is_expanded;
+        if is_expanded {
+            self.body.remove_class("collapsed");
+            self.footer.remove_class("collapsed");
+        } else {
+            self.body.add_class("collapsed");
+            self.footer.add_class("collapsed");
+        }
+        if let Some(toggle) = self.toggle.as_mut() {
+            toggle.chevron.set_glyph(if is_expanded {
+                IconGlyph::ChevronDown
+            } else {
+                IconGlyph::ChevronRight
+            });
+            toggle
+                .button
+                .set_property("aria-expanded", if is_expanded { "true" } else { "false" });
+        }
+    }
+
+    /// Await the header toggle being clicked, then expand or collapse the
+    /// card and report which.
+    ///
+    /// ## Note
+    /// Cards not created with [`Card::collapsible`] will wait indefinitely.
+    pub async fn step(&mut self) -> CardEvent {
+        match self.toggle.as_ref() {
+            Some(toggle) => {
+                toggle.click.next().await;
+            }
+            None => std::future::pending::<()>().await,
+        }
+        let is_expanded = !self.is_expanded;
+        self.set_is_expanded(is_expanded);
+        if is_expanded {
+            CardEvent::Expanded
+        } else {
+            CardEvent::Collapsed
+        }
+    }
+
+    /// Create a card whose body is rendered over the given image
+    /// (`card-img-overlay`).
+    ///
+    /// The header and footer are hidden.
+    pub fn with_image_overlay(src: impl AsRef<str>, alt: impl AsRef<str>) -> Self {
+        let card = Self::new();
+        card.set_image_top(src, alt);
+        card.image_top.set_property("class", "card-img");
+        card.body.set_property("class", "card-img-overlay");
+        card.hide_header();
+        card.hide_footer();
+        card
+    }
+
+    /// Show an image cap above the header.
+    pub fn set_image_top(&self, src: impl AsRef<str>, alt: impl AsRef<str>) {
+        show_image::<V>(&self.image_top, src.as_ref(), alt.as_ref());
+    }
+
+    /// Hide the top image cap.
+    pub fn hide_image_top(&self) {
+        self.image_top.set_style("display", "none");
+    }
+
+    /// Show an image cap below the footer.
+    pub fn set_image_bottom(&self, src: impl AsRef<str>, alt: impl AsRef<str>) {
+        show_image::<V>(&self.image_bottom, src.as_ref(), alt.as_ref());
+    }
+
+    /// Hide the bottom image cap.
+    pub fn hide_image_bottom(&self) {
+        self.image_bottom.set_style("display", "none");
+    }
+
     /// Replace the header content.
     pub fn set_header(&mut self, content: &impl ViewChild<V>) {
         self.header_child.replace(&self.header_inner, content);
     }
 
+    /// Mount a [`TabList`] in the header (`card-header-tabs`).
+    ///
+    /// The header's title-bar pinstripes are dropped so the tabs sit flush
+    /// against the body. See [`TabbedCard`] for a card that also switches its
+    /// body when a tab is clicked.
+    pub fn set_header_tabs<T: ViewChild<V>>(&mut self, tabs: &mut TabList<V, T>) {
+        tabs.set_is_card_header(true);
+        self.set_header(tabs);
+    }
+
     /// Replace the body content.
     pub fn set_body(&mut self, content: &impl ViewChild<V>) {
         self.body_child.replace(&self.body, content);
@@ -106,3 +290,310 @@ impl<V: View> Default for Card<V> {
         Self::new()
     }
 }
+
+/// A [`Card`] with nav-tabs in its header and a pane per tab in its body.
+///
+/// Clicking a tab selects its pane; see [`TabbedCard::step`].
+#[derive(ViewChild)]
+pub struct TabbedCard<V: View, T, P> {
+    #[child]
+    card: Card<V>,
+    tabs: TabList<V, T>,
+    panes: Panes<V, P>,
+    tabs_to_panes: HashMap<Id<T>, Id<P>>,
+}
+
+impl<V: View, T: ViewChild<V>, P: ViewChild<V>> TabbedCard<V, T, P> {
+    /// Create a new `TabbedCard` showing the default pane.
+    pub fn new(default_pane: P) -> Self {
+        let mut card = Card::new();
+        let mut tabs = TabList::<V, T>::default();
+        card.set_header_tabs(&mut tabs);
+        rsx! {
+            let content = div() {}
+        }
+        card.set_body(&content);
+        let panes = Panes::new_retained(content, default_pane);
+
+        Self {
+            card,
+            tabs,
+            panes,
+            tabs_to_panes: Default::default(),
+        }
+    }
+
+    /// Access the underlying card, e.g. to set its footer.
+    pub fn card(&self) -> &Card<V> {
+        &self.card
+    }
+
+    /// Mutably access the underlying card.
+    pub fn card_mut(&mut self) -> &mut Card<V> {
+        &mut self.card
+    }
+
+    /// Push a new tab and its pane, and select them.
+    pub fn push(&mut self, tab: T, pane: P) -> Id<T> {
+        let tid = self.tabs.push(tab);
+        self.tabs.select_by_id(&tid);
+        let pid = self.panes.add_pane(pane);
+        self.panes.select(&pid);
+        self.tabs_to_panes.insert(tid.clone(), pid);
+        tid
+    }
+
+    /// Select a tab and its pane.
+    ///
+    /// Returns `Some(())` when the tab exists and was selected, otherwise `None`.
+    pub fn select(&mut self, tab_id: &Id<T>) -> Option<()> {
+        let pane_id = self.tabs_to_panes.get(tab_id)?;
+        self.tabs.select_by_id(tab_id);
+        self.panes.select(pane_id).then_some(())
+    }
+
+    /// Returns a reference to the visible pane.
+    pub fn get_active_pane(&self) -> Option<&P> {
+        self.panes.current_pane()
+    }
+
+    /// Returns a mutable reference to the visible pane.
+    pub fn get_active_pane_mut(&mut self) -> Option<&mut P> {
+        self.panes.current_pane_mut()
+    }
+
+    /// Await a tab click, then select that tab's pane.
+    pub async fn step(&mut self) -> TabListEvent<V, T> {
+        let ev = self.tabs.step().await;
+        if let TabListEvent::ItemClicked { id, .. } = &ev {
+            self.select(id);
+        }
+        ev
+    }
+}
+
+/// A card owned by a [`CardGroup`], along with its column wrapper.
+struct CardGroupItem<V: View> {
+    col: V::Element,
+    card: Card<V>,
+}
+
+/// A collection of [`Card`]s rendered at equal height.
+///
+/// [`CardGroup::new`] joins the cards edge to edge (`card-group`), while
+/// [`CardGroup::grid`] spaces them out over a fixed number of columns per row
+/// (`row row-cols-*`), wrapping onto new rows as needed.
+#[derive(ViewChild, ViewProperties)]
+pub struct CardGroup<V: View> {
+    #[child]
+    #[properties]
+    div: V::Element,
+    items: Vec<CardGroupItem<V>>,
+}
+
+impl<V: View> CardGroup<V> {
+    /// Create an empty group of attached cards.
+    pub fn new() -> Self {
+        rsx! {
+            let div = div(class = "card-group") {}
+        }
+        Self { div, items: vec![] }
+    }
+
+    /// Create an empty grid with the given number of cards per row.
+    ///
+    /// `columns` is clamped to `1..=6`.
+    pub fn grid(columns: usize) -> Self {
+        let columns = columns.clamp(1, 6);
+        rsx! {
+            let div = div(class = format!("row row-cols-{columns} g-2 card-grid")) {}
+        }
+        Self { div, items: vec![] }
+    }
+
+    /// Returns the number of cards.
+    pub fn len(&self) -> usize {
+        self.items.len()
+    }
+
+    /// Returns `true` if there are no cards.
+    pub fn is_empty(&self) -> bool {
+        self.items.is_empty()
+    }
+
+    /// Append a card and return its index.
+    pub fn push(&mut self, card: Card<V>) -> usize {
+        rsx! {
+            let col = div(class = "col") {
+                {&card}
+            }
+        }
+        self.div.append_child(&col);
+        self.items.push(CardGroupItem { col, card });
+        self.items.len() - 1
+    }
+
+    /// Remove and return the card at the given index.
+    ///
+    /// Returns `None` if the index is out of bounds.
+    pub fn remove(&mut self, index: usize) -> Option<Card<V>> {
+        if index >= self.items.len() {
+            return None;
+        }
+        let item = self.items.remove(index);
+        self.div.remove_child(&item.col);
+        item.col.remove_child(&item.card);
+        Some(item.card)
+    }
+
+    /// Returns a reference to the card at the given index.
+    pub fn get(&self, index: usize) -> Option<&Card<V>> {
+        self.items.get(index).map(|item| &item.card)
+    }
+
+    /// Returns a mutable reference to the card at the given index.
+    pub fn get_mut(&mut self, index: usize) -> Option<&mut Card<V>> {
+        self.items.get_mut(index).map(|item| &mut item.card)
+    }
+
+    /// Iterate over the cards.
+    pub fn iter(&self) -> impl Iterator<Item = &Card<V>> {
+        self.items.iter().map(|item| &item.card)
+    }
+}
+
+impl<V: View> Default for CardGroup<V> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(feature = "library")]
+pub mod library {
+    use futures_lite::FutureExt;
+    use mogwai::future::MogwaiFutureExt;
+
+    use super::*;
+    use crate::library::log_event;
+
+    #[derive(ViewChild)]
+    pub struct CardLibraryItem<V: View> {
+        #[child]
+        pub wrapper: V::Element,
+        card: Card<V>,
+        status_text: V::Text,
+        tabbed: TabbedCard<V, V::Element, V::Element>,
+        grid: CardGroup<V>,
+    }
+
+    impl<V: View> Default for CardLibraryItem<V> {
+        fn default() -> Self {
+            let mut card = Card::collapsible("Network");
+            rsx! {
+                let body = p(class = "card-text") {
+                    "Click the chevron in the header to collapse this card."
+                }
+            }
+            card.set_body(&body);
+            rsx! {
+                let footer = span() { "Updated just now" }
+            }
+            card.set_footer(&footer);
+
+            let status_text = V::Text::new("Expanded");
+
+            rsx! {
+                let default_pane = p(class = "card-text") { "Pick a tab." }
+            }
+            let mut tabbed = TabbedCard::new(default_pane);
+            let mut first = None;
+            for (label, text) in [
+                ("General", "Name, kind and size."),
+                ("Sharing", "Who can see this item."),
+                ("Memory", "Suggested and minimum sizes."),
+            ] {
+                rsx! {
+                    let tab = span() { {V::Text::new(label)} }
+                }
+                rsx! {
+                    let pane = p(class = "card-text") { {V::Text::new(text)} }
+                }
+                let id = tabbed.push(tab, pane);
+                first.get_or_insert(id);
+            }
+            if let Some(first) = first {
+                tabbed.select(&first);
+            }
+
+            let mut grid = CardGroup::grid(3);
+            for (title, text) in [
+                ("Finder", "Browse files and folders."),
+                (
+                    "Chooser",
+                    "Pick a printer or file server. This card has more text, \
+                     but the others stretch to match its height.",
+                ),
+                ("Scrapbook", "Keep clippings."),
+            ] {
+                let mut card = Card::new();
+                rsx! {
+                    let header = span() { {V::Text::new(title)} }
+                }
+                card.set_header(&header);
+                rsx! {
+                    let body = p(class = "card-text") { {V::Text::new(text)} }
+                }
+                card.set_body(&body);
+                card.hide_footer();
+                grid.push(card);
+            }
+
+            rsx! {
+                let wrapper = div() {
+                    div(class = "mb-3", style:max_width = "24rem") {
+                        {&card}
+                    }
+                    p(class = "text-muted") {
+                        {&status_text}
+                    }
+                    div(class = "mb-3", style:max_width = "24rem") {
+                        {&tabbed}
+                    }
+                    div(class = "mb-3", style:max_width = "36rem") {
+                        {&grid}
+                    }
+                }
+            }
+
+            Self {
+                wrapper,
+                card,
+                status_text,
+                tabbed,
+                grid,
+            }
+        }
+    }
+
+    impl<V: View> CardLibraryItem<V> {
+        pub async fn step(&mut self) {
+            let ev = self
+                .card
+                .step()
+                .map(Some)
+                .or(self.tabbed.step().map(|_| None))
+                .await;
+            match ev {
+                Some(CardEvent::Expanded) => {
+                    self.status_text.set_text("Expanded");
+                    log_event("Card", "Expanded");
+                }
+                Some(CardEvent::Collapsed) => {
+                    self.status_text.set_text("Collapsed");
+                    log_event("Card", "Collapsed");
+                }
+                None => {}
+            }
+        }
+    }
+}
@@ -119,6 +119,7 @@ impl<V: View> Checkbox<V> {
 #[cfg(feature = "library")]
 pub mod library {
     use super::*;
+    use crate::library::log_event;
 
     #[derive(ViewChild)]
     pub struct CheckboxLibraryItem<V: View> {
@@ -192,6 +193,7 @@ pub mod library {
                 },
                 event.checked
             );
+            log_event("Checkbox", &msg);
 
             let current_text = self
                 .log
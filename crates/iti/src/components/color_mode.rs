@@ -0,0 +1,102 @@
+//! A button that switches between light and dark color modes.
+//!
+//! See [`crate::theme`] for the mode manager it drives.
+
+use futures_lite::FutureExt;
+use mogwai::{future::MogwaiFutureExt, prelude::*};
+
+use crate::{
+    components::{button::Button, icon::IconGlyph},
+    theme::{ColorMode, ColorModeManager},
+};
+
+fn glyph(mode: ColorMode) -> IconGlyph {
+    match mode {
+        ColorMode::Light => IconGlyph::Sun,
+        ColorMode::Dark => IconGlyph::Moon,
+        ColorMode::Auto => IconGlyph::CircleHalfStroke,
+    }
+}
+
+fn label(mode: ColorMode) -> String {
+    let name = match mode {
+        ColorMode::Light => "Light",
+        ColorMode::Dark => "Dark",
+        ColorMode::Auto => "Auto",
+    };
+    format!("Color mode: {name}")
+}
+
+/// An icon button that cycles the page through light, dark and auto
+/// color modes.
+///
+/// It owns a [`ColorModeManager`], so creating one applies the stored mode.
+#[derive(ViewChild, ViewProperties)]
+pub struct ColorModeToggle<V: View> {
+    #[child]
+    #[properties]
+    wrapper: V::Element,
+    button: Button<V>,
+    manager: ColorModeManager,
+}
+
+impl<V: View> Default for ColorModeToggle<V> {
+    fn default() -> Self {
+        Self::new(ColorModeManager::new())
+    }
+}
+
+impl<V: View> ColorModeToggle<V> {
+    pub fn new(manager: ColorModeManager) -> Self {
+        let mode = manager.mode();
+        let button = Button::icon_only(glyph(mode), label(mode));
+        rsx! {
+            let wrapper = span(class = "color-mode-toggle") {
+                {&button}
+            }
+        }
+        Self {
+            wrapper,
+            button,
+            manager,
+        }
+    }
+
+    pub fn get_manager(&self) -> &ColorModeManager {
+        &self.manager
+    }
+
+    pub fn get_manager_mut(&mut self) -> &mut ColorModeManager {
+        &mut self.manager
+    }
+
+    /// Returns the chosen mode.
+    pub fn mode(&self) -> ColorMode {
+        self.manager.mode()
+    }
+
+    /// Apply and persist `mode`, updating the button to match.
+    pub fn set_mode(&mut self, mode: ColorMode) {
+        if let Err(e) = self.manager.set_mode(mode) {
+            log::warn!("could not store the color mode: {e}");
+        }
+        self.button.get_icon_mut().set_glyph(glyph(mode));
+        self.button.set_label(label(mode));
+    }
+
+    /// Await a click, switch to the next mode and return it.
+    ///
+    /// Also returns the resolved mode when the system color scheme
+    /// changes while the mode is [`ColorMode::Auto`].
+    pub async fn step(&mut self) -> ColorMode {
+        let clicked = self.button.step().map(|_| true);
+        let system = self.manager.step().map(|_| false);
+        if clicked.or(system).await {
+            let mode = self.mode().next();
+            self.set_mode(mode);
+            mode
+        } else {
+            self.manager.resolved_mode()
+        }
+    }
+}
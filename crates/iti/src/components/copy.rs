@@ -0,0 +1,132 @@
+//! A button that copies text to the clipboard.
+
+use futures_lite::FutureExt;
+use mogwai::{future::MogwaiFutureExt, prelude::*};
+
+use crate::{
+    capabilities::{Clipboard, Error, WebClipboard},
+    components::{button::Button, icon::IconGlyph},
+    util::time::Timeout,
+};
+
+/// How long the button shows the outcome of a copy before resetting.
+const FEEDBACK_MILLIS: u64 = 1500;
+
+const COPY_GLYPH: IconGlyph = IconGlyph::Copy;
+
+/// Event emitted by [`CopyButton::step`].
+#[derive(Debug)]
+pub enum CopyEvent {
+    /// The text was copied.
+    Copied,
+    /// The clipboard refused the text.
+    Failed(Error),
+}
+
+/// An icon button that copies its text to the clipboard when clicked.
+///
+/// After a click the icon briefly turns into a check mark titled
+/// "Copied!", or a cross if copying failed.
+#[derive(ViewChild, ViewProperties)]
+pub struct CopyButton<V: View, C = WebClipboard> {
+    #[child]
+    #[properties]
+    wrapper: V::Element,
+    button: Button<V>,
+    clipboard: C,
+    text: String,
+    label: String,
+    /// When the outcome of the last copy stops being shown, while it is.
+    feedback: Option<Timeout>,
+}
+
+impl<V: View, C: Clipboard + Default> CopyButton<V, C> {
+    /// Create a button that copies `text`.
+    pub fn new(text: impl Into<String>) -> Self {
+        Self::with_clipboard(text, C::default())
+    }
+}
+
+impl<V: View, C: Clipboard> CopyButton<V, C> {
+    /// Create a button that copies `text` using `clipboard`.
+    pub fn with_clipboard(text: impl Into<String>, clipboard: C) -> Self {
+        let label = "Copy".to_string();
+        let button = Button::icon_only(COPY_GLYPH, &label);
+        rsx! {
+            let wrapper = span(class = "copy-button") {
+                {&button}
+            }
+        }
+        Self {
+            wrapper,
+            button,
+            clipboard,
+            text: text.into(),
+            label,
+            feedback: None,
+        }
+    }
+
+    pub fn get_text(&self) -> &str {
+        &self.text
+    }
+
+    /// Set the text copied on click.
+    pub fn set_text(&mut self, text: impl Into<String>) {
+        self.text = text.into();
+    }
+
+    /// Set the `aria-label` and tooltip shown while idle. Defaults to
+    /// "Copy".
+    pub fn set_label(&mut self, label: impl Into<String>) {
+        self.label = label.into();
+        if self.feedback.is_none() {
+            self.button.set_label(&self.label);
+        }
+    }
+
+    pub fn get_button(&self) -> &Button<V> {
+        &self.button
+    }
+
+    pub fn get_button_mut(&mut self) -> &mut Button<V> {
+        &mut self.button
+    }
+
+    fn show(&mut self, glyph: IconGlyph, label: &str) {
+        self.button.get_icon_mut().set_glyph(glyph);
+        self.button.set_label(label);
+    }
+
+    /// Await a click and copy the text.
+    pub async fn step(&mut self) -> CopyEvent {
+        loop {
+            let click = self.button.step().map(|_| true);
+            if let Some(feedback) = &self.feedback {
+                let reset = feedback.step().map(|_| false);
+                if !click.or(reset).await {
+                    self.feedback = None;
+                    let label = self.label.clone();
+                    self.show(COPY_GLYPH, &label);
+                    continue;
+                }
+            } else {
+                click.await;
+            }
+
+            let result = self.clipboard.write_text(&self.text).await;
+            self.feedback = Some(Timeout::new(FEEDBACK_MILLIS));
+            return match result {
+                Ok(()) => {
+                    self.show(IconGlyph::Check, "Copied!");
+                    CopyEvent::Copied
+                }
+                Err(e) => {
+                    log::warn!("{e}");
+                    self.show(IconGlyph::Xmark, "Copy failed");
+                    CopyEvent::Failed(e)
+                }
+            };
+        }
+    }
+}
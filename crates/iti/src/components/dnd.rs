@@ -0,0 +1,333 @@
+//! Drag and drop with typed payloads.
+//!
+//! A [`Draggable`] carries a payload of type `T`; a [`DropTarget`] accepts
+//! payloads of the same type and reports them as [`Dropped`] events. Both
+//! are built on HTML5 drag events, so they work with mouse and pen input.
+//!
+//! The payload itself never goes through the browser's `DataTransfer`:
+//! while a drag is in progress it is held in a registry, and only its key
+//! is put on the transfer. Drops of anything else — files, text, or items
+//! of another payload type — are ignored.
+
+use std::{
+    any::Any,
+    cell::{Cell, RefCell},
+    marker::PhantomData,
+};
+
+use futures_lite::FutureExt;
+use mogwai::{
+    future::MogwaiFutureExt,
+    prelude::*,
+    web::{WebElement, WebEvent},
+};
+use wasm_bindgen::JsCast;
+
+/// MIME type of the registry key put on the `DataTransfer`.
+const MIME_TYPE: &str = "application/x-iti-dnd";
+
+thread_local! {
+    static NEXT_KEY: Cell<u64> = const { Cell::new(0) };
+    static DRAGGED: RefCell<Option<(u64, Box<dyn Any>)>> = const { RefCell::new(None) };
+}
+
+mod registry {
+    use super::*;
+
+    /// Hold `payload` for the drag that is starting, returning its key.
+    pub fn start(payload: Box<dyn Any>) -> u64 {
+        let key = NEXT_KEY.with(|next| {
+            let key = next.get();
+            next.set(key + 1);
+            key
+        });
+        DRAGGED.with(|dragged| *dragged.borrow_mut() = Some((key, payload)));
+        key
+    }
+
+    /// Forget the payload of the drag with `key`.
+    pub fn end(key: u64) {
+        DRAGGED.with(|dragged| {
+            let mut dragged = dragged.borrow_mut();
+            if dragged.as_ref().is_some_and(|(k, _)| *k == key) {
+                *dragged = None;
+            }
+        });
+    }
+
+    /// Returns `true` if the drag in progress carries a `T`.
+    pub fn is_dragging<T: 'static>() -> bool {
+        DRAGGED.with(|dragged| {
+            dragged
+                .borrow()
+                .as_ref()
+                .is_some_and(|(_, payload)| payload.is::<T>())
+        })
+    }
+
+    /// Returns a copy of the payload with `key`, if it is a `T`.
+    pub fn get<T: Clone + 'static>(key: u64) -> Option<T> {
+        DRAGGED.with(|dragged| {
+            let dragged = dragged.borrow();
+            let (k, payload) = dragged.as_ref()?;
+            (*k == key).then(|| payload.downcast_ref::<T>().cloned())?
+        })
+    }
+}
+
+/// Event emitted by [`Draggable::step`].
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum DraggableEvent {
+    /// The user started dragging.
+    Started,
+    /// The drag ended, whether or not it was dropped on a target.
+    Ended,
+}
+
+/// Wraps content so it can be dragged onto a [`DropTarget<V, T>`].
+///
+/// The wrapper has the `dragging` class while a drag is in progress.
+///
+/// ## Note
+/// [`Draggable::step`] must be running for drags to carry the payload.
+#[derive(ViewChild, ViewProperties)]
+pub struct Draggable<V: View, T> {
+    #[child]
+    #[properties]
+    wrapper: V::Element,
+    payload: T,
+    dragstart: V::EventListener,
+    dragend: V::EventListener,
+    /// Registry key of the drag in progress.
+    key: Option<u64>,
+}
+
+impl<V: View, T: Clone + 'static> Draggable<V, T> {
+    /// Make `content` draggable, carrying `payload`.
+    pub fn new(content: &impl ViewChild<V>, payload: T) -> Self {
+        rsx! {
+            let wrapper = div(
+                class = "draggable",
+                draggable = "true",
+                on:dragstart = dragstart,
+                on:dragend = dragend,
+            ) {
+                {content}
+            }
+        }
+        Self {
+            wrapper,
+            payload,
+            dragstart,
+            dragend,
+            key: None,
+        }
+    }
+
+    pub fn get_payload(&self) -> &T {
+        &self.payload
+    }
+
+    pub fn set_payload(&mut self, payload: T) {
+        self.payload = payload;
+    }
+
+    /// Allow or prevent dragging. Draggables start out enabled.
+    pub fn set_is_enabled(&self, is_enabled: bool) {
+        self.wrapper
+            .set_property("draggable", if is_enabled { "true" } else { "false" });
+    }
+
+    /// Await the start or end of a drag.
+    pub async fn step(&mut self) -> DraggableEvent {
+        let started = self.dragstart.next().map(Ok);
+        let ended = self.dragend.next().map(Err);
+        match started.or(ended).await {
+            Ok(ev) => {
+                let key = registry::start(Box::new(self.payload.clone()));
+                self.key = Some(key);
+                ev.dyn_ev(|e: &web_sys::DragEvent| {
+                    if let Some(transfer) = e.data_transfer() {
+                        let _ = transfer.set_data(MIME_TYPE, &key.to_string());
+                        transfer.set_effect_allowed("move");
+                    }
+                });
+                self.wrapper.add_class("dragging");
+                DraggableEvent::Started
+            }
+            Err(_) => {
+                if let Some(key) = self.key.take() {
+                    registry::end(key);
+                }
+                self.wrapper.remove_class("dragging");
+                DraggableEvent::Ended
+            }
+        }
+    }
+}
+
+/// Where a payload was dropped, in pixels from the top left of the
+/// [`DropTarget`].
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct DropPosition {
+    pub x: f64,
+    pub y: f64,
+}
+
+/// A payload dropped on a [`DropTarget`].
+#[derive(Clone, Debug, PartialEq)]
+pub struct Dropped<T> {
+    pub payload: T,
+    pub position: DropPosition,
+}
+
+struct DropTargetState {
+    is_over: bool,
+    is_enabled: bool,
+}
+
+impl DropTargetState {
+    fn class(&self) -> String {
+        let over = if self.is_over { " drop-over" } else { "" };
+        format!("drop-target{over}")
+    }
+}
+
+enum DropTargetDomEvent<E> {
+    Over(E),
+    Leave(E),
+    Drop(E),
+}
+
+/// Wraps content so [`Draggable<V, T>`]s can be dropped on it.
+///
+/// The wrapper has the `drop-over` class while a `T` is dragged over it.
+#[derive(ViewChild, ViewProperties)]
+pub struct DropTarget<V: View, T> {
+    #[child]
+    #[properties]
+    wrapper: V::Element,
+    state: Proxy<DropTargetState>,
+    dragover: V::EventListener,
+    dragleave: V::EventListener,
+    drop: V::EventListener,
+    _payload: PhantomData<T>,
+}
+
+impl<V: View, T: Clone + 'static> DropTarget<V, T> {
+    /// Make `content` accept drops of `T` payloads.
+    pub fn new(content: &impl ViewChild<V>) -> Self {
+        let mut state = Proxy::new(DropTargetState {
+            is_over: false,
+            is_enabled: true,
+        });
+        rsx! {
+            let wrapper = div(
+                class = state(s => s.class()),
+                on:dragover = dragover,
+                on:dragleave = dragleave,
+                on:drop = drop,
+            ) {
+                {content}
+            }
+        }
+        Self {
+            wrapper,
+            state,
+            dragover,
+            dragleave,
+            drop,
+            _payload: PhantomData,
+        }
+    }
+
+    /// Accept or refuse drops. Targets start out enabled.
+    pub fn set_is_enabled(&mut self, is_enabled: bool) {
+        self.state.modify(|s| {
+            s.is_enabled = is_enabled;
+            s.is_over &= is_enabled;
+        });
+    }
+
+    pub fn is_enabled(&self) -> bool {
+        self.state.is_enabled
+    }
+
+    fn set_is_over(&mut self, is_over: bool) {
+        if self.state.is_over != is_over {
+            self.state.modify(|s| s.is_over = is_over);
+        }
+    }
+
+    /// Returns the drop position of `e` relative to the target.
+    fn position(&self, e: &web_sys::DragEvent) -> DropPosition {
+        let (left, top) = self
+            .wrapper
+            .dyn_el(|el: &web_sys::Element| {
+                let rect = el.get_bounding_client_rect();
+                (rect.left(), rect.top())
+            })
+            .unwrap_or_default();
+        DropPosition {
+            x: e.client_x() as f64 - left,
+            y: e.client_y() as f64 - top,
+        }
+    }
+
+    /// Await a payload being dropped on the target.
+    pub async fn step(&mut self) -> Dropped<T> {
+        loop {
+            let event = self
+                .dragover
+                .next()
+                .map(DropTargetDomEvent::Over)
+                .or(self.dragleave.next().map(DropTargetDomEvent::Leave))
+                .or(self.drop.next().map(DropTargetDomEvent::Drop))
+                .await;
+            let accepts = self.state.is_enabled && registry::is_dragging::<T>();
+            match event {
+                DropTargetDomEvent::Over(ev) => {
+                    if accepts {
+                        // Allow the drop.
+                        ev.dyn_ev(|e: &web_sys::DragEvent| e.prevent_default());
+                    }
+                    self.set_is_over(accepts);
+                }
+                DropTargetDomEvent::Leave(ev) => {
+                    // Moving onto a child element also fires dragleave.
+                    let is_inside = ev
+                        .dyn_ev(|e: &web_sys::DragEvent| {
+                            let related = e.related_target()?.dyn_into::<web_sys::Node>().ok();
+                            self.wrapper
+                                .dyn_el(|el: &web_sys::Node| el.contains(related.as_ref()))
+                        })
+                        .flatten()
+                        .unwrap_or(false);
+                    if !is_inside {
+                        self.set_is_over(false);
+                    }
+                }
+                DropTargetDomEvent::Drop(ev) => {
+                    self.set_is_over(false);
+                    if !accepts {
+                        continue;
+                    }
+                    let dropped = ev
+                        .dyn_ev(|e: &web_sys::DragEvent| {
+                            e.prevent_default();
+                            let key = e.data_transfer()?.get_data(MIME_TYPE).ok()?;
+                            let payload = registry::get::<T>(key.parse().ok()?)?;
+                            Some(Dropped {
+                                payload,
+                                position: self.position(e),
+                            })
+                        })
+                        .flatten();
+                    if let Some(dropped) = dropped {
+                        return dropped;
+                    }
+                }
+            }
+        }
+    }
+}
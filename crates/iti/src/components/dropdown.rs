@@ -2,101 +2,386 @@
 //!
 //! A Bootstrap dropdown button with a menu of clickable items.  Open/close and
 //! click-outside-to-dismiss are managed in pure Rust — no Bootstrap JS required.
-use mogwai::prelude::*;
+//!
+//! Also provides [`SplitButton`], a primary action button with an attached
+//! dropdown toggle.
+use mogwai::{
+    prelude::*,
+    web::{WebElement, WebEvent},
+};
 use wasm_bindgen::JsCast;
 
-use super::Flavor;
+use super::{
+    button::Button,
+    icon::{Icon, IconGlyph, IconSize},
+    Flavor,
+};
 
 /// Event emitted by a [`Dropdown`].
 pub enum DropdownEvent<V: View> {
     /// A menu item was clicked.
     ItemClicked { index: usize, event: V::Event },
+    /// A menu item of a checkable dropdown was clicked and its checked state
+    /// flipped. The menu stays open.
+    ItemToggled {
+        index: usize,
+        is_checked: bool,
+        event: V::Event,
+    },
     /// The dropdown was dismissed (Escape key or click outside).
     Dismissed,
 }
 
-/// A single item within a [`Dropdown`] menu.
+/// The side of the toggle button a [`Dropdown`] menu opens on.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum DropDirection {
+    /// Below the toggle (`dropdown`).
+    #[default]
+    Down,
+    /// Above the toggle (`dropup`).
+    Up,
+    /// To the left of the toggle (`dropstart`).
+    Start,
+    /// To the right of the toggle (`dropend`).
+    End,
+}
+
+impl DropDirection {
+    fn class(&self) -> &'static str {
+        match self {
+            DropDirection::Down => "dropdown",
+            DropDirection::Up => "dropup",
+            DropDirection::Start => "dropstart",
+            DropDirection::End => "dropend",
+        }
+    }
+}
+
+/// Which edge of the toggle button a [`Dropdown`] menu lines up with.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum DropdownAlignment {
+    /// The menu's left edge lines up with the toggle's left edge.
+    #[default]
+    Start,
+    /// The menu's right edge lines up with the toggle's right edge
+    /// (`dropdown-menu-end`).
+    End,
+}
+
+struct DropdownState {
+    is_open: bool,
+    direction: DropDirection,
+    alignment: DropdownAlignment,
+    flavor: Flavor,
+    /// The toggle is the arrow half of a [`SplitButton`].
+    is_split: bool,
+}
+
+impl DropdownState {
+    fn toggle_class(&self) -> String {
+        let split = if self.is_split {
+            " dropdown-toggle-split"
+        } else {
+            ""
+        };
+        format!("btn btn-{} dropdown-toggle{split}", self.flavor)
+    }
+
+    fn menu_class(&self) -> String {
+        let mut class = "dropdown-menu".to_string();
+        if self.alignment == DropdownAlignment::End {
+            class.push_str(" dropdown-menu-end");
+        }
+        if self.is_open {
+            class.push_str(" show");
+        }
+        class
+    }
+}
+
+/// A key that an open [`Dropdown`] menu responds to.
+enum MenuKey {
+    Up,
+    Down,
+    Enter,
+    Escape,
+}
+
+/// Resolves when one of the [`MenuKey`]s is pressed.
+async fn menu_key_pressed<V: View>(keydown: &V::EventListener) -> (MenuKey, V::Event) {
+    loop {
+        let ev = keydown.next().await;
+        let key = ev
+            .dyn_ev(|e: &web_sys::KeyboardEvent| match e.key().as_str() {
+                "ArrowUp" => Some(MenuKey::Up),
+                "ArrowDown" => Some(MenuKey::Down),
+                "Enter" => Some(MenuKey::Enter),
+                "Escape" => Some(MenuKey::Escape),
+                _ => None,
+            })
+            .flatten();
+        if let Some(key) = key {
+            return (key, ev);
+        }
+    }
+}
+
+/// A single item within a [`Dropdown`] or [`SplitButton`] menu.
+///
+/// The item's content is any `T: ViewChild<V>` — plain text for menus built
+/// with [`Dropdown::push`], or icons, badges and multi-line layouts with
+/// [`Dropdown::push_item`].
 #[derive(ViewChild, ViewProperties)]
-pub struct DropdownItem<V: View> {
+pub struct DropdownItem<V: View, T> {
     #[child]
     #[properties]
     li: V::Element,
+    a: V::Element,
     on_click: V::EventListener,
+    check: V::Element,
+    _check_icon: Icon<V>,
+    is_checked: bool,
+    inner: T,
 }
 
-impl<V: View> DropdownItem<V> {
-    fn new(label: impl AsRef<str>) -> Self {
-        let text = V::Text::new(label);
+impl<V: View, T: ViewChild<V>> DropdownItem<V, T> {
+    fn new(inner: T) -> Self {
+        let check_icon = Icon::new(IconGlyph::Check, IconSize::Sm);
         rsx! {
             let li = li() {
-                a(
+                let a = a(
                     class = "dropdown-item",
-                    href = "#",
+                    role = "button",
                     on:click = on_click,
                 ) {
-                    {text}
+                    let check = span(
+                        class = "dropdown-item-check",
+                        style:display = "none",
+                        style:visibility = "hidden",
+                    ) {
+                        {&check_icon}
+                    }
+                    {&inner}
                 }
             }
         }
 
-        Self { li, on_click }
+        Self {
+            li,
+            a,
+            on_click,
+            check,
+            _check_icon: check_icon,
+            is_checked: false,
+            inner,
+        }
+    }
+
+    /// Returns `true` if the item is checked.
+    ///
+    /// Only meaningful in a checkable [`Dropdown`].
+    pub fn is_checked(&self) -> bool {
+        self.is_checked
+    }
+
+    fn set_is_checked(&mut self, is_checked: bool) {
+        self.is_checked = is_checked;
+        if is_checked {
+            self.check.remove_style("visibility");
+        } else {
+            self.check.set_style("visibility", "hidden");
+        }
+    }
+
+    /// Show or hide the check mark column.
+    fn set_is_checkable(&self, is_checkable: bool) {
+        if is_checkable {
+            self.check.remove_style("display");
+        } else {
+            self.check.set_style("display", "none");
+        }
+    }
+
+    pub fn inner(&self) -> &T {
+        &self.inner
+    }
+
+    pub fn inner_mut(&mut self) -> &mut T {
+        &mut self.inner
+    }
+
+    /// Await the next click on the item.
+    ///
+    /// The click's default action is prevented, so that links in the
+    /// item's content don't navigate, e.g. by changing the URL's hash.
+    async fn clicked(&self) -> V::Event {
+        let event = self.on_click.next().await;
+        event.dyn_ev(|e: &web_sys::Event| e.prevent_default());
+        event
     }
+
+    /// Show or hide the keyboard highlight.
+    fn set_is_highlighted(&self, is_highlighted: bool) {
+        if is_highlighted {
+            self.a.add_class("active");
+        } else {
+            self.a.remove_class("active");
+        }
+    }
+}
+
+/// A non-clickable `li` in a dropdown menu: a divider, header or disabled item.
+fn menu_divider<V: View>() -> V::Element {
+    rsx! {
+        let li = li() {
+            hr(class = "dropdown-divider") {}
+        }
+    }
+    li
+}
+
+fn menu_header<V: View>(text: impl AsRef<str>) -> V::Element {
+    let text = V::Text::new(text);
+    rsx! {
+        let li = li() {
+            h6(class = "dropdown-header") {
+                {text}
+            }
+        }
+    }
+    li
+}
+
+fn menu_disabled<V: View>(label: impl AsRef<str>) -> V::Element {
+    let text = V::Text::new(label);
+    rsx! {
+        let li = li() {
+            a(
+                class = "dropdown-item disabled",
+                aria_disabled = "true",
+                tabindex = "-1",
+            ) {
+                {text}
+            }
+        }
+    }
+    li
 }
 
 /// A Bootstrap dropdown button with a menu.
 ///
 /// Toggle the menu by calling [`Dropdown::toggle`] in response to
 /// [`Dropdown::step`] returning [`None`].
+///
+/// Menu items hold a `T`. [`Dropdown::new`] creates a menu of text labels;
+/// use [`Dropdown::new_custom`] for richer content, and [`Dropdown::get`] to
+/// reach an item's content after it is clicked.
+///
+/// Besides clickable items the menu can hold dividers, section headers and
+/// disabled items. These are never reported by [`Dropdown::step`], and item
+/// indices count only the clickable items.
+///
+/// While the menu is open the arrow keys move a highlight through the
+/// clickable items, Enter selects the highlighted item and Escape dismisses
+/// the menu, returning focus to the toggle button.
+///
+/// By default a click outside the open menu lands on an invisible backdrop
+/// and is reported as [`DropdownEvent::Dismissed`], leaving the caller to hide
+/// the menu. With [`Dropdown::set_auto_close`] the backdrop lets clicks
+/// through to the page instead, and the dropdown closes itself when the
+/// document is clicked anywhere outside of it.
+///
+/// Dropdowns near the edge of the viewport can open upwards or sideways with
+/// [`Dropdown::set_direction`], and line up with the toggle's right edge with
+/// [`Dropdown::set_alignment`].
+///
+/// A dropdown made checkable with [`Dropdown::set_is_checkable`] works as a
+/// lightweight multi-select: each item shows a check mark when checked, and
+/// clicking an item flips its checked state and reports
+/// [`DropdownEvent::ItemToggled`] instead of [`DropdownEvent::ItemClicked`],
+/// leaving the menu open.
 #[derive(ViewChild, ViewProperties)]
-pub struct Dropdown<V: View> {
+pub struct Dropdown<V: View, T> {
     #[child]
     #[properties]
     wrapper: V::Element,
+    toggle_button: V::Element,
+    backdrop: V::Element,
     menu: V::Element,
     toggle_click: V::EventListener,
     backdrop_click: V::EventListener,
+    document_click: V::EventListener,
     keydown: V::EventListener,
-    items: Vec<DropdownItem<V>>,
-    open: Proxy<bool>,
-    is_open: bool,
-    flavor: Proxy<Flavor>,
+    auto_close: bool,
+    is_checkable: bool,
+    items: Vec<DropdownItem<V, T>>,
+    /// Dividers, headers and disabled items.
+    decorations: Vec<V::Element>,
+    /// Index of the item highlighted with the arrow keys.
+    highlighted: Option<usize>,
+    state: Proxy<DropdownState>,
 }
 
-impl<V: View> Dropdown<V> {
+impl<V: View> Dropdown<V, V::Text> {
+    /// Create a dropdown whose menu items are text labels.
     pub fn new(label: impl AsRef<str>, flavor: Flavor) -> Self {
-        let mut flavor_proxy = Proxy::new(flavor);
-        let mut open = Proxy::new(false);
+        Self::new_custom(label, flavor)
+    }
+
+    /// Add a menu item and return its index.
+    ///
+    /// The index counts only clickable items.
+    pub fn push(&mut self, label: impl AsRef<str>) -> usize {
+        self.push_item(V::Text::new(label))
+    }
+
+    /// Create the label-less toggle and menu of a [`SplitButton`].
+    fn new_split(flavor: Flavor) -> Self {
+        let mut dropdown = Self::new_custom("", flavor);
+        dropdown.state.modify(|s| s.is_split = true);
+        dropdown
+            .toggle_button
+            .set_property("aria-label", "Toggle dropdown");
+        dropdown
+    }
+}
+
+impl<V: View, T: ViewChild<V>> Dropdown<V, T> {
+    /// Create a dropdown whose menu items hold arbitrary content.
+    pub fn new_custom(label: impl AsRef<str>, flavor: Flavor) -> Self {
+        let mut state = Proxy::new(DropdownState {
+            is_open: false,
+            direction: DropDirection::Down,
+            alignment: DropdownAlignment::Start,
+            flavor,
+            is_split: false,
+        });
         let label_text = V::Text::new(label);
 
         rsx! {
             let wrapper = div(
-                class = "dropdown",
+                class = state(s => s.direction.class()),
                 document:keydown = keydown,
+                document:click = document_click,
             ) {
-                button(
-                    class = flavor_proxy(
-                        f => format!("btn btn-{f} dropdown-toggle")
-                    ),
+                let toggle_button = button(
+                    class = state(s => s.toggle_class()),
                     type = "button",
                     on:click = toggle_click,
                 ) {
                     {label_text}
                 }
-                div(
+                let backdrop = div(
                     style:position = "fixed",
                     style:inset = "0",
                     style:z_index = "1000",
-                    style:display = open(
-                        is_open => if *is_open { "block" } else { "none" }
+                    style:display = state(
+                        s => if s.is_open { "block" } else { "none" }
                     ),
                     on:click = backdrop_click,
                 ) {}
                 let menu = ul(
-                    class = open(is_open => if *is_open {
-                        "dropdown-menu show"
-                    } else {
-                        "dropdown-menu"
-                    }),
+                    class = state(s => s.menu_class()),
                     style:z_index = "1001",
                 ) {
                     let items = {vec![]}
@@ -106,63 +391,265 @@ impl<V: View> Dropdown<V> {
 
         Self {
             wrapper,
+            toggle_button,
+            backdrop,
             menu,
             toggle_click,
             backdrop_click,
+            document_click,
             keydown,
+            auto_close: false,
+            is_checkable: false,
             items,
-            open,
-            is_open: false,
-            flavor: flavor_proxy,
+            decorations: vec![],
+            highlighted: None,
+            state,
         }
     }
 
-    /// Add a menu item and return its index.
-    pub fn push(&mut self, label: impl AsRef<str>) -> usize {
+    /// Add a menu item holding `content` and return its index.
+    ///
+    /// The index counts only clickable items.
+    pub fn push_item(&mut self, content: T) -> usize {
         let index = self.items.len();
-        let item = DropdownItem::new(label);
+        let item = DropdownItem::new(content);
+        item.set_is_checkable(self.is_checkable);
         self.menu.append_child(&item);
         self.items.push(item);
         index
     }
 
-    /// Remove a menu item by index.
+    /// Returns the clickable item at the given index.
+    pub fn get(&self, index: usize) -> Option<&DropdownItem<V, T>> {
+        self.items.get(index)
+    }
+
+    /// Returns the clickable item at the given index, mutably.
+    pub fn get_mut(&mut self, index: usize) -> Option<&mut DropdownItem<V, T>> {
+        self.items.get_mut(index)
+    }
+
+    /// Returns the number of clickable items.
+    pub fn len(&self) -> usize {
+        self.items.len()
+    }
+
+    /// Returns `true` if there are no clickable items.
+    pub fn is_empty(&self) -> bool {
+        self.items.is_empty()
+    }
+
+    /// Add a horizontal divider to the menu.
+    pub fn push_divider(&mut self) {
+        let li = menu_divider::<V>();
+        self.menu.append_child(&li);
+        self.decorations.push(li);
+    }
+
+    /// Add a section header to the menu.
+    pub fn push_header(&mut self, text: impl AsRef<str>) {
+        let li = menu_header::<V>(text);
+        self.menu.append_child(&li);
+        self.decorations.push(li);
+    }
+
+    /// Add a greyed-out item that cannot be clicked.
+    pub fn push_disabled(&mut self, label: impl AsRef<str>) {
+        let li = menu_disabled::<V>(label);
+        self.menu.append_child(&li);
+        self.decorations.push(li);
+    }
+
+    /// Remove a clickable menu item by index, returning its content.
     ///
     /// ## Panics
     /// Panics if `index` >= len.
-    pub fn remove(&mut self, index: usize) {
+    pub fn remove(&mut self, index: usize) -> T {
+        self.set_highlighted(None);
         let item = self.items.remove(index);
         self.menu.remove_child(&item);
+        item.a.remove_child(&item.inner);
+        item.inner
+    }
+
+    /// Remove all dividers, headers and disabled items.
+    pub fn clear_decorations(&mut self) {
+        for li in self.decorations.drain(..) {
+            self.menu.remove_child(&li);
+        }
     }
 
     pub fn set_flavor(&mut self, flavor: Flavor) {
-        self.flavor.set(flavor);
+        self.state.modify(|s| s.flavor = flavor);
+    }
+
+    /// Set the side of the toggle button the menu opens on.
+    pub fn set_direction(&mut self, direction: DropDirection) {
+        self.state.modify(|s| s.direction = direction);
+    }
+
+    pub fn direction(&self) -> DropDirection {
+        self.state.direction
+    }
+
+    /// Set which edge of the toggle button the menu lines up with.
+    pub fn set_alignment(&mut self, alignment: DropdownAlignment) {
+        self.state.modify(|s| s.alignment = alignment);
+    }
+
+    pub fn alignment(&self) -> DropdownAlignment {
+        self.state.alignment
     }
 
     /// Show the dropdown menu.
     pub fn show(&mut self) {
-        self.is_open = true;
-        self.open.set(true);
+        self.state.modify(|s| s.is_open = true);
     }
 
     /// Hide the dropdown menu.
     pub fn hide(&mut self) {
-        self.is_open = false;
-        self.open.set(false);
+        self.state.modify(|s| s.is_open = false);
+        self.set_highlighted(None);
+    }
+
+    /// Returns `true` if the menu is showing.
+    pub fn is_open(&self) -> bool {
+        self.state.is_open
     }
 
     /// Toggle the dropdown menu.
     pub fn toggle(&mut self) {
-        self.is_open = !self.is_open;
-        self.open.set(self.is_open);
+        if self.state.is_open {
+            self.hide();
+        } else {
+            self.show();
+        }
+    }
+
+    /// Close the menu automatically when the document is clicked outside of
+    /// the dropdown.
+    ///
+    /// When enabled, clicks outside pass through to the page rather than
+    /// being swallowed by the backdrop, and [`Dropdown::step`] hides the menu
+    /// itself before returning [`Some(Dismissed)`]. Escape also closes the
+    /// menu.
+    pub fn set_auto_close(&mut self, auto_close: bool) {
+        self.auto_close = auto_close;
+        if auto_close {
+            self.backdrop.set_style("pointer-events", "none");
+        } else {
+            self.backdrop.remove_style("pointer-events");
+        }
+    }
+
+    /// Returns `true` if the menu closes itself on outside clicks.
+    pub fn is_auto_close(&self) -> bool {
+        self.auto_close
+    }
+
+    /// Resolves when the document is clicked outside of the dropdown.
+    async fn outside_click(&self) {
+        loop {
+            let ev = self.document_click.next().await;
+            let target = ev.dyn_ev(|e: &web_sys::Event| e.target()).flatten();
+            let is_inside = self
+                .wrapper
+                .dyn_el(|el: &web_sys::Node| {
+                    el.contains(target.as_ref().and_then(|t| t.dyn_ref::<web_sys::Node>()))
+                })
+                .unwrap_or(false);
+            if !is_inside {
+                return;
+            }
+        }
+    }
+
+    /// Turn the menu into a list of checkable items, or back into a list of
+    /// actions.
+    pub fn set_is_checkable(&mut self, is_checkable: bool) {
+        self.is_checkable = is_checkable;
+        for item in self.items.iter() {
+            item.set_is_checkable(is_checkable);
+        }
+    }
+
+    /// Returns `true` if clicking an item toggles its checked state.
+    pub fn is_checkable(&self) -> bool {
+        self.is_checkable
+    }
+
+    /// Check or uncheck the item at the given index.
+    ///
+    /// Returns `None` if the index is out of bounds.
+    pub fn set_is_checked(&mut self, index: usize, is_checked: bool) -> Option<()> {
+        self.items.get_mut(index)?.set_is_checked(is_checked);
+        Some(())
+    }
+
+    /// Returns `true` if the item at the given index is checked.
+    pub fn is_checked(&self, index: usize) -> bool {
+        self.items.get(index).is_some_and(|item| item.is_checked)
+    }
+
+    /// Returns the indices of all checked items, in menu order.
+    pub fn checked_indices(&self) -> Vec<usize> {
+        self.items
+            .iter()
+            .enumerate()
+            .filter_map(|(i, item)| item.is_checked.then_some(i))
+            .collect()
+    }
+
+    /// Report a click on (or Enter over) the item at `index`, toggling it
+    /// first if the dropdown is checkable.
+    fn select_item(&mut self, index: usize, event: V::Event) -> DropdownEvent<V> {
+        if !self.is_checkable {
+            return DropdownEvent::ItemClicked { index, event };
+        }
+        let is_checked = !self.is_checked(index);
+        self.set_is_checked(index, is_checked);
+        DropdownEvent::ItemToggled {
+            index,
+            is_checked,
+            event,
+        }
+    }
+
+    /// Returns the index of the item highlighted with the arrow keys, if any.
+    pub fn highlighted(&self) -> Option<usize> {
+        self.highlighted
+    }
+
+    fn set_highlighted(&mut self, highlighted: Option<usize>) {
+        if let Some(item) = self.highlighted.and_then(|i| self.items.get(i)) {
+            item.set_is_highlighted(false);
+        }
+        if let Some(item) = highlighted.and_then(|i| self.items.get(i)) {
+            item.set_is_highlighted(true);
+        }
+        self.highlighted = highlighted;
+    }
+
+    /// Move the highlight one item down (or up), wrapping around the ends.
+    fn move_highlight(&mut self, down: bool) {
+        let len = self.items.len();
+        if len == 0 {
+            return;
+        }
+        let next = match self.highlighted {
+            None if down => 0,
+            None => len - 1,
+            Some(i) if down => (i + 1) % len,
+            Some(i) => (i + len - 1) % len,
+        };
+        self.set_highlighted(Some(next));
     }
 
     fn item_click_events(&self) -> impl std::future::Future<Output = DropdownEvent<V>> + '_ {
         use mogwai::future::*;
 
         let events = self.items.iter().enumerate().map(|(index, item)| {
-            item.on_click
-                .next()
+            item.clicked()
                 .map(move |event| DropdownEvent::ItemClicked { index, event })
         });
         race_all(events)
@@ -172,46 +659,198 @@ impl<V: View> Dropdown<V> {
     ///
     /// Returns [`None`] when the toggle button was clicked (caller should call
     /// [`Dropdown::toggle`]), [`Some(ItemClicked)`] when a menu item was
-    /// clicked, or [`Some(Dismissed)`] when the dropdown was dismissed via
+    /// clicked ([`Some(ItemToggled)`] if the dropdown is checkable), or [`Some(Dismissed)`] when the dropdown was dismissed via
     /// Escape or a click outside.
     ///
     /// Escape and click-outside events are only returned when the dropdown is
-    /// open; when closed they are silently ignored.
-    pub async fn step(&self) -> Option<DropdownEvent<V>> {
+    /// open; when closed they are silently ignored. Arrow keys move the
+    /// highlight without returning, and Enter on a highlighted item returns
+    /// [`Some(ItemClicked)`] with the keyboard event. Escape moves focus back
+    /// to the toggle button before returning [`Some(Dismissed)`].
+    pub async fn step(&mut self) -> Option<DropdownEvent<V>> {
         use futures_lite::FutureExt;
         use mogwai::future::MogwaiFutureExt;
 
-        loop {
-            let escape = async {
-                loop {
-                    let ev = self.keydown.next().await;
-                    let is_escape = ev.when_event::<mogwai::web::Web, _>(|e: &web_sys::Event| {
-                        e.dyn_ref::<web_sys::KeyboardEvent>()
-                            .is_some_and(|ke| ke.key() == "Escape")
-                    });
-                    if is_escape == Some(true) {
-                        return;
-                    }
-                }
-            };
+        enum Action<V: View> {
+            Event(Option<DropdownEvent<V>>),
+            Key(MenuKey, V::Event),
+        }
 
-            let result = self
+        loop {
+            let action = self
                 .toggle_click
                 .next()
-                .map(|_| None)
-                .or(self.item_click_events().map(Some))
+                .map(|_| Action::Event(None))
+                .or(self.item_click_events().map(|ev| Action::Event(Some(ev))))
                 .or(self
                     .backdrop_click
                     .next()
-                    .map(|_| Some(DropdownEvent::Dismissed)))
-                .or(escape.map(|_| Some(DropdownEvent::Dismissed)))
+                    .map(|_| Action::Event(Some(DropdownEvent::Dismissed))))
+                .or(async {
+                    if self.auto_close {
+                        self.outside_click().await;
+                    } else {
+                        std::future::pending::<()>().await;
+                    }
+                    Action::Event(Some(DropdownEvent::Dismissed))
+                })
+                .or(menu_key_pressed::<V>(&self.keydown).map(|(key, ev)| Action::Key(key, ev)))
                 .await;
 
-            // Only return Dismissed when the dropdown is actually open;
-            // otherwise loop back and wait for a meaningful event.
-            match &result {
-                Some(DropdownEvent::Dismissed) if !self.is_open => continue,
-                _ => return result,
+            match action {
+                // Only return Dismissed when the dropdown is actually open;
+                // otherwise loop back and wait for a meaningful event.
+                Action::Event(Some(DropdownEvent::Dismissed)) if !self.state.is_open => continue,
+                Action::Event(Some(DropdownEvent::Dismissed)) if self.auto_close => {
+                    self.hide();
+                    return Some(DropdownEvent::Dismissed);
+                }
+                Action::Event(Some(DropdownEvent::ItemClicked { index, event })) => {
+                    return Some(self.select_item(index, event));
+                }
+                Action::Event(result) => return result,
+                // Keys are ignored while the menu is closed.
+                Action::Key(_, _) if !self.state.is_open => continue,
+                Action::Key(MenuKey::Up, ev) => {
+                    // Keep the page from scrolling.
+                    ev.dyn_ev(|e: &web_sys::Event| e.prevent_default());
+                    self.move_highlight(false);
+                }
+                Action::Key(MenuKey::Down, ev) => {
+                    ev.dyn_ev(|e: &web_sys::Event| e.prevent_default());
+                    self.move_highlight(true);
+                }
+                Action::Key(MenuKey::Enter, event) => {
+                    if let Some(index) = self.highlighted {
+                        // Don't let Enter also "click" the focused toggle.
+                        event.dyn_ev(|e: &web_sys::Event| e.prevent_default());
+                        return Some(self.select_item(index, event));
+                    }
+                }
+                Action::Key(MenuKey::Escape, _) => {
+                    if self.auto_close {
+                        self.hide();
+                    }
+                    self.toggle_button.dyn_el(|el: &web_sys::HtmlElement| {
+                        el.focus().ok();
+                    });
+                    return Some(DropdownEvent::Dismissed);
+                }
+            }
+        }
+    }
+}
+
+/// Event emitted by a [`SplitButton`].
+pub enum SplitButtonEvent<V: View> {
+    /// The primary action button was clicked.
+    Primary(V::Event),
+    /// A menu item was clicked. The menu has already been closed.
+    Item { index: usize, event: V::Event },
+}
+
+/// A primary action [`Button`] with an attached [`Dropdown`] toggle.
+///
+/// Unlike a plain [`Dropdown`], the split button opens and closes its own
+/// menu: [`SplitButton::step`] handles the toggle, Escape and click-outside
+/// internally and only returns when the primary button or a menu item is
+/// clicked.
+#[derive(ViewChild, ViewProperties)]
+pub struct SplitButton<V: View> {
+    #[child]
+    #[properties]
+    wrapper: V::Element,
+    button: Button<V>,
+    dropdown: Dropdown<V, V::Text>,
+}
+
+impl<V: View> SplitButton<V> {
+    pub fn new(label: impl AsRef<str>, flavor: Flavor) -> Self {
+        let mut button = Button::new(label, Some(flavor));
+        button.set_has_icon(false);
+        let dropdown = Dropdown::new_split(flavor);
+
+        rsx! {
+            let wrapper = div(class = "btn-group split-button", role = "group") {
+                {&button}
+                {&dropdown}
+            }
+        }
+
+        Self {
+            wrapper,
+            button,
+            dropdown,
+        }
+    }
+
+    /// Access the primary action button.
+    pub fn button(&self) -> &Button<V> {
+        &self.button
+    }
+
+    /// Mutably access the primary action button.
+    pub fn button_mut(&mut self) -> &mut Button<V> {
+        &mut self.button
+    }
+
+    /// Add a menu item and return its index.
+    pub fn push(&mut self, label: impl AsRef<str>) -> usize {
+        self.dropdown.push(label)
+    }
+
+    /// Remove a menu item by index.
+    ///
+    /// ## Panics
+    /// Panics if `index` >= len.
+    pub fn remove(&mut self, index: usize) {
+        self.dropdown.remove(index);
+    }
+
+    pub fn set_flavor(&mut self, flavor: Flavor) {
+        self.button.set_flavor(Some(flavor));
+        self.dropdown.set_flavor(flavor);
+    }
+
+    /// Show the dropdown menu.
+    pub fn show(&mut self) {
+        self.dropdown.show();
+    }
+
+    /// Hide the dropdown menu.
+    pub fn hide(&mut self) {
+        self.dropdown.hide();
+    }
+
+    /// Await the next primary click or menu item click.
+    ///
+    /// Toggle clicks, Escape and clicks outside the open menu are handled
+    /// here without returning.
+    pub async fn step(&mut self) -> SplitButtonEvent<V> {
+        use futures_lite::FutureExt;
+        use mogwai::future::MogwaiFutureExt;
+
+        loop {
+            let event = self
+                .button
+                .step()
+                .map(|event| Some(SplitButtonEvent::Primary(event)))
+                .or(self.dropdown.step().map(|event| match event {
+                    Some(DropdownEvent::ItemClicked { index, event })
+                    | Some(DropdownEvent::ItemToggled { index, event, .. }) => {
+                        Some(SplitButtonEvent::Item { index, event })
+                    }
+                    Some(DropdownEvent::Dismissed) | None => None,
+                }))
+                .await;
+
+            match event {
+                Some(event) => {
+                    self.dropdown.hide();
+                    return event;
+                }
+                // The toggle was clicked, or the open menu was dismissed.
+                None => self.dropdown.toggle(),
             }
         }
     }
@@ -222,28 +861,49 @@ pub mod library {
     use mogwai::prelude::*;
 
     use super::*;
+    use crate::library::log_event;
 
     #[derive(ViewChild)]
     pub struct DropdownLibraryItem<V: View> {
         #[child]
         pub wrapper: V::Element,
-        dropdown: Dropdown<V>,
+        dropdown: Dropdown<V, V::Text>,
+        filter: Dropdown<V, V::Text>,
+        split_button: SplitButton<V>,
         status_text: V::Text,
     }
 
     impl<V: View> Default for DropdownLibraryItem<V> {
         fn default() -> Self {
             let mut dropdown = Dropdown::new("Select an item", Flavor::Primary);
+            dropdown.push_header("Actions");
             dropdown.push("Action");
             dropdown.push("Another action");
+            dropdown.push_disabled("Unavailable action");
+            dropdown.push_divider();
             dropdown.push("Something else");
+            dropdown.set_auto_close(true);
+
+            let mut filter = Dropdown::new("Show", Flavor::Secondary);
+            filter.set_is_checkable(true);
+            filter.set_auto_close(true);
+            for label in ["Documents", "Applications", "Folders"] {
+                filter.push(label);
+            }
+            filter.set_is_checked(0, true);
+
+            let mut split_button = SplitButton::new("Save", Flavor::Success);
+            split_button.push("Save as\u{2026}");
+            split_button.push("Save a copy");
 
             let status_text = V::Text::new("No item selected yet.");
 
             rsx! {
                 let wrapper = div() {
-                    div(class = "mb-3") {
+                    div(class = "mb-3 d-flex gap-2") {
                         {&dropdown}
+                        {&filter}
+                        {&split_button}
                     }
                     p() {
                         {&status_text}
@@ -254,6 +914,8 @@ pub mod library {
             Self {
                 wrapper,
                 dropdown,
+                filter,
+                split_button,
                 status_text,
             }
         }
@@ -261,19 +923,64 @@ pub mod library {
 
     impl<V: View> DropdownLibraryItem<V> {
         pub async fn step(&mut self) {
-            match self.dropdown.step().await {
-                None => {
+            use futures_lite::FutureExt;
+            use mogwai::future::MogwaiFutureExt;
+
+            enum Action<V: View> {
+                Dropdown(Option<DropdownEvent<V>>),
+                Filter(Option<DropdownEvent<V>>),
+                Split(SplitButtonEvent<V>),
+            }
+
+            let action = self
+                .dropdown
+                .step()
+                .map(Action::Dropdown)
+                .or(self.filter.step().map(Action::Filter))
+                .or(self.split_button.step().map(Action::Split))
+                .await;
+            match action {
+                Action::Dropdown(None) => {
                     self.dropdown.toggle();
                 }
-                Some(DropdownEvent::ItemClicked { index, .. }) => {
+                Action::Dropdown(Some(DropdownEvent::ItemClicked { index, .. })) => {
                     self.dropdown.hide();
                     let labels = ["Action", "Another action", "Something else"];
                     let label = labels.get(index).unwrap_or(&"Unknown");
+                    log_event("Dropdown", format!("Clicked {label}"));
                     self.status_text.set_text(format!("Selected: {label}"));
                 }
-                Some(DropdownEvent::Dismissed) => {
+                Action::Dropdown(Some(_)) => {
                     self.dropdown.hide();
                 }
+                Action::Filter(None) => {
+                    self.filter.toggle();
+                }
+                Action::Filter(Some(DropdownEvent::ItemToggled { .. })) => {
+                    let labels = ["Documents", "Applications", "Folders"];
+                    let shown = self
+                        .filter
+                        .checked_indices()
+                        .into_iter()
+                        .filter_map(|i| labels.get(i).copied())
+                        .collect::<Vec<_>>();
+                    log_event("Dropdown", format!("Filter: {}", shown.join(", ")));
+                    self.status_text
+                        .set_text(format!("Showing: {}", shown.join(", ")));
+                }
+                Action::Filter(Some(_)) => {
+                    self.filter.hide();
+                }
+                Action::Split(SplitButtonEvent::Primary(_)) => {
+                    log_event("SplitButton", "Clicked Save");
+                    self.status_text.set_text("Split button: Save");
+                }
+                Action::Split(SplitButtonEvent::Item { index, .. }) => {
+                    let labels = ["Save as\u{2026}", "Save a copy"];
+                    let label = labels.get(index).unwrap_or(&"Unknown");
+                    log_event("SplitButton", format!("Clicked {label}"));
+                    self.status_text.set_text(format!("Split button: {label}"));
+                }
             }
         }
     }
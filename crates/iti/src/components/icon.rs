@@ -2,7 +2,85 @@
 //!
 //! Supports Font Awesome 6 Free icon styles (Solid, Regular, Brands) with
 //! a comprehensive set of named glyph variants covering common UI needs.
-use mogwai::prelude::*;
+//!
+//! Glyphs can also be drawn from Bootstrap Icons (`bi-*`) instead; see
+//! [`IconSource`].
+use std::cell::Cell;
+
+use mogwai::{prelude::*, web::WebElement};
+
+use super::{badge::Badge, Flavor};
+
+const SVG_NS: &str = "http://www.w3.org/2000/svg";
+
+/// A custom glyph drawn as an inline `<svg>` path, for use with
+/// [`IconGlyph::Svg`].
+///
+/// The path is filled with the current text color and sized like a font
+/// glyph, so [`IconSize`] and flavor colors apply as usual.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct SvgGlyph {
+    /// The `viewBox` of the `<svg>`, e.g. `"0 0 24 24"`.
+    pub view_box: &'static str,
+    /// The path's `d` attribute.
+    pub path: &'static str,
+}
+
+impl SvgGlyph {
+    /// A glyph drawn in a `0 0 24 24` view box.
+    pub const fn new(path: &'static str) -> Self {
+        Self {
+            view_box: "0 0 24 24",
+            path,
+        }
+    }
+
+    /// A glyph drawn in the given view box.
+    pub const fn with_view_box(view_box: &'static str, path: &'static str) -> Self {
+        Self { view_box, path }
+    }
+}
+
+/// The icon font an [`Icon`] is drawn from.
+///
+/// The named [`IconGlyph`] variants map onto both sets, so switching sources
+/// does not require changing glyphs. [`IconGlyph::Other`] is passed through
+/// as-is and should name a class from the chosen set.
+///
+/// Bootstrap Icons has no separate styles, so [`IconStyle`] is ignored for
+/// [`IconSource::Bootstrap`]. Its stylesheet is not bundled with iti; load it
+/// with [`Loader`](crate::assets::Loader), e.g.
+/// `Loader::new().bootstrap_icons(Source::Cdn).inject()`.
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub enum IconSource {
+    /// Font Awesome 6 Free (`fa-*`).
+    #[default]
+    FontAwesome,
+    /// Bootstrap Icons (`bi-*`).
+    Bootstrap,
+}
+
+thread_local! {
+    /// The source [`Icon::new`] draws from.
+    static DEFAULT_SOURCE: Cell<IconSource> = const { Cell::new(IconSource::FontAwesome) };
+}
+
+impl IconSource {
+    /// Set the source used by [`Icon::new`] and [`Icon::with_style`],
+    /// including the icons built into other iti components.
+    ///
+    /// Call this before building any views. Passing
+    /// [`IconSource::Bootstrap`] lets an application drop Font Awesome
+    /// entirely.
+    pub fn set_default(source: IconSource) {
+        DEFAULT_SOURCE.set(source);
+    }
+
+    /// The source used by [`Icon::new`] and [`Icon::with_style`].
+    pub fn current_default() -> IconSource {
+        DEFAULT_SOURCE.get()
+    }
+}
 
 /// Font Awesome icon style.
 ///
@@ -57,6 +135,20 @@ impl IconSize {
         }
     }
 
+    /// The size class used with [`IconSource::Bootstrap`] (defined in
+    /// `iti.css`).
+    pub fn bootstrap_class(&self) -> &str {
+        match self {
+            IconSize::Xxs => "bi-2xs",
+            IconSize::Xs => "bi-xs",
+            IconSize::Sm => "bi-sm",
+            IconSize::Regular => "",
+            IconSize::Large => "bi-lg",
+            IconSize::Xl => "bi-xl",
+            IconSize::Xxl => "bi-2xl",
+        }
+    }
+
     /// All sizes in order from smallest to largest.
     pub const ALL: [IconSize; 7] = [
         IconSize::Xxs,
@@ -69,12 +161,88 @@ impl IconSize {
     ];
 }
 
+/// Font Awesome animation classes.
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub enum IconAnimation {
+    #[default]
+    None,
+    /// Continuous rotation (`fa-spin`).
+    Spin,
+    /// Rotation in eight steps (`fa-spin-pulse`).
+    SpinPulse,
+    /// Scale up and down (`fa-beat`).
+    Beat,
+    /// Fade in and out (`fa-fade`).
+    Fade,
+}
+
+impl IconAnimation {
+    pub fn as_str(&self) -> &str {
+        match self {
+            IconAnimation::None => "",
+            IconAnimation::Spin => "fa-spin",
+            IconAnimation::SpinPulse => "fa-spin-pulse",
+            IconAnimation::Beat => "fa-beat",
+            IconAnimation::Fade => "fa-fade",
+        }
+    }
+}
+
+/// Fixed rotation of an icon.
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub enum IconRotation {
+    #[default]
+    None,
+    /// `fa-rotate-90`
+    Rotate90,
+    /// `fa-rotate-180`
+    Rotate180,
+    /// `fa-rotate-270`
+    Rotate270,
+}
+
+impl IconRotation {
+    pub fn as_str(&self) -> &str {
+        match self {
+            IconRotation::None => "",
+            IconRotation::Rotate90 => "fa-rotate-90",
+            IconRotation::Rotate180 => "fa-rotate-180",
+            IconRotation::Rotate270 => "fa-rotate-270",
+        }
+    }
+}
+
+/// Mirroring of an icon.
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub enum IconFlip {
+    #[default]
+    None,
+    /// `fa-flip-horizontal`
+    Horizontal,
+    /// `fa-flip-vertical`
+    Vertical,
+    /// `fa-flip-both`
+    Both,
+}
+
+impl IconFlip {
+    pub fn as_str(&self) -> &str {
+        match self {
+            IconFlip::None => "",
+            IconFlip::Horizontal => "fa-flip-horizontal",
+            IconFlip::Vertical => "fa-flip-vertical",
+            IconFlip::Both => "fa-flip-both",
+        }
+    }
+}
+
 /// Font Awesome icon glyph identifiers.
 ///
 /// Named variants cover common UI icon needs across navigation, actions,
 /// status, content, objects, and people categories. Use [`IconGlyph::Other`]
 /// for any glyph not listed here — pass the Font Awesome class name
-/// (e.g. `"fa-wand-magic-wand"`).
+/// (e.g. `"fa-wand-magic-wand"`), or [`IconGlyph::Svg`] for a bespoke glyph
+/// that isn't in any icon font.
 #[derive(Clone, Copy, Debug, PartialEq)]
 pub enum IconGlyph {
     // ── Navigation ──────────────────────────────────────────────
@@ -90,6 +258,7 @@ pub enum IconGlyph {
 
     // ── Actions ─────────────────────────────────────────────────
     Check,
+    Copy,
     Download,
     Filter,
     Link,
@@ -97,9 +266,11 @@ pub enum IconGlyph {
     Minus,
     Pen,
     Plus,
+    Redo,
     Share,
     Sort,
     Trash,
+    Undo,
     Upload,
 
     // ── Status / Feedback ───────────────────────────────────────
@@ -129,6 +300,10 @@ pub enum IconGlyph {
     EyeSlash,
     Gear,
     Lock,
+    Moon,
+    Sun,
+    /// A half-filled circle, e.g. for an automatic light/dark color mode.
+    CircleHalfStroke,
 
     // ── People / Social ─────────────────────────────────────────
     Globe,
@@ -145,6 +320,9 @@ pub enum IconGlyph {
     ///
     /// Pass the Font Awesome class name, e.g. `"fa-wand-magic-wand"`.
     Other(&'static str),
+
+    /// A custom glyph rendered as an inline `<svg>` path.
+    Svg(SvgGlyph),
 }
 
 impl IconGlyph {
@@ -163,6 +341,7 @@ impl IconGlyph {
 
             // Actions
             IconGlyph::Check => "fa-check",
+            IconGlyph::Copy => "fa-copy",
             IconGlyph::Download => "fa-download",
             IconGlyph::Filter => "fa-filter",
             IconGlyph::Link => "fa-link",
@@ -170,9 +349,11 @@ impl IconGlyph {
             IconGlyph::Minus => "fa-minus",
             IconGlyph::Pen => "fa-pen",
             IconGlyph::Plus => "fa-plus",
+            IconGlyph::Redo => "fa-rotate-right",
             IconGlyph::Share => "fa-share",
             IconGlyph::Sort => "fa-sort",
             IconGlyph::Trash => "fa-trash",
+            IconGlyph::Undo => "fa-rotate-left",
             IconGlyph::Upload => "fa-upload",
 
             // Status / Feedback
@@ -202,6 +383,9 @@ impl IconGlyph {
             IconGlyph::EyeSlash => "fa-eye-slash",
             IconGlyph::Gear => "fa-gear",
             IconGlyph::Lock => "fa-lock",
+            IconGlyph::Moon => "fa-moon",
+            IconGlyph::Sun => "fa-sun",
+            IconGlyph::CircleHalfStroke => "fa-circle-half-stroke",
 
             // People / Social
             IconGlyph::Globe => "fa-globe",
@@ -215,6 +399,88 @@ impl IconGlyph {
             IconGlyph::TableCells => "fa-table-cells",
 
             IconGlyph::Other(s) => s,
+            IconGlyph::Svg(_) => "",
+        }
+    }
+
+    /// The equivalent Bootstrap Icons class.
+    ///
+    /// [`IconGlyph::Other`] is returned unchanged, and [`IconGlyph::Svg`] has
+    /// no class.
+    pub fn bootstrap_class(&self) -> &str {
+        match self {
+            // Navigation
+            IconGlyph::ArrowDown => "bi-arrow-down",
+            IconGlyph::ArrowLeft => "bi-arrow-left",
+            IconGlyph::ArrowRight => "bi-arrow-right",
+            IconGlyph::ArrowUp => "bi-arrow-up",
+            IconGlyph::Bars => "bi-list",
+            IconGlyph::ChevronDown => "bi-chevron-down",
+            IconGlyph::ChevronLeft => "bi-chevron-left",
+            IconGlyph::ChevronRight => "bi-chevron-right",
+            IconGlyph::ChevronUp => "bi-chevron-up",
+
+            // Actions
+            IconGlyph::Check => "bi-check-lg",
+            IconGlyph::Copy => "bi-copy",
+            IconGlyph::Download => "bi-download",
+            IconGlyph::Filter => "bi-funnel",
+            IconGlyph::Link => "bi-link-45deg",
+            IconGlyph::MagnifyingGlass => "bi-search",
+            IconGlyph::Minus => "bi-dash-lg",
+            IconGlyph::Pen => "bi-pen",
+            IconGlyph::Plus => "bi-plus-lg",
+            IconGlyph::Redo => "bi-arrow-clockwise",
+            IconGlyph::Share => "bi-share",
+            IconGlyph::Sort => "bi-arrow-down-up",
+            IconGlyph::Trash => "bi-trash",
+            IconGlyph::Undo => "bi-arrow-counterclockwise",
+            IconGlyph::Upload => "bi-upload",
+
+            // Status / Feedback
+            IconGlyph::Bell => "bi-bell",
+            IconGlyph::CircleCheck => "bi-check-circle-fill",
+            IconGlyph::CircleExclamation => "bi-exclamation-circle-fill",
+            IconGlyph::CircleInfo => "bi-info-circle-fill",
+            IconGlyph::CircleMinus => "bi-dash-circle-fill",
+            IconGlyph::CirclePlus => "bi-plus-circle-fill",
+            IconGlyph::CircleXmark => "bi-x-circle-fill",
+            IconGlyph::Flag => "bi-flag",
+            IconGlyph::Spinner => "bi-arrow-repeat",
+            IconGlyph::TriangleExclamation => "bi-exclamation-triangle-fill",
+
+            // Content
+            IconGlyph::Calendar => "bi-calendar",
+            IconGlyph::Clock => "bi-clock",
+            IconGlyph::Envelope => "bi-envelope",
+            IconGlyph::File => "bi-file-earmark",
+            IconGlyph::Folder => "bi-folder",
+            IconGlyph::Image => "bi-image",
+            IconGlyph::Tag => "bi-tag",
+
+            // Objects / Settings
+            IconGlyph::Bolt => "bi-lightning-fill",
+            IconGlyph::Eye => "bi-eye",
+            IconGlyph::EyeSlash => "bi-eye-slash",
+            IconGlyph::Gear => "bi-gear",
+            IconGlyph::Lock => "bi-lock",
+            IconGlyph::Moon => "bi-moon",
+            IconGlyph::Sun => "bi-sun",
+            IconGlyph::CircleHalfStroke => "bi-circle-half",
+
+            // People / Social
+            IconGlyph::Globe => "bi-globe",
+            IconGlyph::Heart => "bi-heart",
+            IconGlyph::Star => "bi-star",
+            IconGlyph::User => "bi-person",
+            IconGlyph::Xmark => "bi-x-lg",
+
+            // Layout
+            IconGlyph::Grip => "bi-grip-horizontal",
+            IconGlyph::TableCells => "bi-grid-3x3",
+
+            IconGlyph::Other(s) => s,
+            IconGlyph::Svg(_) => "",
         }
     }
 
@@ -231,6 +497,7 @@ impl IconGlyph {
             IconGlyph::ChevronRight => "ChevronRight",
             IconGlyph::ChevronUp => "ChevronUp",
             IconGlyph::Check => "Check",
+            IconGlyph::Copy => "Copy",
             IconGlyph::Download => "Download",
             IconGlyph::Filter => "Filter",
             IconGlyph::Link => "Link",
@@ -238,9 +505,11 @@ impl IconGlyph {
             IconGlyph::Minus => "Minus",
             IconGlyph::Pen => "Pen",
             IconGlyph::Plus => "Plus",
+            IconGlyph::Redo => "Redo",
             IconGlyph::Share => "Share",
             IconGlyph::Sort => "Sort",
             IconGlyph::Trash => "Trash",
+            IconGlyph::Undo => "Undo",
             IconGlyph::Upload => "Upload",
             IconGlyph::Bell => "Bell",
             IconGlyph::CircleCheck => "CircleCheck",
@@ -264,6 +533,9 @@ impl IconGlyph {
             IconGlyph::EyeSlash => "EyeSlash",
             IconGlyph::Gear => "Gear",
             IconGlyph::Lock => "Lock",
+            IconGlyph::Moon => "Moon",
+            IconGlyph::Sun => "Sun",
+            IconGlyph::CircleHalfStroke => "CircleHalfStroke",
             IconGlyph::Globe => "Globe",
             IconGlyph::Heart => "Heart",
             IconGlyph::Star => "Star",
@@ -272,6 +544,7 @@ impl IconGlyph {
             IconGlyph::Grip => "Grip",
             IconGlyph::TableCells => "TableCells",
             IconGlyph::Other(s) => s,
+            IconGlyph::Svg(_) => "Svg",
         }
     }
 
@@ -288,8 +561,9 @@ impl IconGlyph {
         IconGlyph::ChevronUp,
     ];
 
-    pub const ACTIONS: [IconGlyph; 12] = [
+    pub const ACTIONS: [IconGlyph; 15] = [
         IconGlyph::Check,
+        IconGlyph::Copy,
         IconGlyph::Download,
         IconGlyph::Filter,
         IconGlyph::Link,
@@ -297,9 +571,11 @@ impl IconGlyph {
         IconGlyph::Minus,
         IconGlyph::Pen,
         IconGlyph::Plus,
+        IconGlyph::Redo,
         IconGlyph::Share,
         IconGlyph::Sort,
         IconGlyph::Trash,
+        IconGlyph::Undo,
         IconGlyph::Upload,
     ];
 
@@ -326,12 +602,15 @@ impl IconGlyph {
         IconGlyph::Tag,
     ];
 
-    pub const OBJECTS: [IconGlyph; 5] = [
+    pub const OBJECTS: [IconGlyph; 8] = [
         IconGlyph::Bolt,
         IconGlyph::Eye,
         IconGlyph::EyeSlash,
         IconGlyph::Gear,
         IconGlyph::Lock,
+        IconGlyph::Moon,
+        IconGlyph::Sun,
+        IconGlyph::CircleHalfStroke,
     ];
 
     pub const PEOPLE: [IconGlyph; 5] = [
@@ -346,22 +625,79 @@ impl IconGlyph {
 }
 
 struct IconState {
+    source: IconSource,
     style: IconStyle,
     glyph: IconGlyph,
     size: IconSize,
+    animation: IconAnimation,
+    rotation: IconRotation,
+    flip: IconFlip,
+    /// Set while the icon is a layer of an [`IconStack`].
+    stack_scale: Option<IconStackScale>,
+    is_inverse: bool,
+    is_fixed_width: bool,
+    flavor: Option<Flavor>,
 }
 
-/// A Font Awesome icon element.
+impl IconState {
+    fn class(&self) -> String {
+        let mut class = match (self.source, self.glyph) {
+            (IconSource::FontAwesome, IconGlyph::Svg(_)) => {
+                format!("iti-svg-icon {}", self.size.as_str())
+            }
+            (IconSource::Bootstrap, IconGlyph::Svg(_)) => {
+                format!("iti-svg-icon {}", self.size.bootstrap_class())
+            }
+            (IconSource::FontAwesome, _) => format!(
+                "{} {} {}",
+                self.style.as_str(),
+                self.glyph.as_str(),
+                self.size.as_str()
+            ),
+            (IconSource::Bootstrap, _) => format!(
+                "bi {} {}",
+                self.glyph.bootstrap_class(),
+                self.size.bootstrap_class()
+            ),
+        };
+        // Modifier classes share Font Awesome's names; `iti.css` provides
+        // them for Bootstrap Icons.
+        for modifier in [
+            self.animation.as_str(),
+            self.rotation.as_str(),
+            self.flip.as_str(),
+            self.stack_scale.map(|s| s.as_str()).unwrap_or(""),
+            if self.is_inverse { "fa-inverse" } else { "" },
+            if self.is_fixed_width { "fa-fw" } else { "" },
+        ] {
+            if !modifier.is_empty() {
+                class.push(' ');
+                class.push_str(modifier);
+            }
+        }
+        if let Some(flavor) = self.flavor {
+            class.push_str(&format!(" text-{flavor}"));
+        }
+        class
+    }
+}
+
+/// A Font Awesome (or Bootstrap Icons) icon element.
 ///
-/// Supports setting the glyph, size, style, and visibility. Use
+/// Supports setting the glyph, size, style, source, and visibility. Use
 /// [`ViewProperties::add_class`] and [`ViewProperties::remove_class`] for
 /// additional CSS classes.
 #[derive(ViewChild, ViewProperties)]
 pub struct Icon<V: View> {
+    /// Holds the `<i>` and its badge. It has no box of its own
+    /// (`display: contents`) until a badge is attached.
     #[child]
+    wrapper: V::Element,
     #[properties]
     i: V::Element,
     state: Proxy<IconState>,
+    badge: Option<Badge<V>>,
+    is_visible: Cell<bool>,
 }
 
 impl<V: View> ViewEventTarget<V> for Icon<V> {
@@ -377,27 +713,75 @@ impl<V: View> Icon<V> {
     }
 
     /// Create an icon with explicit glyph, size, and style.
+    ///
+    /// The icon is drawn from [`IconSource::current_default`].
     pub fn with_style(glyph: IconGlyph, size: IconSize, style: IconStyle) -> Self {
-        let mut state = Proxy::new(IconState { style, glyph, size });
+        let mut state = Proxy::new(IconState {
+            source: IconSource::current_default(),
+            style,
+            glyph,
+            size,
+            animation: IconAnimation::None,
+            rotation: IconRotation::None,
+            flip: IconFlip::None,
+            stack_scale: None,
+            is_inverse: false,
+            is_fixed_width: false,
+            flavor: None,
+        });
 
         rsx! {
-            let i = i(
-                class = state(
-                    s => format!(
-                        "{} {} {}",
-                        s.style.as_str(),
-                        s.glyph.as_str(),
-                        s.size.as_str()
-                    )
-                ),
-            ) {}
+            let wrapper = span(style:display = "contents") {
+                let i = i(class = state(s => s.class())) {}
+            }
         }
 
-        Self { i, state }
+        let icon = Self {
+            wrapper,
+            i,
+            state,
+            badge: None,
+            is_visible: Cell::new(true),
+        };
+        icon.sync_svg();
+        icon
     }
 
     pub fn set_glyph(&mut self, glyph: IconGlyph) {
+        let had_svg = matches!(self.state.glyph, IconGlyph::Svg(_));
         self.state.modify(|s| s.glyph = glyph);
+        if had_svg || matches!(glyph, IconGlyph::Svg(_)) {
+            self.sync_svg();
+        }
+    }
+
+    /// Replace the inline `<svg>` child to match an [`IconGlyph::Svg`], or
+    /// remove it for font glyphs.
+    fn sync_svg(&self) {
+        let glyph = self.state.glyph;
+        self.i.dyn_el(|el: &web_sys::Element| {
+            if let Ok(Some(old)) = el.query_selector(":scope > svg") {
+                old.remove();
+            }
+            let IconGlyph::Svg(svg_glyph) = glyph else {
+                return;
+            };
+            let Some(document) = el.owner_document() else {
+                return;
+            };
+            let (Ok(svg), Ok(path)) = (
+                document.create_element_ns(Some(SVG_NS), "svg"),
+                document.create_element_ns(Some(SVG_NS), "path"),
+            ) else {
+                return;
+            };
+            svg.set_attribute("viewBox", svg_glyph.view_box).ok();
+            svg.set_attribute("fill", "currentColor").ok();
+            svg.set_attribute("aria-hidden", "true").ok();
+            path.set_attribute("d", svg_glyph.path).ok();
+            svg.append_child(&path).ok();
+            el.insert_before(&svg, el.first_child().as_ref()).ok();
+        });
     }
 
     pub fn set_size(&mut self, size: IconSize) {
@@ -408,11 +792,233 @@ impl<V: View> Icon<V> {
         self.state.modify(|s| s.style = style);
     }
 
+    /// Animate the icon, e.g. [`IconAnimation::Spin`] for a loading state.
+    pub fn set_animation(&mut self, animation: IconAnimation) {
+        self.state.modify(|s| s.animation = animation);
+    }
+
+    pub fn animation(&self) -> IconAnimation {
+        self.state.animation
+    }
+
+    pub fn set_rotation(&mut self, rotation: IconRotation) {
+        self.state.modify(|s| s.rotation = rotation);
+    }
+
+    pub fn rotation(&self) -> IconRotation {
+        self.state.rotation
+    }
+
+    /// Mirror the icon horizontally, vertically or both.
+    pub fn set_flip(&mut self, flip: IconFlip) {
+        self.state.modify(|s| s.flip = flip);
+    }
+
+    pub fn flip(&self) -> IconFlip {
+        self.state.flip
+    }
+
+    /// Give the icon a fixed width (`fa-fw`) so icons line up in menus and
+    /// lists regardless of glyph.
+    pub fn set_fixed_width(&mut self, is_fixed_width: bool) {
+        self.state.modify(|s| s.is_fixed_width = is_fixed_width);
+    }
+
+    /// Color the icon with the given flavor (`text-{flavor}`), or inherit
+    /// the surrounding text color with `None`.
+    pub fn set_flavor(&mut self, flavor: Option<Flavor>) {
+        self.state.modify(|s| s.flavor = flavor);
+    }
+
+    pub fn flavor(&self) -> Option<Flavor> {
+        self.state.flavor
+    }
+
+    /// Draw the icon in white (`fa-inverse`), e.g. on top of a filled
+    /// layer in an [`IconStack`].
+    pub fn set_is_inverse(&mut self, is_inverse: bool) {
+        self.state.modify(|s| s.is_inverse = is_inverse);
+    }
+
+    /// Draw this icon from a different icon font.
+    pub fn set_source(&mut self, source: IconSource) {
+        self.state.modify(|s| s.source = source);
+    }
+
+    pub fn source(&self) -> IconSource {
+        self.state.source
+    }
+
     pub fn set_is_visible(&self, is_visible: bool) {
-        if is_visible {
-            self.i.remove_style("display");
+        self.is_visible.set(is_visible);
+        self.sync_wrapper();
+    }
+
+    /// Show or hide the wrapper, giving it a box only while it holds a
+    /// badge.
+    fn sync_wrapper(&self) {
+        let display = if !self.is_visible.get() {
+            "none"
+        } else if self.badge.is_some() {
+            "inline-block"
         } else {
-            self.i.set_style("display", "none");
+            "contents"
+        };
+        self.wrapper.set_style("display", display);
+    }
+
+    /// Attach a badge to the icon's top-right corner, replacing any
+    /// previous one, which is returned.
+    ///
+    /// Pass `None` to remove the badge. The badge is made positioned
+    /// (see [`Badge::set_is_positioned`]).
+    pub fn set_badge(&mut self, badge: Option<Badge<V>>) -> Option<Badge<V>> {
+        let previous = self.badge.take();
+        if let Some(previous) = previous.as_ref() {
+            self.wrapper.remove_child(previous);
+            self.wrapper.remove_style("position");
         }
+        if let Some(mut badge) = badge {
+            badge.set_is_positioned(true);
+            self.wrapper.set_style("position", "relative");
+            self.wrapper.append_child(&badge);
+            self.badge = Some(badge);
+        }
+        self.sync_wrapper();
+        previous
+    }
+
+    pub fn get_badge(&self) -> Option<&Badge<V>> {
+        self.badge.as_ref()
+    }
+
+    pub fn get_badge_mut(&mut self) -> Option<&mut Badge<V>> {
+        self.badge.as_mut()
+    }
+}
+
+/// The size of a layer within an [`IconStack`].
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub enum IconStackScale {
+    /// Regular sized layer (`fa-stack-1x`), e.g. a glyph inside a circle.
+    #[default]
+    Regular,
+    /// Double sized layer (`fa-stack-2x`), e.g. a background circle or a
+    /// ban symbol over another glyph.
+    Large,
+}
+
+impl IconStackScale {
+    pub fn as_str(&self) -> &str {
+        match self {
+            IconStackScale::Regular => "fa-stack-1x",
+            IconStackScale::Large => "fa-stack-2x",
+        }
+    }
+}
+
+struct IconStackState {
+    source: IconSource,
+    size: IconSize,
+}
+
+/// Several [`Icon`]s layered on top of each other (`fa-stack`).
+///
+/// Layers are drawn in the order they are pushed, so push the background
+/// first. Each layer stays a regular [`Icon`] and can be changed in place
+/// with [`IconStack::get_mut`].
+#[derive(ViewChild, ViewProperties)]
+pub struct IconStack<V: View> {
+    #[child]
+    #[properties]
+    span: V::Element,
+    layers: Vec<Icon<V>>,
+    state: Proxy<IconStackState>,
+}
+
+impl<V: View> IconStack<V> {
+    /// Create an empty stack of the given size.
+    pub fn new(size: IconSize) -> Self {
+        let mut state = Proxy::new(IconStackState {
+            source: IconSource::current_default(),
+            size,
+        });
+
+        rsx! {
+            let span = span(
+                class = state(s => match s.source {
+                    IconSource::FontAwesome => format!("fa-stack {}", s.size.as_str()),
+                    IconSource::Bootstrap => {
+                        format!("fa-stack {}", s.size.bootstrap_class())
+                    }
+                }),
+            ) {}
+        }
+
+        Self {
+            span,
+            layers: vec![],
+            state,
+        }
+    }
+
+    pub fn set_size(&mut self, size: IconSize) {
+        self.state.modify(|s| s.size = size);
+    }
+
+    /// Draw the stack and all of its layers from a different icon font.
+    pub fn set_source(&mut self, source: IconSource) {
+        self.state.modify(|s| s.source = source);
+        for layer in self.layers.iter_mut() {
+            layer.set_source(source);
+        }
+    }
+
+    pub fn source(&self) -> IconSource {
+        self.state.source
+    }
+
+    /// Push a layer on top of the stack and return its index.
+    pub fn push(&mut self, mut icon: Icon<V>, scale: IconStackScale) -> usize {
+        icon.state.modify(|s| s.stack_scale = Some(scale));
+        self.span.append_child(&icon);
+        self.layers.push(icon);
+        self.layers.len() - 1
+    }
+
+    /// Remove and return the layer at the given index.
+    pub fn remove(&mut self, index: usize) -> Option<Icon<V>> {
+        if index >= self.layers.len() {
+            return None;
+        }
+        let mut icon = self.layers.remove(index);
+        self.span.remove_child(&icon);
+        icon.state.modify(|s| s.stack_scale = None);
+        Some(icon)
+    }
+
+    /// Change the scale of the layer at the given index.
+    pub fn set_scale(&mut self, index: usize, scale: IconStackScale) -> Option<()> {
+        self.layers
+            .get_mut(index)?
+            .state
+            .modify(|s| s.stack_scale = Some(scale));
+        Some(())
+    }
+
+    pub fn get(&self, index: usize) -> Option<&Icon<V>> {
+        self.layers.get(index)
+    }
+
+    pub fn get_mut(&mut self, index: usize) -> Option<&mut Icon<V>> {
+        self.layers.get_mut(index)
+    }
+
+    pub fn len(&self) -> usize {
+        self.layers.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.layers.is_empty()
     }
 }
@@ -6,8 +6,8 @@
 //!
 //! Supports two loading modes:
 //!
-//! - **Embedded** (with `embed-assets` feature): Icons compile into the WASM
-//!   binary and are served via Blob URLs. No network requests.
+//! - **Embedded** (with `embed-classic-icons` feature): Icons compile into
+//!   the WASM binary and are served via Blob URLs. No network requests.
 //! - **URL** (default): Icons load from `/icons-classic/{category}/{icon}.png`.
 //!   Requires Trunk to copy assets or manual CDN/static hosting setup.
 //!
@@ -840,16 +840,16 @@ pub struct IconClassic<V: View> {
 impl<V: View> IconClassic<V> {
     /// Create a new classic icon from a glyph.
     ///
-    /// With the `embed-assets` feature, icons are compiled into the WASM binary
-    /// and served via Blob URLs. Without it, icons load from
+    /// With the `embed-classic-icons` feature, icons are compiled into the
+    /// WASM binary and served via Blob URLs. Without it, icons load from
     /// `/icons-classic/{category}/{filename}.png`.
     pub fn new(glyph: IconClassicGlyph) -> Self {
-        let src = if cfg!(feature = "embed-assets") {
-            #[cfg(feature = "embed-assets")]
+        let src = if cfg!(feature = "embed-classic-icons") {
+            #[cfg(feature = "embed-classic-icons")]
             {
                 crate::assets::embedded::blob_url_for_classic_icon(&glyph)
             }
-            #[cfg(not(feature = "embed-assets"))]
+            #[cfg(not(feature = "embed-classic-icons"))]
             {
                 unreachable!()
             }
@@ -1,15 +1,22 @@
 //! Item lists.
 //!
 //! Includes list items and lists.
-use std::future::Future;
+use std::ops::{Bound, RangeBounds};
 
-use mogwai::prelude::*;
+use mogwai::{
+    prelude::*,
+    web::{WebElement, WebEvent},
+};
+use wasm_bindgen::JsCast;
 
-use super::Flavor;
+use super::{badge::Badge, Breakpoint, Flavor};
 
 struct ItemState {
     flavor: Option<Flavor>,
     is_active: bool,
+    is_highlighted: bool,
+    is_action: bool,
+    is_disabled: bool,
 }
 
 impl ItemState {
@@ -20,32 +27,118 @@ impl ItemState {
             "list-group-item".to_string()
         };
         let active = if self.is_active { " active" } else { "" };
-        format!("{list_group}{active}")
+        let highlighted = if self.is_highlighted {
+            " highlighted"
+        } else {
+            ""
+        };
+        let action = if self.is_action {
+            " list-group-item-action"
+        } else {
+            ""
+        };
+        let disabled = if self.is_disabled { " disabled" } else { "" };
+        format!("{list_group}{active}{highlighted}{action}{disabled}")
+    }
+}
+
+/// A key a focused [`List`] responds to.
+enum ListKey {
+    Up,
+    Down,
+    Home,
+    End,
+    Enter,
+}
+
+/// Resolves when one of the [`ListKey`]s is pressed.
+async fn list_key_pressed<V: View>(keydown: &V::EventListener) -> (ListKey, V::Event) {
+    loop {
+        let ev = keydown.next().await;
+        let key = ev
+            .dyn_ev(|e: &web_sys::KeyboardEvent| match e.key().as_str() {
+                "ArrowUp" => Some(ListKey::Up),
+                "ArrowDown" => Some(ListKey::Down),
+                "Home" => Some(ListKey::Home),
+                "End" => Some(ListKey::End),
+                "Enter" => Some(ListKey::Enter),
+                _ => None,
+            })
+            .flatten();
+        if let Some(key) = key {
+            return (key, ev);
+        }
     }
 }
 
+/// The element a [`ListItem`] renders as.
+#[derive(Clone, Copy, PartialEq)]
+enum ItemKind {
+    Li,
+    Link,
+    Button,
+}
+
 /// A single item within a [`List`].
+///
+/// Items are `<li>`s by default. Navigation lists can use action items
+/// instead, rendered as `<a>` ([`ListItem::new_link`]) or `<button>`
+/// ([`ListItem::new_button`]) with `list-group-item-action` hover styling;
+/// add them with [`List::push_item`].
 #[derive(ViewChild, ViewProperties)]
 pub struct ListItem<V: View, T> {
     #[child]
     #[properties]
     li: V::Element,
     item: T,
-    on_click: V::EventListener,
     state: Proxy<ItemState>,
+    kind: ItemKind,
+    is_hidden: bool,
 }
 
 impl<V: View, T: ViewChild<V>> ListItem<V, T> {
-    pub fn new(item: T) -> Self {
-        let mut state = Proxy::new(ItemState {
+    fn new_state(is_action: bool) -> Proxy<ItemState> {
+        Proxy::new(ItemState {
             flavor: None,
             is_active: false,
-        });
+            is_highlighted: false,
+            is_action,
+            is_disabled: false,
+        })
+    }
+
+    pub fn new(item: T) -> Self {
+        let mut state = Self::new_state(false);
 
         rsx! {
             let li = li(
                 class = state(s => s.class()),
-                on:click = on_click
+                role = "option",
+                aria_selected = state(s => if s.is_active { "true" } else { "false" }),
+            ) {
+                {&item}
+            }
+        }
+
+        ListItem {
+            li,
+            item,
+            state,
+            is_hidden: false,
+            kind: ItemKind::Li,
+        }
+    }
+
+    /// Create an action item rendered as a link to `href`.
+    pub fn new_link(item: T, href: impl AsRef<str>) -> Self {
+        let mut state = Self::new_state(true);
+
+        rsx! {
+            let li = a(
+                class = state(s => s.class()),
+                href = href.as_ref(),
+                role = "option",
+                aria_selected = state(s => if s.is_active { "true" } else { "false" }),
             ) {
                 {&item}
             }
@@ -54,11 +147,81 @@ impl<V: View, T: ViewChild<V>> ListItem<V, T> {
         ListItem {
             li,
             item,
-            on_click,
             state,
+            is_hidden: false,
+            kind: ItemKind::Link,
         }
     }
 
+    /// Create an action item rendered as a `<button>`.
+    pub fn new_button(item: T) -> Self {
+        let mut state = Self::new_state(true);
+
+        rsx! {
+            let li = button(
+                type = "button",
+                class = state(s => s.class()),
+                role = "option",
+                aria_selected = state(s => if s.is_active { "true" } else { "false" }),
+            ) {
+                {&item}
+            }
+        }
+
+        ListItem {
+            li,
+            item,
+            state,
+            is_hidden: false,
+            kind: ItemKind::Button,
+        }
+    }
+
+    /// Disable or enable the item.
+    ///
+    /// Clicks on a disabled item are not reported by [`List::step`].
+    pub fn set_disabled(&mut self, is_disabled: bool) {
+        self.state.modify(|s| s.is_disabled = is_disabled);
+        if is_disabled {
+            self.li.set_property("aria-disabled", "true");
+        } else {
+            self.li.remove_property("aria-disabled");
+        }
+        match self.kind {
+            ItemKind::Li => {}
+            ItemKind::Link => {
+                if is_disabled {
+                    self.li.set_property("tabindex", "-1");
+                } else {
+                    self.li.remove_property("tabindex");
+                }
+            }
+            ItemKind::Button => {
+                if is_disabled {
+                    self.li.set_property("disabled", "");
+                } else {
+                    self.li.remove_property("disabled");
+                }
+            }
+        }
+    }
+
+    pub fn is_disabled(&self) -> bool {
+        self.state.is_disabled
+    }
+
+    /// Returns `true` if the item is hidden by the list's filter.
+    ///
+    /// See [`List::set_filter`].
+    pub fn is_hidden(&self) -> bool {
+        self.is_hidden
+    }
+
+    fn set_is_hidden(&mut self, is_hidden: bool) {
+        self.is_hidden = is_hidden;
+        set_is_hidden::<V>(&self.li, is_hidden);
+    }
+
     pub fn set_flavor(&mut self, flavor: Option<super::Flavor>) {
         self.state.modify(|s| s.flavor = flavor);
     }
@@ -67,6 +230,10 @@ impl<V: View, T: ViewChild<V>> ListItem<V, T> {
         self.state.modify(|s| s.is_active = is_active);
     }
 
+    fn set_is_highlighted(&mut self, is_highlighted: bool) {
+        self.state.modify(|s| s.is_highlighted = is_highlighted);
+    }
+
     pub fn inner(&self) -> &T {
         &self.item
     }
@@ -76,31 +243,311 @@ impl<V: View, T: ViewChild<V>> ListItem<V, T> {
     }
 }
 
+/// Feed-style content for a list row: a heading with a small timestamp,
+/// secondary text below, and an optional trailing [`Badge`].
+///
+/// ```ignore
+/// let row = ListItemContent::new("New message")
+///     .timestamp("3 mins ago")
+///     .text("Are we still on for lunch?")
+///     .badge(Badge::with_count(2, Flavor::Primary));
+/// list.push(row);
+/// ```
+///
+/// The timestamp and secondary text are hidden while empty.
+#[derive(ViewChild)]
+pub struct ListItemContent<V: View> {
+    #[child]
+    wrapper: V::Element,
+    title: V::Text,
+    timestamp_el: V::Element,
+    timestamp: V::Text,
+    text_el: V::Element,
+    text: V::Text,
+    badge: Option<Badge<V>>,
+}
+
+impl<V: View> ListItemContent<V> {
+    pub fn new(initial_title: impl AsRef<str>) -> Self {
+        rsx! {
+            let wrapper = div(class = "list-item-content") {
+                div(class = "list-item-content-main") {
+                    div(class = "list-item-content-heading") {
+                        div(class = "fw-bold") {
+                            let title = ""
+                        }
+                        let timestamp_el = small(class = "text-body-secondary") {
+                            let timestamp = ""
+                        }
+                    }
+                    let text_el = div(class = "list-item-content-text") {
+                        let text = ""
+                    }
+                }
+            }
+        }
+        title.set_text(initial_title);
+
+        let mut content = Self {
+            wrapper,
+            title,
+            timestamp_el,
+            timestamp,
+            text_el,
+            text,
+            badge: None,
+        };
+        content.set_timestamp("");
+        content.set_text("");
+        content
+    }
+
+    /// Set the timestamp, consuming and returning the content.
+    pub fn timestamp(mut self, timestamp: impl AsRef<str>) -> Self {
+        self.set_timestamp(timestamp);
+        self
+    }
+
+    /// Set the secondary text, consuming and returning the content.
+    pub fn text(mut self, text: impl AsRef<str>) -> Self {
+        self.set_text(text);
+        self
+    }
+
+    /// Set the trailing badge, consuming and returning the content.
+    pub fn badge(mut self, badge: Badge<V>) -> Self {
+        self.set_badge(Some(badge));
+        self
+    }
+
+    pub fn set_title(&self, title: impl AsRef<str>) {
+        self.title.set_text(title);
+    }
+
+    pub fn set_timestamp(&mut self, timestamp: impl AsRef<str>) {
+        let timestamp = timestamp.as_ref();
+        set_is_hidden(&self.timestamp_el, timestamp.is_empty());
+        self.timestamp.set_text(timestamp);
+    }
+
+    pub fn set_text(&mut self, text: impl AsRef<str>) {
+        let text = text.as_ref();
+        set_is_hidden(&self.text_el, text.is_empty());
+        self.text.set_text(text);
+    }
+
+    /// Replace the trailing badge, returning the previous one.
+    pub fn set_badge(&mut self, badge: Option<Badge<V>>) -> Option<Badge<V>> {
+        let previous = self.badge.take();
+        if let Some(previous) = previous.as_ref() {
+            self.wrapper.remove_child(previous);
+        }
+        if let Some(badge) = badge {
+            self.wrapper.append_child(&badge);
+            self.badge = Some(badge);
+        }
+        previous
+    }
+
+    pub fn get_badge(&self) -> Option<&Badge<V>> {
+        self.badge.as_ref()
+    }
+
+    pub fn get_badge_mut(&mut self) -> Option<&mut Badge<V>> {
+        self.badge.as_mut()
+    }
+}
+
+fn set_is_hidden<V: View>(el: &V::Element, is_hidden: bool) {
+    if is_hidden {
+        el.set_style("display", "none");
+    } else {
+        el.remove_style("display");
+    }
+}
+
+/// How a [`List`] tracks its active items.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum SelectionMode {
+    /// The list doesn't track a selection; callers manage `active` classes
+    /// themselves with [`ListItem::set_is_active`].
+    #[default]
+    None,
+    /// At most one item is selected. Clicking an item selects it.
+    Single,
+    /// Any number of items can be selected. A plain click selects only the
+    /// clicked item, ctrl/cmd-click toggles it, and shift-click selects the
+    /// range from the last clicked item.
+    Multi,
+}
+
+/// The direction a [`List`] lays out its items.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum ListOrientation {
+    /// Items are stacked (the default).
+    #[default]
+    Vertical,
+    /// Items sit side by side (`list-group-horizontal`).
+    Horizontal,
+    /// Items are stacked on narrow viewports and sit side by side at or
+    /// above the breakpoint (`list-group-horizontal-{breakpoint}`).
+    ///
+    /// [`Breakpoint::Xs`] is the same as [`ListOrientation::Horizontal`].
+    HorizontalFrom(Breakpoint),
+}
+
+struct ListState {
+    is_numbered: bool,
+    orientation: ListOrientation,
+}
+
+impl ListState {
+    fn class(&self) -> String {
+        let mut class = "list-group".to_string();
+        if self.is_numbered {
+            class.push_str(" list-group-numbered");
+        }
+        match self.orientation {
+            ListOrientation::Vertical => {}
+            ListOrientation::Horizontal | ListOrientation::HorizontalFrom(Breakpoint::Xs) => {
+                class.push_str(" list-group-horizontal")
+            }
+            ListOrientation::HorizontalFrom(bp) => {
+                class.push_str(&format!(" list-group-horizontal-{bp}"))
+            }
+        }
+        class
+    }
+}
+
 /// Event emitted when a list item is clicked.
 #[derive(Debug)]
-pub struct ListEvent<V: View> {
-    pub index: usize,
-    pub event: V::Event,
+pub enum ListEvent<V: View> {
+    /// An item was clicked without changing the selection.
+    Clicked { index: usize, event: V::Event },
+    /// An item was clicked and the selection changed.
+    ///
+    /// `selected` holds the indices of all selected items, in ascending order.
+    SelectionChanged {
+        index: usize,
+        selected: Vec<usize>,
+        event: V::Event,
+    },
+}
+
+impl<V: View> ListEvent<V> {
+    /// The index of the clicked item.
+    pub fn index(&self) -> usize {
+        match self {
+            ListEvent::Clicked { index, .. } => *index,
+            ListEvent::SelectionChanged { index, .. } => *index,
+        }
+    }
 }
 
 /// A Bootstrap list-group with clickable items.
+///
+/// With [`List::set_selection_mode`] the list tracks its selected items
+/// itself, toggling their `active` classes and reporting
+/// [`ListEvent::SelectionChanged`] from [`List::step`].
+///
+/// The list is focusable (an ARIA `listbox`). While focused, the arrow keys,
+/// Home and End move a highlight through the items and Enter activates the
+/// highlighted item exactly as a click would.
+///
+/// [`List::numbered`] renders an `<ol>` whose items are numbered, and
+/// [`List::set_orientation`] lays the items out side by side.
 #[derive(ViewChild, ViewProperties)]
 pub struct List<V: View, T> {
     #[child]
     #[properties]
+    /// The `<ul>`, or `<ol>` for numbered lists.
     ul: V::Element,
+    /// Delegated click listener for every item.
+    click: V::EventListener,
+    keydown: V::EventListener,
+    state: Proxy<ListState>,
     items: Vec<ListItem<V, T>>,
+    /// Index of the item highlighted with the keyboard.
+    highlighted: Option<usize>,
+    selection_mode: SelectionMode,
+    /// Selected indices, in ascending order.
+    selected: Vec<usize>,
+    /// The item shift-clicks extend the selection from.
+    anchor: Option<usize>,
+    /// Items that don't match are hidden.
+    filter: Option<Box<dyn Fn(&T) -> bool>>,
 }
 
 impl<V: View, T> Default for List<V, T> {
     fn default() -> Self {
-        rsx! {
-            let ul = ul(class = "list-group") {
-                let items = {vec![]}
+        Self::build(false)
+    }
+}
+
+impl<V: View, T> List<V, T> {
+    fn build(is_numbered: bool) -> Self {
+        let mut state = Proxy::new(ListState {
+            is_numbered,
+            orientation: ListOrientation::Vertical,
+        });
+
+        let (ul, click, keydown) = if is_numbered {
+            rsx! {
+                let ul = ol(
+                    class = state(s => s.class()),
+                    role = "listbox",
+                    tabindex = "0",
+                    on:click = click,
+                    on:keydown = keydown,
+                ) {}
             }
+            (ul, click, keydown)
+        } else {
+            rsx! {
+                let ul = ul(
+                    class = state(s => s.class()),
+                    role = "listbox",
+                    tabindex = "0",
+                    on:click = click,
+                    on:keydown = keydown,
+                ) {}
+            }
+            (ul, click, keydown)
+        };
+
+        List {
+            ul,
+            click,
+            keydown,
+            state,
+            items: vec![],
+            highlighted: None,
+            selection_mode: SelectionMode::None,
+            selected: vec![],
+            anchor: None,
+            filter: None,
         }
+    }
+
+    /// Create an empty numbered list (`list-group-numbered`), rendered as an
+    /// `<ol>`.
+    pub fn numbered() -> Self {
+        Self::build(true)
+    }
+
+    /// Returns `true` if the list was created with [`List::numbered`].
+    pub fn is_numbered(&self) -> bool {
+        self.state.is_numbered
+    }
+
+    /// Lay the items out stacked or side by side.
+    pub fn set_orientation(&mut self, orientation: ListOrientation) {
+        self.state.modify(|s| s.orientation = orientation);
+    }
 
-        List { ul, items }
+    pub fn orientation(&self) -> ListOrientation {
+        self.state.orientation
     }
 }
 
@@ -136,10 +583,29 @@ impl<V: View, T: ViewChild<V>> List<V, T> {
     /// ## Note
     /// If `index` > len, the item will simply be appended to the end of the list.
     pub fn insert(&mut self, index: usize, item: T) {
-        let item = ListItem::new(item);
+        self.insert_item(index, ListItem::new(item));
+    }
+
+    /// Inserts an already constructed item (e.g. an action item) at the
+    /// given index.
+    ///
+    /// ## Note
+    /// If `index` > len, the item will simply be appended to the end of the list.
+    pub fn insert_item(&mut self, index: usize, mut item: ListItem<V, T>) {
+        self.apply_filter(&mut item);
         if let Some(previous_item) = self.items.get(index) {
             self.ul.insert_child_before(previous_item, Some(&item));
             self.items.insert(index, item);
+            for i in self
+                .selected
+                .iter_mut()
+                .chain(self.anchor.as_mut())
+                .chain(self.highlighted.as_mut())
+            {
+                if *i >= index {
+                    *i += 1;
+                }
+            }
         } else {
             self.ul.append_child(&item);
             self.items.push(item);
@@ -148,33 +614,351 @@ impl<V: View, T: ViewChild<V>> List<V, T> {
 
     /// Removes the item at the given index.
     ///
+    /// If the item was selected it is dropped from the selection.
+    ///
     /// ## Panics
     /// Panics if `index` > len.
     pub fn remove(&mut self, index: usize) -> T {
         let t = self.items.remove(index);
         self.ul.remove_child(&t);
+        self.remap_indices(|i| match i {
+            i if i == index => None,
+            i if i > index => Some(i - 1),
+            i => Some(i),
+        });
         t.item
     }
 
+    /// Removes the last item and returns it, or `None` if the list is empty.
+    pub fn pop(&mut self) -> Option<T> {
+        let index = self.items.len().checked_sub(1)?;
+        Some(self.remove(index))
+    }
+
+    /// Removes every item.
+    pub fn clear(&mut self) {
+        for item in self.items.drain(..) {
+            self.ul.remove_child(&item);
+        }
+        self.selected.clear();
+        self.anchor = None;
+        self.highlighted = None;
+    }
+
+    /// Keeps only the items for which `predicate` returns `true`.
+    ///
+    /// Selection and highlight follow the items that are kept.
+    pub fn retain(&mut self, mut predicate: impl FnMut(&T) -> bool) {
+        let mut new_indices = Vec::with_capacity(self.items.len());
+        let mut kept = 0;
+        for item in std::mem::take(&mut self.items) {
+            if predicate(&item.item) {
+                new_indices.push(Some(kept));
+                kept += 1;
+                self.items.push(item);
+            } else {
+                new_indices.push(None);
+                self.ul.remove_child(&item);
+            }
+        }
+        self.remap_indices(|i| new_indices[i]);
+    }
+
+    /// Replaces the items in `range` with `items`, returning the removed
+    /// items.
+    ///
+    /// Selection and highlight follow the items outside of `range`.
+    ///
+    /// ## Panics
+    /// Panics if the range is out of bounds.
+    pub fn splice(
+        &mut self,
+        range: impl RangeBounds<usize>,
+        items: impl IntoIterator<Item = T>,
+    ) -> Vec<T> {
+        let start = match range.start_bound() {
+            Bound::Included(i) => *i,
+            Bound::Excluded(i) => i + 1,
+            Bound::Unbounded => 0,
+        };
+        let end = match range.end_bound() {
+            Bound::Included(i) => i + 1,
+            Bound::Excluded(i) => *i,
+            Bound::Unbounded => self.items.len(),
+        };
+        let removed = self
+            .items
+            .drain(start..end)
+            .map(|item| {
+                self.ul.remove_child(&item);
+                item.item
+            })
+            .collect::<Vec<_>>();
+        let count = removed.len();
+        self.remap_indices(|i| match i {
+            i if i < start => Some(i),
+            i if i < end => None,
+            i => Some(i - count),
+        });
+        for (offset, item) in items.into_iter().enumerate() {
+            self.insert(start + offset, item);
+        }
+        removed
+    }
+
+    /// Updates the selection, anchor and highlight after items move.
+    ///
+    /// `f` maps an old index to its new index, or `None` if the item is gone.
+    fn remap_indices(&mut self, f: impl Fn(usize) -> Option<usize>) {
+        self.selected = self.selected.iter().filter_map(|i| f(*i)).collect();
+        self.anchor = self.anchor.and_then(&f);
+        self.highlighted = self.highlighted.and_then(&f);
+    }
+
     pub fn push(&mut self, item: T) {
-        let item = ListItem::new(item);
+        self.push_item(ListItem::new(item));
+    }
+
+    /// Appends an already constructed item (e.g. an action item).
+    pub fn push_item(&mut self, mut item: ListItem<V, T>) {
+        self.apply_filter(&mut item);
         self.ul.append_child(&item);
         self.items.push(item);
     }
 
-    fn item_click_events(&self) -> impl Future<Output = ListEvent<V>> + '_ {
-        use mogwai::future::*;
+    /// Hide every item that doesn't match `filter`.
+    ///
+    /// Hidden items keep their indices and selection state, and items added
+    /// later are filtered too. The keyboard highlight skips hidden items.
+    pub fn set_filter(&mut self, filter: impl Fn(&T) -> bool + 'static) {
+        self.filter = Some(Box::new(filter));
+        let mut items = std::mem::take(&mut self.items);
+        for item in items.iter_mut() {
+            self.apply_filter(item);
+        }
+        self.items = items;
+        if self
+            .highlighted
+            .is_some_and(|i| self.items.get(i).is_some_and(ListItem::is_hidden))
+        {
+            self.set_highlighted(None);
+        }
+    }
 
-        let events = self.items.iter().enumerate().map(|(index, item)| {
-            item.on_click
-                .next()
-                .map(move |event| ListEvent { index, event })
-        });
-        race_all(events)
+    /// Remove the filter, showing every item.
+    pub fn clear_filter(&mut self) {
+        self.filter = None;
+        for item in self.items.iter_mut() {
+            item.set_is_hidden(false);
+        }
+    }
+
+    fn apply_filter(&self, item: &mut ListItem<V, T>) {
+        let is_hidden = self.filter.as_ref().is_some_and(|f| !f(&item.item));
+        item.set_is_hidden(is_hidden);
+    }
+
+    /// Set how the list tracks its active items.
+    ///
+    /// Switching modes clears the selection.
+    pub fn set_selection_mode(&mut self, mode: SelectionMode) {
+        self.selection_mode = mode;
+        self.set_selected([]);
+    }
+
+    pub fn selection_mode(&self) -> SelectionMode {
+        self.selection_mode
+    }
+
+    /// The indices of the selected items, in ascending order.
+    pub fn selected(&self) -> &[usize] {
+        &self.selected
+    }
+
+    /// Replace the selection, updating the items' `active` classes.
+    ///
+    /// Out-of-bounds indices are ignored. In [`SelectionMode::Single`] only
+    /// the first index is kept.
+    pub fn set_selected(&mut self, indices: impl IntoIterator<Item = usize>) {
+        let mut selected = indices
+            .into_iter()
+            .filter(|i| *i < self.items.len())
+            .collect::<Vec<_>>();
+        selected.sort_unstable();
+        selected.dedup();
+        if self.selection_mode == SelectionMode::Single {
+            selected.truncate(1);
+        }
+        for i in self.selected.iter() {
+            if let Some(item) = self.items.get_mut(*i) {
+                item.set_is_active(false);
+            }
+        }
+        for i in selected.iter() {
+            if let Some(item) = self.items.get_mut(*i) {
+                item.set_is_active(true);
+            }
+        }
+        self.anchor = selected.first().copied();
+        self.selected = selected;
+    }
+
+    /// Deselect all items.
+    pub fn clear_selection(&mut self) {
+        self.set_selected([]);
+    }
+
+    /// Await the next click that lands inside an item.
+    ///
+    /// A single listener on the list element receives every click; the
+    /// event target is mapped back to the item's index.
+    async fn item_click_events(&self) -> (usize, V::Event) {
+        loop {
+            let event = self.click.next().await;
+            if let Some(index) = self.clicked_index(&event) {
+                return (index, event);
+            }
+        }
+    }
+
+    /// Returns the index of the item containing the event's target, if any.
+    fn clicked_index(&self, event: &V::Event) -> Option<usize> {
+        let list = self.ul.dyn_el(|el: &web_sys::Node| el.clone())?;
+        let mut node = event
+            .dyn_ev(|e: &web_sys::Event| e.target())
+            .flatten()?
+            .dyn_into::<web_sys::Node>()
+            .ok()?;
+        // Walk up to the list's direct child: the item's root element.
+        loop {
+            let parent = node.parent_node()?;
+            if parent.is_same_node(Some(&list)) {
+                break;
+            }
+            node = parent;
+        }
+        // Items are the list's only children, in order.
+        let mut el = node.dyn_into::<web_sys::Element>().ok()?;
+        let mut index = 0;
+        while let Some(previous) = el.previous_element_sibling() {
+            index += 1;
+            el = previous;
+        }
+        (index < self.items.len()).then_some(index)
+    }
+
+    /// Apply a click on the item at `index` to the selection.
+    fn click_item(&mut self, index: usize, event: V::Event) -> ListEvent<V> {
+        let (toggle, extend) = match self.selection_mode {
+            SelectionMode::None => return ListEvent::Clicked { index, event },
+            SelectionMode::Single => (false, false),
+            SelectionMode::Multi => event
+                .dyn_ev(|e: &web_sys::MouseEvent| (e.ctrl_key() || e.meta_key(), e.shift_key()))
+                .unwrap_or_default(),
+        };
+
+        // Shift-clicks keep extending from the same item.
+        let anchor = if extend {
+            self.anchor.unwrap_or(index)
+        } else {
+            index
+        };
+        let selected = if extend {
+            (anchor.min(index)..=anchor.max(index)).collect()
+        } else if toggle {
+            let mut selected = self.selected.clone();
+            if let Some(pos) = selected.iter().position(|i| *i == index) {
+                selected.remove(pos);
+            } else {
+                selected.push(index);
+            }
+            selected
+        } else {
+            vec![index]
+        };
+
+        let previous = self.selected.clone();
+        self.set_selected(selected);
+        self.anchor = Some(anchor);
+
+        if self.selected == previous {
+            ListEvent::Clicked { index, event }
+        } else {
+            ListEvent::SelectionChanged {
+                index,
+                selected: self.selected.clone(),
+                event,
+            }
+        }
+    }
+
+    /// Returns the index of the item highlighted with the keyboard, if any.
+    pub fn highlighted(&self) -> Option<usize> {
+        self.highlighted
+    }
+
+    fn set_highlighted(&mut self, highlighted: Option<usize>) {
+        if let Some(item) = self.highlighted.and_then(|i| self.items.get_mut(i)) {
+            item.set_is_highlighted(false);
+        }
+        if let Some(item) = highlighted.and_then(|i| self.items.get_mut(i)) {
+            item.set_is_highlighted(true);
+        }
+        self.highlighted = highlighted;
     }
 
-    pub async fn step(&self) -> ListEvent<V> {
-        self.item_click_events().await
+    /// Await the next item click, or Enter on the highlighted item.
+    ///
+    /// When a [`SelectionMode`] is set the selection is updated before
+    /// returning. Arrow, Home and End keys move the highlight without
+    /// returning.
+    pub async fn step(&mut self) -> ListEvent<V> {
+        use futures_lite::FutureExt;
+        use mogwai::future::MogwaiFutureExt;
+
+        enum Action<V: View> {
+            Click(usize, V::Event),
+            Key(ListKey, V::Event),
+        }
+
+        loop {
+            let action = self
+                .item_click_events()
+                .map(|(index, event)| Action::Click(index, event))
+                .or(list_key_pressed::<V>(&self.keydown).map(|(key, ev)| Action::Key(key, ev)))
+                .await;
+
+            let len = self.items.len();
+            let is_visible = |i: &usize| !self.items[*i].is_hidden();
+            let next = match action {
+                Action::Click(index, _) if self.items[index].is_disabled() => continue,
+                Action::Click(index, event) => {
+                    self.set_highlighted(Some(index));
+                    return self.click_item(index, event);
+                }
+                Action::Key(ListKey::Enter, event) => match self.highlighted {
+                    Some(index) if !self.items[index].is_disabled() => {
+                        return self.click_item(index, event)
+                    }
+                    _ => continue,
+                },
+                Action::Key(key, event) => {
+                    // Keep the page from scrolling.
+                    event.dyn_ev(|e: &web_sys::Event| e.prevent_default());
+                    // Hidden (filtered out) items are skipped.
+                    match (key, self.highlighted) {
+                        (ListKey::Home, _) | (ListKey::Down, None) => (0..len).find(is_visible),
+                        (ListKey::End, _) | (ListKey::Up, None) => (0..len).rev().find(is_visible),
+                        (ListKey::Down, Some(i)) => (i + 1..len).find(is_visible).or(Some(i)),
+                        (ListKey::Up, Some(i)) => (0..i).rev().find(is_visible).or(Some(i)),
+                        (ListKey::Enter, _) => unreachable!(),
+                    }
+                }
+            };
+            if let Some(next) = next {
+                self.set_highlighted(Some(next));
+            }
+        }
     }
 
     pub fn iter(&self) -> impl Iterator<Item = &ListItem<V, T>> {
@@ -193,21 +977,23 @@ pub mod library {
     use mogwai::prelude::*;
 
     use super::*;
+    use crate::library::log_event;
 
     #[derive(ViewChild)]
     pub struct ListLibraryItem<V: View> {
         #[child]
         pub wrapper: V::Element,
         list: List<V, V::Element>,
+        feed: List<V, ListItemContent<V>>,
         add_click: V::EventListener,
         remove_click: V::EventListener,
-        selected: Option<usize>,
         count: usize,
     }
 
     impl<V: View> Default for ListLibraryItem<V> {
         fn default() -> Self {
             let mut list = List::default();
+            list.set_selection_mode(SelectionMode::Multi);
             for label in ["Apple", "Banana", "Cherry"] {
                 let text = V::Text::new(label);
                 rsx! {
@@ -215,12 +1001,36 @@ pub mod library {
                 }
                 list.push(el);
             }
+            rsx! {
+                let el = span() { "Durian (disabled)" }
+            }
+            let mut durian = ListItem::new_button(el);
+            durian.set_disabled(true);
+            list.push_item(durian);
+
+            let mut feed = List::default();
+            feed.set_selection_mode(SelectionMode::Single);
+            feed.push(
+                ListItemContent::new("Weekly sync")
+                    .timestamp("3 days ago")
+                    .text("Agenda and notes from Monday.")
+                    .badge(Badge::with_count(14, Flavor::Primary)),
+            );
+            feed.push(
+                ListItemContent::new("Build finished")
+                    .timestamp("1 hr ago")
+                    .badge(Badge::new("new", Flavor::Success)),
+            );
+            feed.push(ListItemContent::new("Quiet item").text("No timestamp or badge."));
 
             rsx! {
                 let wrapper = div() {
                     div(class = "mb-3") {
                         {&list}
                     }
+                    div(class = "mb-3") {
+                        {&feed}
+                    }
                     div(class = "btn-group") {
                         button(
                             type = "button",
@@ -243,9 +1053,9 @@ pub mod library {
             Self {
                 wrapper,
                 list,
+                feed,
                 add_click,
                 remove_click,
-                selected: None,
                 count: 3,
             }
         }
@@ -263,23 +1073,17 @@ pub mod library {
                 .list
                 .step()
                 .map(ListAction::ItemClicked)
+                .or(self.feed.step().map(ListAction::ItemClicked))
                 .or(self.add_click.next().map(|_| ListAction::Add))
                 .or(self.remove_click.next().map(|_| ListAction::Remove))
                 .await;
 
             match action {
-                ListAction::ItemClicked(ListEvent { index, .. }) => {
-                    // Deselect previous
-                    if let Some(prev) = self.selected {
-                        if let Some(item) = self.list.get_mut(prev) {
-                            item.set_is_active(false);
-                        }
-                    }
-                    // Select new
-                    if let Some(item) = self.list.get_mut(index) {
-                        item.set_is_active(true);
-                    }
-                    self.selected = Some(index);
+                ListAction::ItemClicked(ListEvent::Clicked { index, .. }) => {
+                    log_event("List", format!("Clicked item {index}"));
+                }
+                ListAction::ItemClicked(ListEvent::SelectionChanged { selected, .. }) => {
+                    log_event("List", format!("Selected {selected:?}"));
                 }
                 ListAction::Add => {
                     self.count += 1;
@@ -290,10 +1094,9 @@ pub mod library {
                     self.list.push(el);
                 }
                 ListAction::Remove => {
-                    if let Some(index) = self.selected.take() {
-                        if index < self.list.len() {
-                            self.list.remove(index);
-                        }
+                    // Remove from the end so earlier indices stay valid.
+                    for index in self.list.selected().to_vec().into_iter().rev() {
+                        self.list.remove(index);
                     }
                 }
             }
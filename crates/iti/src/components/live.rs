@@ -0,0 +1,460 @@
+//! Live data pushed from a server.
+//!
+//! [`LiveWidget`] keeps a WebSocket connection open, reconnecting with
+//! backoff when it drops, and shows the state of the connection in a
+//! [`Badge`]. Messages are JSON, typed on the way in and out.
+//!
+//! [`EventSourceStream`] does the same for one-way Server-Sent Events.
+
+use std::{cell::RefCell, collections::VecDeque, marker::PhantomData, rc::Rc};
+
+use mogwai::prelude::*;
+use serde::{de::DeserializeOwned, Serialize};
+use snafu::prelude::*;
+use wasm_bindgen::{prelude::Closure, JsCast, JsValue};
+
+use crate::{
+    components::{badge::Badge, Flavor},
+    util::queue::Queue,
+};
+
+/// Delay before the first reconnection attempt. Each failed attempt
+/// doubles it, up to [`MAX_BACKOFF_MILLIS`].
+const BACKOFF_MILLIS: u64 = 500;
+
+const MAX_BACKOFF_MILLIS: u64 = 30_000;
+
+/// Live connection errors.
+#[derive(Debug, Snafu)]
+pub enum Error {
+    #[snafu(display("Error serializing a live message: {source}"))]
+    Serialize { source: serde_json::Error },
+    #[snafu(display("Could not send a live message: {message}"))]
+    Send { message: String },
+}
+
+/// Returns how long to wait before reconnecting after `attempts` failed
+/// attempts.
+fn backoff_millis(attempts: u32) -> u64 {
+    let exponent = attempts.saturating_sub(1).min(16);
+    (BACKOFF_MILLIS << exponent).min(MAX_BACKOFF_MILLIS)
+}
+
+/// Returns the JSON in `text` as a `T`, logging it if it isn't one.
+fn parse<T: DeserializeOwned>(url: &str, text: &str) -> Option<T> {
+    serde_json::from_str(text)
+        .inspect_err(|e| log::warn!("could not parse a message from '{url}': {e}"))
+        .ok()
+}
+
+/// The state of a [`LiveWidget`] or [`EventSourceStream`] connection.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum ConnectionState {
+    /// Opening the first connection.
+    #[default]
+    Connecting,
+    Open,
+    /// The connection dropped and is being retried.
+    Reconnecting,
+}
+
+impl ConnectionState {
+    fn flavor(&self) -> Flavor {
+        match self {
+            ConnectionState::Connecting => Flavor::Secondary,
+            ConnectionState::Open => Flavor::Success,
+            ConnectionState::Reconnecting => Flavor::Warning,
+        }
+    }
+
+    fn label(&self) -> &'static str {
+        match self {
+            ConnectionState::Connecting => "Connecting",
+            ConnectionState::Open => "Live",
+            ConnectionState::Reconnecting => "Reconnecting",
+        }
+    }
+}
+
+enum SocketEvent {
+    Open,
+    Message(String),
+    Closed,
+}
+
+/// An open WebSocket and the callbacks feeding its events to `queue`.
+struct Connection {
+    socket: web_sys::WebSocket,
+    queue: Rc<RefCell<Queue<SocketEvent>>>,
+    _callbacks: [Closure<dyn FnMut(JsValue)>; 3],
+}
+
+impl Connection {
+    fn open(url: &str) -> Result<Self, JsValue> {
+        let socket = web_sys::WebSocket::new(url)?;
+        let queue = Queue::new();
+        let callback = |to_event: fn(JsValue) -> Option<SocketEvent>| {
+            let queue = queue.clone();
+            Closure::<dyn FnMut(JsValue)>::new(move |ev: JsValue| {
+                if let Some(event) = to_event(ev) {
+                    queue.borrow_mut().push(event);
+                }
+            })
+        };
+        let on_open = callback(|_| Some(SocketEvent::Open));
+        let on_message = callback(|ev| {
+            let data = ev.dyn_into::<web_sys::MessageEvent>().ok()?.data();
+            data.as_string().map(SocketEvent::Message)
+        });
+        // An error always comes with a close, so only the close is handled.
+        let on_close = callback(|_| Some(SocketEvent::Closed));
+        socket.set_onopen(Some(on_open.as_ref().unchecked_ref()));
+        socket.set_onmessage(Some(on_message.as_ref().unchecked_ref()));
+        socket.set_onclose(Some(on_close.as_ref().unchecked_ref()));
+        Ok(Self {
+            socket,
+            queue,
+            _callbacks: [on_open, on_message, on_close],
+        })
+    }
+}
+
+impl Drop for Connection {
+    fn drop(&mut self) {
+        self.socket.set_onopen(None);
+        self.socket.set_onmessage(None);
+        self.socket.set_onclose(None);
+        let _ = self.socket.close();
+    }
+}
+
+/// A WebSocket connection with a status badge.
+///
+/// The connection is opened by the first [`LiveWidget::step`] or
+/// [`LiveWidget::send`], and reopened whenever it drops, waiting longer
+/// after each failed attempt. Messages that can't be parsed as a `T` are
+/// logged and skipped.
+///
+/// ## Note
+/// Text frames only; binary messages are ignored.
+#[derive(ViewChild, ViewProperties)]
+pub struct LiveWidget<V: View, T> {
+    #[child]
+    #[properties]
+    wrapper: V::Element,
+    badge: Badge<V>,
+    url: String,
+    connection: Option<Connection>,
+    state: ConnectionState,
+    /// Failed connection attempts since the last successful one.
+    attempts: u32,
+    /// Messages received while [`LiveWidget::send`] waited to connect.
+    pending: VecDeque<T>,
+}
+
+impl<V: View, T: DeserializeOwned> LiveWidget<V, T> {
+    /// Create a widget for the WebSocket at `url`.
+    pub fn new(url: impl Into<String>) -> Self {
+        let state = ConnectionState::default();
+        let badge = Badge::new(state.label(), state.flavor());
+        rsx! {
+            let wrapper = span(class = "live-widget") {
+                {&badge}
+            }
+        }
+        Self {
+            wrapper,
+            badge,
+            url: url.into(),
+            connection: None,
+            state,
+            attempts: 0,
+            pending: VecDeque::new(),
+        }
+    }
+
+    pub fn get_url(&self) -> &str {
+        &self.url
+    }
+
+    pub fn state(&self) -> ConnectionState {
+        self.state
+    }
+
+    pub fn get_badge(&self) -> &Badge<V> {
+        &self.badge
+    }
+
+    pub fn get_badge_mut(&mut self) -> &mut Badge<V> {
+        &mut self.badge
+    }
+
+    fn set_state(&mut self, state: ConnectionState) {
+        self.state = state;
+        self.badge.set_text(state.label());
+        self.badge.set_flavor(state.flavor());
+    }
+
+    /// Drive the connection until something happens on it, returning the
+    /// text of a received message.
+    async fn advance(&mut self) -> Option<String> {
+        let Some(connection) = self.connection.as_ref() else {
+            if self.attempts > 0 {
+                mogwai::time::wait_millis(backoff_millis(self.attempts)).await;
+            }
+            match Connection::open(&self.url) {
+                Ok(connection) => self.connection = Some(connection),
+                Err(e) => {
+                    log::warn!("could not open a WebSocket to '{}': {e:?}", self.url);
+                    self.attempts += 1;
+                }
+            }
+            return None;
+        };
+        let event = Queue::next(&connection.queue).await;
+        match event {
+            SocketEvent::Open => {
+                self.attempts = 0;
+                self.set_state(ConnectionState::Open);
+                None
+            }
+            SocketEvent::Message(text) => Some(text),
+            SocketEvent::Closed => {
+                self.connection = None;
+                self.attempts += 1;
+                self.set_state(ConnectionState::Reconnecting);
+                None
+            }
+        }
+    }
+
+    /// Send `message` as JSON, waiting for the connection to open if it
+    /// isn't.
+    pub async fn send(&mut self, message: &impl Serialize) -> Result<(), Error> {
+        let text = serde_json::to_string(message).context(SerializeSnafu)?;
+        loop {
+            if let (ConnectionState::Open, Some(connection)) = (self.state, &self.connection) {
+                return connection
+                    .socket
+                    .send_with_str(&text)
+                    .map_err(|e| Error::Send {
+                        message: format!("{e:?}"),
+                    });
+            }
+            if let Some(text) = self.advance().await {
+                if let Some(message) = parse(&self.url, &text) {
+                    self.pending.push_back(message);
+                }
+            }
+        }
+    }
+
+    /// Await the next message, connecting or reconnecting as needed.
+    pub async fn step(&mut self) -> T {
+        if let Some(message) = self.pending.pop_front() {
+            return message;
+        }
+        loop {
+            if let Some(text) = self.advance().await {
+                if let Some(message) = parse(&self.url, &text) {
+                    return message;
+                }
+            }
+        }
+    }
+}
+
+enum SourceEvent {
+    Open,
+    Message {
+        id: String,
+        data: String,
+    },
+    /// The browser is retrying the connection itself.
+    Retrying,
+    /// The browser gave up on the connection.
+    Closed,
+}
+
+/// An open `EventSource` and the callbacks feeding its events to `queue`.
+struct SourceConnection {
+    source: web_sys::EventSource,
+    queue: Rc<RefCell<Queue<SourceEvent>>>,
+    _callbacks: [Closure<dyn FnMut(JsValue)>; 3],
+}
+
+impl SourceConnection {
+    fn open(url: &str) -> Result<Self, JsValue> {
+        let source = web_sys::EventSource::new(url)?;
+        let queue = Queue::new();
+        let on_open = Closure::<dyn FnMut(JsValue)>::new({
+            let queue = queue.clone();
+            move |_| queue.borrow_mut().push(SourceEvent::Open)
+        });
+        let on_message = Closure::<dyn FnMut(JsValue)>::new({
+            let queue = queue.clone();
+            move |ev: JsValue| {
+                let Ok(ev) = ev.dyn_into::<web_sys::MessageEvent>() else {
+                    return;
+                };
+                if let Some(data) = ev.data().as_string() {
+                    let id = ev.last_event_id();
+                    queue.borrow_mut().push(SourceEvent::Message { id, data });
+                }
+            }
+        });
+        let on_error = Closure::<dyn FnMut(JsValue)>::new({
+            let queue = queue.clone();
+            let source = source.clone();
+            move |_| {
+                let event = if source.ready_state() == web_sys::EventSource::CLOSED {
+                    SourceEvent::Closed
+                } else {
+                    SourceEvent::Retrying
+                };
+                queue.borrow_mut().push(event);
+            }
+        });
+        source.set_onopen(Some(on_open.as_ref().unchecked_ref()));
+        source.set_onmessage(Some(on_message.as_ref().unchecked_ref()));
+        source.set_onerror(Some(on_error.as_ref().unchecked_ref()));
+        Ok(Self {
+            source,
+            queue,
+            _callbacks: [on_open, on_message, on_error],
+        })
+    }
+}
+
+impl Drop for SourceConnection {
+    fn drop(&mut self) {
+        self.source.set_onopen(None);
+        self.source.set_onmessage(None);
+        self.source.set_onerror(None);
+        self.source.close();
+    }
+}
+
+/// A typed stream of Server-Sent Events.
+///
+/// Each event's data is parsed as JSON; events that aren't a `T` are
+/// logged and skipped. Only unnamed (`message`) events are received.
+///
+/// The browser reconnects a dropped stream by itself. If it gives up, e.g.
+/// because the server answered with an error, the stream is reopened after
+/// a backoff that grows with each failed attempt.
+pub struct EventSourceStream<T> {
+    url: String,
+    connection: Option<SourceConnection>,
+    state: ConnectionState,
+    /// Failed connection attempts since the last successful one.
+    attempts: u32,
+    last_event_id: Option<String>,
+    resume_param: Option<String>,
+    _message: PhantomData<T>,
+}
+
+impl<T: DeserializeOwned> EventSourceStream<T> {
+    /// Create a stream of the events at `url`.
+    ///
+    /// Nothing is requested until the first [`EventSourceStream::step`].
+    pub fn new(url: impl Into<String>) -> Self {
+        Self {
+            url: url.into(),
+            connection: None,
+            state: ConnectionState::default(),
+            attempts: 0,
+            last_event_id: None,
+            resume_param: None,
+            _message: PhantomData,
+        }
+    }
+
+    pub fn get_url(&self) -> &str {
+        &self.url
+    }
+
+    pub fn state(&self) -> ConnectionState {
+        self.state
+    }
+
+    /// Returns the id of the last event received, if the server sent one.
+    pub fn last_event_id(&self) -> Option<&str> {
+        self.last_event_id.as_deref()
+    }
+
+    /// Set the id to resume after, e.g. one saved before a page reload.
+    ///
+    /// It is only sent to the server with
+    /// [`EventSourceStream::set_resume_param`].
+    pub fn set_last_event_id(&mut self, id: Option<String>) {
+        self.last_event_id = id;
+    }
+
+    /// Pass the last event id to the server in the query parameter `name`
+    /// whenever the stream is opened, so it can resume where the stream
+    /// left off. Off (`None`) by default.
+    ///
+    /// ## Note
+    /// Browsers send a `Last-Event-ID` header when they reconnect on
+    /// their own, but not when a stream is opened anew, so servers that
+    /// resume should read both.
+    pub fn set_resume_param(&mut self, name: Option<&str>) {
+        self.resume_param = name.map(String::from);
+    }
+
+    /// Returns the URL to open, with the resume parameter if there is one.
+    fn open_url(&self) -> String {
+        match (&self.resume_param, &self.last_event_id) {
+            (Some(name), Some(id)) => {
+                let separator = if self.url.contains('?') { '&' } else { '?' };
+                let id = String::from(js_sys::encode_uri_component(id));
+                format!("{}{separator}{name}={id}", self.url)
+            }
+            _ => self.url.clone(),
+        }
+    }
+
+    /// Await the next event, reopening the stream as needed.
+    pub async fn step(&mut self) -> T {
+        loop {
+            let Some(connection) = self.connection.as_ref() else {
+                if self.attempts > 0 {
+                    mogwai::time::wait_millis(backoff_millis(self.attempts)).await;
+                }
+                let url = self.open_url();
+                match SourceConnection::open(&url) {
+                    Ok(connection) => self.connection = Some(connection),
+                    Err(e) => {
+                        log::warn!("could not open an EventSource to '{url}': {e:?}");
+                        self.attempts += 1;
+                    }
+                }
+                continue;
+            };
+            let event = Queue::next(&connection.queue).await;
+            match event {
+                SourceEvent::Open => {
+                    self.attempts = 0;
+                    self.state = ConnectionState::Open;
+                }
+                SourceEvent::Message { id, data } => {
+                    if !id.is_empty() {
+                        self.last_event_id = Some(id);
+                    }
+                    if let Some(message) = parse(&self.url, &data) {
+                        return message;
+                    }
+                }
+                SourceEvent::Retrying => {
+                    self.state = ConnectionState::Reconnecting;
+                }
+                SourceEvent::Closed => {
+                    self.connection = None;
+                    self.attempts += 1;
+                    self.state = ConnectionState::Reconnecting;
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,141 @@
+//! Content that waits on a request.
+//!
+//! [`Loadable`] shows a spinner while a request is in flight, then either
+//! its content or an error [`Alert`]:
+//!
+//! ```ignore
+//! let table = Table::new(..);
+//! let mut users = Loadable::<V, Vec<User>>::new(&table);
+//! users.start(WebHttp.get("/api/users"));
+//! if let Some(users) = users.step().await {
+//!     // fill in the table from `users`
+//! }
+//! ```
+
+use std::{future::Future, pin::Pin};
+
+use mogwai::prelude::*;
+
+use crate::{
+    capabilities::Error,
+    components::{alert::Alert, Flavor},
+};
+
+/// Where a [`Loadable`] is in its request's lifecycle.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum LoadPhase {
+    #[default]
+    Loading,
+    Loaded,
+    Failed,
+}
+
+/// Shows a spinner, then content or an error, as a request progresses.
+///
+/// Starts out [`LoadPhase::Loading`].
+#[derive(ViewChild, ViewProperties)]
+pub struct Loadable<V: View, T> {
+    #[child]
+    #[properties]
+    wrapper: V::Element,
+    phase: Proxy<LoadPhase>,
+    alert: Alert<V>,
+    value: Option<T>,
+    /// The request in flight, if any.
+    request: Option<Pin<Box<dyn Future<Output = Result<T, Error>>>>>,
+}
+
+impl<V: View, T> Loadable<V, T> {
+    /// Create a loadable that shows `content` once loaded.
+    pub fn new(content: &impl ViewChild<V>) -> Self {
+        let mut phase = Proxy::new(LoadPhase::Loading);
+        let alert = Alert::new("", Flavor::Danger);
+        alert.set_heading("Could not load");
+
+        rsx! {
+            let wrapper = div(class = "loadable") {
+                div(
+                    class = "loadable-spinner",
+                    style:display = phase(p => if *p == LoadPhase::Loading { "flex" } else { "none" }),
+                ) {
+                    span(class = "spinner-border", role = "status") {
+                        span(class = "visually-hidden") {
+                            "Loading..."
+                        }
+                    }
+                }
+                div(
+                    class = "loadable-content",
+                    style:display = phase(p => if *p == LoadPhase::Loaded { "block" } else { "none" }),
+                ) {
+                    {content}
+                }
+                div(
+                    class = "loadable-error",
+                    style:display = phase(p => if *p == LoadPhase::Failed { "block" } else { "none" }),
+                ) {
+                    {&alert}
+                }
+            }
+        }
+
+        Self {
+            wrapper,
+            phase,
+            alert,
+            value: None,
+            request: None,
+        }
+    }
+
+    pub fn phase(&self) -> LoadPhase {
+        *self.phase
+    }
+
+    /// Returns the loaded value, if the last request succeeded.
+    pub fn get(&self) -> Option<&T> {
+        self.value.as_ref()
+    }
+
+    pub fn get_alert(&self) -> &Alert<V> {
+        &self.alert
+    }
+
+    pub fn get_alert_mut(&mut self) -> &mut Alert<V> {
+        &mut self.alert
+    }
+
+    /// Show the spinner while `request` is in flight, replacing any earlier
+    /// request. Await its outcome with [`Loadable::step`].
+    pub fn start(&mut self, request: impl Future<Output = Result<T, Error>> + 'static) {
+        self.value = None;
+        self.phase.set(LoadPhase::Loading);
+        self.request = Some(Box::pin(request));
+    }
+
+    /// Await the request given to [`Loadable::start`].
+    ///
+    /// On success the content is shown and the value returned, so the
+    /// content can be filled in from it. On failure the error is shown in
+    /// the alert and `None` is returned. Never resolves while no request is
+    /// in flight.
+    pub async fn step(&mut self) -> Option<&T> {
+        let Some(request) = self.request.as_mut() else {
+            return std::future::pending().await;
+        };
+        let result = request.await;
+        self.request = None;
+        match result {
+            Ok(value) => {
+                self.phase.set(LoadPhase::Loaded);
+                self.value = Some(value);
+            }
+            Err(e) => {
+                log::warn!("{e}");
+                self.alert.set_text(e.to_string());
+                self.phase.set(LoadPhase::Failed);
+            }
+        }
+        self.value.as_ref()
+    }
+}
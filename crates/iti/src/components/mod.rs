@@ -6,29 +6,96 @@ pub mod button;
 pub mod button_group;
 pub mod card;
 pub mod checkbox;
+pub mod color_mode;
+pub mod copy;
+pub mod dnd;
 pub mod dropdown;
 pub mod icon;
 pub mod icon_classic;
 pub mod list;
+pub mod live;
+pub mod loadable;
 pub mod modal;
+pub mod observe;
 pub mod pane;
 #[cfg(feature = "library")]
 pub mod platinum_kit;
 pub mod progress;
 pub mod radio;
+pub mod responsive;
 pub mod select;
 pub mod shadow;
 pub mod slider;
 pub mod tab;
 pub mod table;
+pub mod tabs;
 pub mod title_bar;
 pub mod toast;
+pub mod undo;
 pub mod widget;
 
+/// Responsive breakpoint, named as in Bootstrap.
+///
+/// Used by responsive class variants such as `list-group-horizontal-md`,
+/// which only apply at or above the breakpoint's viewport width.
+///
+/// Breakpoints are ordered from narrowest to widest. See
+/// [`responsive::BreakpointWatcher`] for the breakpoint of the viewport.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Breakpoint {
+    /// Below 576px. Every viewport is at least `Xs`, so there are no `xs`
+    /// class variants.
+    Xs,
+    /// 576px and up.
+    Sm,
+    /// 768px and up.
+    Md,
+    /// 992px and up.
+    Lg,
+    /// 1200px and up.
+    Xl,
+    /// 1400px and up.
+    Xxl,
+}
+
+impl std::fmt::Display for Breakpoint {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(self.class_name())
+    }
+}
+
+impl Breakpoint {
+    pub fn class_name(&self) -> &str {
+        match self {
+            Breakpoint::Xs => "xs",
+            Breakpoint::Sm => "sm",
+            Breakpoint::Md => "md",
+            Breakpoint::Lg => "lg",
+            Breakpoint::Xl => "xl",
+            Breakpoint::Xxl => "xxl",
+        }
+    }
+
+    /// Returns the viewport width in pixels at which this breakpoint starts.
+    pub fn min_width(&self) -> u32 {
+        match self {
+            Breakpoint::Xs => 0,
+            Breakpoint::Sm => 576,
+            Breakpoint::Md => 768,
+            Breakpoint::Lg => 992,
+            Breakpoint::Xl => 1200,
+            Breakpoint::Xxl => 1400,
+        }
+    }
+}
+
 /// Contextual color variant.
 ///
 /// Maps to contextual class suffixes used across components (e.g.
 /// `flavor-primary`, `alert-danger`, `list-group-item-success`).
+///
+/// Apps with their own palette can use [`Flavor::Custom`] with a name of
+/// their choosing and style it with [`crate::color::flavor_css`].
 #[derive(Clone, Copy, Debug, Default, PartialEq)]
 pub enum Flavor {
     #[default]
@@ -41,6 +108,13 @@ pub enum Flavor {
     Light,
     Dark,
     Link,
+    /// An app-defined flavor, e.g. `Flavor::Custom("brand")` produces
+    /// `flavor-brand`, `alert-brand`, `text-bg-brand` and so on.
+    ///
+    /// ## Note
+    /// iti.css has no rules for custom flavors. Add them with
+    /// [`crate::color::flavor_css`] or your own stylesheet.
+    Custom(&'static str),
 }
 
 impl std::fmt::Display for Flavor {
@@ -61,6 +135,7 @@ impl Flavor {
             Flavor::Light => "light",
             Flavor::Dark => "dark",
             Flavor::Link => "link",
+            Flavor::Custom(name) => name,
         }
     }
 }
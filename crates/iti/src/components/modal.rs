@@ -1,15 +1,201 @@
 //! Modal dialog component.
 //!
-//! A Bootstrap modal with title, body slot, and close handling.  The backdrop
+//! A Bootstrap modal with title, body and footer slots, and close handling.
+//! [`Modal::with_actions`] builds the common OK / Cancel dialog.  The backdrop
 //! and visibility are managed in pure Rust — no Bootstrap JS required.
-//! Pressing Escape while the modal is visible will also close it.
-use mogwai::prelude::*;
+//! Pressing Escape while the modal is visible will also close it. While
+//! visible, Tab focus is trapped within the dialog, and focus returns to the
+//! previously focused element when the modal hides.
+//!
+//! Modals shown on top of each other are stacked: each gets a higher
+//! z-index, only the topmost backdrop dims the page, and Escape only closes
+//! the topmost modal.
+use mogwai::{
+    prelude::*,
+    web::{WebElement, WebEvent},
+};
 use wasm_bindgen::JsCast;
 
+use super::{
+    button::{Button, PrimaryButton},
+    Breakpoint,
+};
+use crate::a11y::FocusTrap;
+
 /// Event emitted by a [`Modal`].
 pub enum ModalEvent {
     /// The modal was closed (via close button, backdrop click, or Escape key).
     Closed,
+    /// The OK button of a [`Modal::with_actions`] modal was clicked.
+    Confirmed,
+    /// The Cancel button of a [`Modal::with_actions`] modal was clicked.
+    Cancelled,
+}
+
+/// Width of a [`Modal`]'s dialog.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum ModalSize {
+    /// 300px.
+    Sm,
+    /// 500px.
+    #[default]
+    Default,
+    /// 800px.
+    Lg,
+    /// 1140px.
+    Xl,
+}
+
+impl ModalSize {
+    fn class_suffix(&self) -> &str {
+        match self {
+            ModalSize::Sm => " modal-sm",
+            ModalSize::Default => "",
+            ModalSize::Lg => " modal-lg",
+            ModalSize::Xl => " modal-xl",
+        }
+    }
+}
+
+#[derive(Clone, Copy, PartialEq)]
+enum Fullscreen {
+    Off,
+    Always,
+    Below(Breakpoint),
+}
+
+struct ModalState {
+    is_visible: bool,
+    size: ModalSize,
+    fullscreen: Fullscreen,
+    is_centered: bool,
+    is_scrollable: bool,
+}
+
+impl ModalState {
+    fn display(&self) -> &'static str {
+        if self.is_visible {
+            "block"
+        } else {
+            "none"
+        }
+    }
+
+    fn show_suffix(&self) -> &'static str {
+        if self.is_visible {
+            " show"
+        } else {
+            ""
+        }
+    }
+
+    fn backdrop_class(&self) -> String {
+        format!("modal-backdrop fade{}", self.show_suffix())
+    }
+
+    fn modal_class(&self) -> String {
+        format!("modal fade{}", self.show_suffix())
+    }
+
+    fn dialog_class(&self) -> String {
+        let fullscreen = match self.fullscreen {
+            Fullscreen::Off | Fullscreen::Below(Breakpoint::Xs) => String::new(),
+            Fullscreen::Always => " modal-fullscreen".to_string(),
+            Fullscreen::Below(bp) => format!(" modal-fullscreen-{bp}-down"),
+        };
+        let centered = if self.is_centered {
+            " modal-dialog-centered"
+        } else {
+            ""
+        };
+        let scrollable = if self.is_scrollable {
+            " modal-dialog-scrollable"
+        } else {
+            ""
+        };
+        format!(
+            "modal-dialog{}{fullscreen}{centered}{scrollable}",
+            self.size.class_suffix()
+        )
+    }
+}
+
+/// Registry of visible modals, bottom to top.
+mod layers {
+    use std::{
+        cell::RefCell,
+        sync::atomic::{AtomicUsize, Ordering},
+    };
+
+    /// z-index of the bottom backdrop.
+    const BASE_Z_INDEX: usize = 1050;
+    /// z-index distance between layers; the modal sits halfway up its layer.
+    const LAYER_STEP: usize = 10;
+
+    struct Layer {
+        id: usize,
+        backdrop: web_sys::HtmlElement,
+        modal: web_sys::HtmlElement,
+    }
+
+    static NEXT_ID: AtomicUsize = AtomicUsize::new(0);
+
+    thread_local! {
+        static LAYERS: RefCell<Vec<Layer>> = const { RefCell::new(Vec::new()) };
+    }
+
+    pub(super) fn next_id() -> usize {
+        NEXT_ID.fetch_add(1, Ordering::Relaxed)
+    }
+
+    /// Put the modal with `id` on top of the stack.
+    pub(super) fn push(id: usize, backdrop: web_sys::HtmlElement, modal: web_sys::HtmlElement) {
+        LAYERS.with_borrow_mut(|layers| {
+            layers.retain(|layer| layer.id != id);
+            layers.push(Layer {
+                id,
+                backdrop,
+                modal,
+            });
+            restyle(layers);
+        });
+    }
+
+    pub(super) fn remove(id: usize) {
+        LAYERS.with_borrow_mut(|layers| {
+            layers.retain(|layer| layer.id != id);
+            restyle(layers);
+        });
+    }
+
+    /// Returns `true` if no other modal is shown above the modal with `id`.
+    pub(super) fn is_topmost(id: usize) -> bool {
+        LAYERS.with_borrow(|layers| layers.last().is_none_or(|layer| layer.id == id))
+    }
+
+    fn restyle(layers: &[Layer]) {
+        let top = layers.len().saturating_sub(1);
+        for (depth, layer) in layers.iter().enumerate() {
+            let z_index = BASE_Z_INDEX + depth * LAYER_STEP;
+            let backdrop = layer.backdrop.style();
+            let _ = backdrop.set_property("z-index", &z_index.to_string());
+            if depth == top {
+                let _ = backdrop.remove_property("background-color");
+            } else {
+                let _ = backdrop.set_property("background-color", "transparent");
+            }
+            let _ = layer
+                .modal
+                .style()
+                .set_property("z-index", &(z_index + LAYER_STEP / 2).to_string());
+        }
+    }
+}
+
+/// The buttons of a modal created with [`Modal::with_actions`].
+struct ModalActions<V: View> {
+    ok: PrimaryButton<V>,
+    cancel: Button<V>,
 }
 
 /// A Bootstrap modal dialog.
@@ -25,39 +211,52 @@ pub struct Modal<V: View> {
     title: V::Text,
     body: V::Element,
     body_child: ProxyChild<V>,
+    footer: V::Element,
+    footer_child: ProxyChild<V>,
+    actions: Option<ModalActions<V>>,
     close_click: V::EventListener,
     backdrop_click: V::EventListener,
+    /// Clicks on the `.modal` element itself land outside the dialog box.
+    dialog_click: V::EventListener,
     keydown: V::EventListener,
-    visible: Proxy<bool>,
+    state: Proxy<ModalState>,
+    backdrop: V::Element,
+    /// The `.modal` element; receives focus on show and traps Tab.
+    dialog: V::Element,
+    /// Keeps Tab inside the dialog while visible, and restores the
+    /// previously focused element when dropped.
+    focus_trap: Option<FocusTrap>,
+    is_static_backdrop: bool,
+    /// This modal's id in the stack of visible modals.
+    layer_id: usize,
 }
 
 impl<V: View> Modal<V> {
     pub fn new(title: impl AsRef<str>) -> Self {
-        let mut visible = Proxy::new(false);
+        let mut state = Proxy::new(ModalState {
+            is_visible: false,
+            size: ModalSize::Default,
+            fullscreen: Fullscreen::Off,
+            is_centered: false,
+            is_scrollable: false,
+        });
 
         rsx! {
             let wrapper = div(
                 document:keydown = keydown,
             ) {
-                div(
-                    class = visible(v => if *v {
-                        "modal-backdrop fade show"
-                    } else {
-                        "modal-backdrop fade"
-                    }),
-                    style:display = visible(v => if *v { "block" } else { "none" }),
+                let backdrop = div(
+                    class = state(s => s.backdrop_class()),
+                    style:display = state(s => s.display()),
                     on:click = backdrop_click,
                 ) {}
-                div(
-                    class = visible(v => if *v {
-                        "modal fade show"
-                    } else {
-                        "modal fade"
-                    }),
+                let dialog = div(
+                    class = state(s => s.modal_class()),
                     tabindex = "-1",
-                    style:display = visible(v => if *v { "block" } else { "none" }),
+                    style:display = state(s => s.display()),
+                    on:click = dialog_click,
                 ) {
-                    div(class = "modal-dialog") {
+                    div(class = state(s => s.dialog_class())) {
                         div(class = "modal-content") {
                             div(class = "modal-header") {
                                 h5(class = "modal-title") {
@@ -71,6 +270,10 @@ impl<V: View> Modal<V> {
                                 ) {}
                             }
                             let body = div(class = "modal-body") {}
+                            let footer = div(
+                                class = "modal-footer",
+                                style:display = "none",
+                            ) {}
                         }
                     }
                 }
@@ -87,16 +290,59 @@ impl<V: View> Modal<V> {
         });
         body.append_child(&body_child);
 
+        let footer_child = ProxyChild::new(&{
+            rsx! {
+                let placeholder = span() {}
+            }
+            placeholder
+        });
+        footer.append_child(&footer_child);
+
         Self {
             wrapper,
             title: title_text,
             body,
             body_child,
+            footer,
+            footer_child,
+            actions: None,
             close_click,
             backdrop_click,
+            dialog_click,
             keydown,
-            visible,
+            state,
+            backdrop,
+            dialog,
+            focus_trap: None,
+            is_static_backdrop: false,
+            layer_id: layers::next_id(),
+        }
+    }
+
+    /// Create a confirm dialog with OK and Cancel buttons in the footer.
+    ///
+    /// [`Modal::step`] returns [`ModalEvent::Confirmed`] or
+    /// [`ModalEvent::Cancelled`] when the buttons are clicked.
+    pub fn with_actions(
+        title: impl AsRef<str>,
+        ok_label: impl AsRef<str>,
+        cancel_label: impl AsRef<str>,
+    ) -> Self {
+        let mut modal = Self::new(title);
+
+        let mut ok = PrimaryButton::new(ok_label, None);
+        ok.set_has_icon(false);
+        let mut cancel = Button::new(cancel_label, None);
+        cancel.set_has_icon(false);
+        rsx! {
+            let wrapper = div(class = "modal-actions") {
+                {&cancel}
+                {&ok}
+            }
         }
+        modal.set_footer(&wrapper);
+        modal.actions = Some(ModalActions { ok, cancel });
+        modal
     }
 
     pub fn set_title(&self, title: impl AsRef<str>) {
@@ -108,40 +354,313 @@ impl<V: View> Modal<V> {
         self.body_child.replace(&self.body, content);
     }
 
+    /// Replace the modal footer content, showing the footer.
+    ///
+    /// This replaces the OK / Cancel buttons of a [`Modal::with_actions`]
+    /// modal.
+    pub fn set_footer(&mut self, content: &impl ViewChild<V>) {
+        self.footer_child.replace(&self.footer, content);
+        self.footer.remove_style("display");
+        self.actions = None;
+    }
+
     /// Show the modal and its backdrop.
+    ///
+    /// Focus moves into the dialog, remembering the previously focused
+    /// element.
     pub fn show(&mut self) {
-        self.visible.set(true);
+        if !self.state.is_visible {
+            self.focus_trap = self.dialog.dyn_el(FocusTrap::new);
+        }
+        self.state.modify(|s| s.is_visible = true);
+        let backdrop = self.backdrop.dyn_el(|el: &web_sys::HtmlElement| el.clone());
+        let dialog = self.dialog.dyn_el(|el: &web_sys::HtmlElement| el.clone());
+        if let (Some(backdrop), Some(dialog)) = (backdrop, dialog) {
+            layers::push(self.layer_id, backdrop, dialog);
+        }
+        self.dialog.dyn_el(|el: &web_sys::HtmlElement| el.focus());
     }
 
-    /// Hide the modal and its backdrop.
+    /// Hide the modal and its backdrop, restoring focus to the element that
+    /// had it before [`Modal::show`].
     pub fn hide(&mut self) {
-        self.visible.set(false);
+        self.state.modify(|s| s.is_visible = false);
+        layers::remove(self.layer_id);
+        // Dropping the trap restores focus.
+        self.focus_trap = None;
     }
 
     /// Returns `true` if the modal is currently visible.
     pub fn is_visible(&self) -> bool {
-        *self.visible
+        self.state.is_visible
+    }
+
+    pub fn set_size(&mut self, size: ModalSize) {
+        self.state.modify(|s| s.size = size);
+    }
+
+    pub fn size(&self) -> ModalSize {
+        self.state.size
+    }
+
+    /// Make the dialog cover the viewport below the given breakpoint
+    /// (`modal-fullscreen-{bp}-down`), or turn fullscreen off with `None`.
+    ///
+    /// No viewport is narrower than [`Breakpoint::Xs`], so it has the same
+    /// effect as `None`.
+    ///
+    /// See [`Modal::set_is_fullscreen`] for a dialog that is always
+    /// fullscreen.
+    pub fn set_fullscreen(&mut self, below: Option<Breakpoint>) {
+        let fullscreen = below.map(Fullscreen::Below).unwrap_or(Fullscreen::Off);
+        self.state.modify(|s| s.fullscreen = fullscreen);
+    }
+
+    /// Make the dialog cover the viewport at every width.
+    pub fn set_is_fullscreen(&mut self, is_fullscreen: bool) {
+        let fullscreen = if is_fullscreen {
+            Fullscreen::Always
+        } else {
+            Fullscreen::Off
+        };
+        self.state.modify(|s| s.fullscreen = fullscreen);
+    }
+
+    /// Returns the breakpoint below which the dialog is fullscreen, if one
+    /// was set with [`Modal::set_fullscreen`].
+    pub fn fullscreen(&self) -> Option<Breakpoint> {
+        match self.state.fullscreen {
+            Fullscreen::Below(bp) => Some(bp),
+            Fullscreen::Off | Fullscreen::Always => None,
+        }
+    }
+
+    /// Returns `true` if the dialog is fullscreen at every width.
+    pub fn is_fullscreen(&self) -> bool {
+        self.state.fullscreen == Fullscreen::Always
     }
 
-    /// Await the next modal event (close button, backdrop click, or Escape key).
+    /// Center the dialog vertically in the viewport.
+    pub fn set_is_centered(&mut self, is_centered: bool) {
+        self.state.modify(|s| s.is_centered = is_centered);
+    }
+
+    pub fn is_centered(&self) -> bool {
+        self.state.is_centered
+    }
+
+    /// Keep the dialog within the viewport and scroll the body instead of
+    /// the page when the content is tall.
+    pub fn set_is_scrollable(&mut self, is_scrollable: bool) {
+        self.state.modify(|s| s.is_scrollable = is_scrollable);
+    }
+
+    pub fn is_scrollable(&self) -> bool {
+        self.state.is_scrollable
+    }
+
+    /// Make backdrop clicks shake the dialog instead of closing it.
+    ///
+    /// Use this when an accidental dismissal would lose the user's input.
+    /// The close button and Escape still close the modal.
+    pub fn set_static_backdrop(&mut self, is_static: bool) {
+        self.is_static_backdrop = is_static;
+    }
+
+    pub fn is_static_backdrop(&self) -> bool {
+        self.is_static_backdrop
+    }
+
+    /// Await a click on the backdrop, outside the dialog box.
+    async fn backdrop_clicked(&self) {
+        use futures_lite::FutureExt;
+
+        let outside_dialog = async {
+            loop {
+                let ev = self.dialog_click.next().await;
+                let target = ev.dyn_ev(|e: &web_sys::Event| e.target()).flatten();
+                let is_dialog = self
+                    .dialog
+                    .dyn_el(|el: &web_sys::EventTarget| Some(el) == target.as_ref())
+                    .unwrap_or(false);
+                if is_dialog {
+                    return;
+                }
+            }
+        };
+        async {
+            self.backdrop_click.next().await;
+        }
+        .or(outside_dialog)
+        .await
+    }
+
+    /// Play the `modal-static` shake animation.
+    fn shake(&self) {
+        self.dialog.dyn_el(|el: &web_sys::HtmlElement| {
+            let classes = el.class_list();
+            let _ = classes.remove_1("modal-static");
+            // Force a reflow so the animation restarts.
+            let _ = el.offset_width();
+            let _ = classes.add_1("modal-static");
+        });
+    }
+
+    /// Await the next modal event (close button, backdrop click, Escape key,
+    /// or one of the [`Modal::with_actions`] buttons).
     pub async fn step(&self) -> ModalEvent {
         use futures_lite::FutureExt;
 
-        let close_or_backdrop = self.close_click.next().or(self.backdrop_click.next());
+        let backdrop = async {
+            loop {
+                self.backdrop_clicked().await;
+                if !self.is_static_backdrop {
+                    return;
+                }
+                self.shake();
+            }
+        };
+        let close_or_backdrop = async {
+            self.close_click.next().await;
+        }
+        .or(backdrop);
         let escape = async {
             loop {
                 let ev = self.keydown.next().await;
+                if !self.state.is_visible || !layers::is_topmost(self.layer_id) {
+                    continue;
+                }
                 let is_escape = ev.when_event::<mogwai::web::Web, _>(|e: &web_sys::Event| {
                     e.dyn_ref::<web_sys::KeyboardEvent>()
                         .is_some_and(|ke| ke.key() == "Escape")
                 });
                 if is_escape == Some(true) {
-                    return ev;
+                    return;
                 }
             }
         };
-        close_or_backdrop.or(escape).await;
-        ModalEvent::Closed
+        let closed = async {
+            close_or_backdrop.or(escape).await;
+            ModalEvent::Closed
+        };
+        let actions = async {
+            match self.actions.as_ref() {
+                Some(actions) => {
+                    let ok = async {
+                        actions.ok.step().await;
+                        ModalEvent::Confirmed
+                    };
+                    let cancel = async {
+                        actions.cancel.step().await;
+                        ModalEvent::Cancelled
+                    };
+                    ok.or(cancel).await
+                }
+                None => std::future::pending().await,
+            }
+        };
+        closed.or(actions).await
+    }
+}
+
+impl<V: View> Drop for Modal<V> {
+    fn drop(&mut self) {
+        layers::remove(self.layer_id);
+    }
+}
+
+/// Awaitable, styled replacements for `window.confirm` and `window.prompt`.
+///
+/// Each helper appends a temporary [`Modal`] to the document body, shows it,
+/// awaits the user's answer and removes the modal again.
+///
+/// ```ignore
+/// if dialogs::confirm("Delete file?", "This can't be undone.").await {
+///     delete_file();
+/// }
+/// ```
+pub mod dialogs {
+    use mogwai::{
+        prelude::*,
+        web::{Web, WebElement},
+    };
+
+    use super::{Modal, ModalEvent};
+
+    fn confirm_modal<V: View>(title: &str, body: &str) -> Modal<V> {
+        let mut modal = Modal::with_actions(title, "OK", "Cancel");
+        let text = V::Text::new(body);
+        rsx! {
+            let content = p() { {text} }
+        }
+        modal.set_body(&content);
+        modal
+    }
+
+    fn prompt_modal<V: View>(title: &str, placeholder: &str) -> (Modal<V>, V::Element) {
+        let mut modal = Modal::with_actions(title, "OK", "Cancel");
+        rsx! {
+            let input = input(
+                type = "text",
+                class = "form-control",
+                placeholder = placeholder,
+            ) {}
+        }
+        modal.set_body(&input);
+        (modal, input)
+    }
+
+    /// A modal attached to the body and shown, which is hidden and removed
+    /// again when dropped.
+    struct Shown<'a> {
+        modal: &'a mut Modal<Web>,
+    }
+
+    impl<'a> Shown<'a> {
+        fn new(modal: &'a mut Modal<Web>) -> Self {
+            mogwai::web::body().append_child(&*modal);
+            modal.show();
+            Self { modal }
+        }
+    }
+
+    impl Drop for Shown<'_> {
+        fn drop(&mut self) {
+            self.modal.hide();
+            mogwai::web::body().remove_child(&*self.modal);
+        }
+    }
+
+    /// Attach `modal` to the body, show it and await its first event.
+    ///
+    /// `focus` receives keyboard focus once the modal is visible. The modal
+    /// is hidden and removed once the event arrives, or if this future is
+    /// dropped before then.
+    async fn run(modal: &mut Modal<Web>, focus: Option<&<Web as View>::Element>) -> ModalEvent {
+        let shown = Shown::new(modal);
+        if let Some(el) = focus {
+            el.dyn_el(|el: &web_sys::HtmlElement| el.focus());
+        }
+        shown.modal.step().await
+    }
+
+    /// Ask the user to confirm, returning `true` if they clicked OK.
+    ///
+    /// Cancel, the close button, a backdrop click and Escape all return
+    /// `false`.
+    pub async fn confirm(title: impl AsRef<str>, body: impl AsRef<str>) -> bool {
+        let mut modal = confirm_modal::<Web>(title.as_ref(), body.as_ref());
+        matches!(run(&mut modal, None).await, ModalEvent::Confirmed)
+    }
+
+    /// Ask the user for a line of text, returning `None` if they didn't
+    /// click OK.
+    pub async fn prompt(title: impl AsRef<str>, placeholder: impl AsRef<str>) -> Option<String> {
+        let (mut modal, input) = prompt_modal::<Web>(title.as_ref(), placeholder.as_ref());
+        match run(&mut modal, Some(&input)).await {
+            ModalEvent::Confirmed => input.dyn_el(|el: &web_sys::HtmlInputElement| el.value()),
+            ModalEvent::Cancelled | ModalEvent::Closed => None,
+        }
     }
 }
 
@@ -152,13 +671,28 @@ pub mod library {
     use mogwai::prelude::*;
 
     use super::*;
+    use crate::library::{log_event, Knob, KnobEvent, Knobs};
+
+    const SIZES: [(&str, ModalSize); 4] = [
+        ("Small", ModalSize::Sm),
+        ("Default", ModalSize::Default),
+        ("Large", ModalSize::Lg),
+        ("Extra large", ModalSize::Xl),
+    ];
 
     #[derive(ViewChild)]
     pub struct ModalLibraryItem<V: View> {
         #[child]
         pub wrapper: V::Element,
         modal: Modal<V>,
+        confirm: Modal<V>,
+        confirm_result: V::Text,
         open_click: V::EventListener,
+        open_confirm_click: V::EventListener,
+        knobs: Knobs<V>,
+        size: Knob<ModalSize>,
+        is_centered: Knob<bool>,
+        is_static: Knob<bool>,
     }
 
     impl<V: View> Default for ModalLibraryItem<V> {
@@ -168,11 +702,23 @@ pub mod library {
             rsx! {
                 let body_content = div() {
                     p() { "This is the modal body. It can contain any content." }
-                    p() { "Click the backdrop or the close button to dismiss." }
+                    p() { "Click the close button, or the backdrop unless it is static, to dismiss." }
                 }
             }
             modal.set_body(&body_content);
 
+            let mut confirm = Modal::with_actions("Save changes?", "Save", "Don't Save");
+            confirm.set_static_backdrop(true);
+            rsx! {
+                let confirm_body = p() { "Your changes will be lost if you don't save them." }
+            }
+            confirm.set_body(&confirm_body);
+
+            let mut knobs = Knobs::default();
+            let size = knobs.select("Size", SIZES, 1);
+            let is_centered = knobs.toggle("Centered", false);
+            let is_static = knobs.toggle("Static backdrop", false);
+
             rsx! {
                 let wrapper = div() {
                     button(
@@ -182,33 +728,84 @@ pub mod library {
                     ) {
                         "Open modal"
                     }
+                    button(
+                        type = "button",
+                        class = "btn ms-1",
+                        on:click = open_confirm_click,
+                    ) {
+                        "Open confirm dialog"
+                    }
+                    p(class = "mt-2") {
+                        let confirm_result = ""
+                    }
                     {&modal}
+                    {&confirm}
+                    {&knobs}
                 }
             }
 
             Self {
                 wrapper,
                 modal,
+                confirm,
+                confirm_result,
                 open_click,
+                open_confirm_click,
+                knobs,
+                size,
+                is_centered,
+                is_static,
             }
         }
     }
 
+    enum Action {
+        Open,
+        OpenConfirm,
+        Modal,
+        Confirm(ModalEvent),
+        Knob(KnobEvent),
+    }
+
     impl<V: View> ModalLibraryItem<V> {
         pub async fn step(&mut self) {
-            match self
+            let action = self
                 .open_click
                 .next()
-                .map(Ok)
-                .or(self.modal.step().map(Err))
-                .await
-            {
-                Ok(_) => {
-                    self.modal.show();
-                }
-                Err(ModalEvent::Closed) => {
+                .map(|_| Action::Open)
+                .or(self.open_confirm_click.next().map(|_| Action::OpenConfirm))
+                .or(self.modal.step().map(|_| Action::Modal))
+                .or(self.confirm.step().map(Action::Confirm))
+                .or(self.knobs.step().map(Action::Knob))
+                .await;
+            match action {
+                Action::Open => self.modal.show(),
+                Action::OpenConfirm => self.confirm.show(),
+                Action::Modal => {
+                    log_event("Modal", "Closed");
                     self.modal.hide();
                 }
+                Action::Confirm(event) => {
+                    self.confirm.hide();
+                    let (logged, result) = match event {
+                        ModalEvent::Confirmed => ("Confirmed", "Saved."),
+                        ModalEvent::Cancelled => ("Cancelled", "Not saved."),
+                        ModalEvent::Closed => ("Closed", "Dismissed."),
+                    };
+                    log_event("Modal", logged);
+                    self.confirm_result.set_text(result);
+                }
+                Action::Knob(event) => {
+                    if let Some(size) = self.size.changed(&event) {
+                        self.modal.set_size(size);
+                    }
+                    if let Some(is_centered) = self.is_centered.changed(&event) {
+                        self.modal.set_is_centered(is_centered);
+                    }
+                    if let Some(is_static) = self.is_static.changed(&event) {
+                        self.modal.set_static_backdrop(is_static);
+                    }
+                }
             }
         }
     }
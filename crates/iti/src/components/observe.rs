@@ -0,0 +1,202 @@
+//! Observing elements as the page changes around them.
+//!
+//! [`Visibility`] reports when its content scrolls into or out of view,
+//! which is the basis for lazy loading, scrollspy and infinite scroll.
+//! [`Resized`] reports changes to its content's size, for layouts that
+//! have to measure themselves.
+
+use std::{cell::RefCell, rc::Rc};
+
+use mogwai::{prelude::*, web::WebElement};
+use wasm_bindgen::{prelude::Closure, JsCast};
+
+use crate::util::queue::Queue;
+
+/// Event emitted by [`Visibility::step`].
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum VisibilityEvent {
+    /// The content scrolled into view.
+    Entered,
+    /// The content scrolled out of view.
+    Left,
+}
+
+/// Wraps content to report when it enters or leaves the viewport, using
+/// an `IntersectionObserver`.
+///
+/// Content starts out counted as not visible, so content that is on
+/// screen when it is added reports [`VisibilityEvent::Entered`] at once.
+#[derive(ViewChild, ViewProperties)]
+pub struct Visibility<V: View> {
+    #[child]
+    #[properties]
+    wrapper: V::Element,
+    observer: Option<web_sys::IntersectionObserver>,
+    _callback: Closure<dyn FnMut(js_sys::Array)>,
+    queue: Rc<RefCell<Queue<bool>>>,
+    is_visible: bool,
+}
+
+impl<V: View> Visibility<V> {
+    /// Observe the visibility of `content`.
+    pub fn new(content: &impl ViewChild<V>) -> Self {
+        Self::with_margin(content, "0px")
+    }
+
+    /// Observe the visibility of `content`, growing the viewport by
+    /// `root_margin` (in CSS margin syntax).
+    ///
+    /// A positive margin such as `"200px"` reports content as entered
+    /// shortly before it scrolls into view, e.g. to start loading an image
+    /// in time.
+    pub fn with_margin(content: &impl ViewChild<V>, root_margin: &str) -> Self {
+        rsx! {
+            let wrapper = div(class = "observe-visibility") {
+                {content}
+            }
+        }
+
+        let queue = Queue::new();
+        let callback = Closure::<dyn FnMut(js_sys::Array)>::new({
+            let queue = queue.clone();
+            move |entries: js_sys::Array| {
+                let mut queue = queue.borrow_mut();
+                for entry in entries.iter() {
+                    let entry: web_sys::IntersectionObserverEntry = entry.unchecked_into();
+                    queue.push(entry.is_intersecting());
+                }
+            }
+        });
+        let init = web_sys::IntersectionObserverInit::new();
+        init.set_root_margin(root_margin);
+        let observer = web_sys::IntersectionObserver::new_with_options(
+            callback.as_ref().unchecked_ref(),
+            &init,
+        )
+        .inspect_err(|e| log::warn!("could not create an IntersectionObserver: {e:?}"))
+        .ok();
+        if let Some(observer) = observer.as_ref() {
+            wrapper.dyn_el(|el: &web_sys::Element| observer.observe(el));
+        }
+
+        Self {
+            wrapper,
+            observer,
+            _callback: callback,
+            queue,
+            is_visible: false,
+        }
+    }
+
+    /// Returns `true` if the content is in view.
+    pub fn is_visible(&self) -> bool {
+        self.is_visible
+    }
+
+    /// Await the content entering or leaving the viewport.
+    pub async fn step(&mut self) -> VisibilityEvent {
+        loop {
+            let is_visible = Queue::next(&self.queue).await;
+            if is_visible != self.is_visible {
+                self.is_visible = is_visible;
+                return if is_visible {
+                    VisibilityEvent::Entered
+                } else {
+                    VisibilityEvent::Left
+                };
+            }
+        }
+    }
+}
+
+impl<V: View> Drop for Visibility<V> {
+    fn drop(&mut self) {
+        if let Some(observer) = self.observer.take() {
+            observer.disconnect();
+        }
+    }
+}
+
+/// Wraps content to report changes to its size, using a
+/// `ResizeObserver`.
+///
+/// Sizes are the `(width, height)` of the wrapper's content box in CSS
+/// pixels.
+#[derive(ViewChild, ViewProperties)]
+pub struct Resized<V: View> {
+    #[child]
+    #[properties]
+    wrapper: V::Element,
+    observer: Option<web_sys::ResizeObserver>,
+    _callback: Closure<dyn FnMut(js_sys::Array)>,
+    queue: Rc<RefCell<Queue<(f64, f64)>>>,
+    size: (f64, f64),
+}
+
+impl<V: View> Resized<V> {
+    /// Observe the size of `content`.
+    pub fn new(content: &impl ViewChild<V>) -> Self {
+        rsx! {
+            let wrapper = div(class = "observe-resized") {
+                {content}
+            }
+        }
+
+        let queue = Queue::new();
+        let callback = Closure::<dyn FnMut(js_sys::Array)>::new({
+            let queue = queue.clone();
+            move |entries: js_sys::Array| {
+                let mut queue = queue.borrow_mut();
+                for entry in entries.iter() {
+                    let entry: web_sys::ResizeObserverEntry = entry.unchecked_into();
+                    let rect = entry.content_rect();
+                    queue.push((rect.width(), rect.height()));
+                }
+            }
+        });
+        let observer = web_sys::ResizeObserver::new(callback.as_ref().unchecked_ref())
+            .inspect_err(|e| log::warn!("could not create a ResizeObserver: {e:?}"))
+            .ok();
+        if let Some(observer) = observer.as_ref() {
+            wrapper.dyn_el(|el: &web_sys::Element| observer.observe(el));
+        }
+
+        Self {
+            wrapper,
+            observer,
+            _callback: callback,
+            queue,
+            size: (0.0, 0.0),
+        }
+    }
+
+    /// Returns the last observed `(width, height)`.
+    ///
+    /// This is `(0.0, 0.0)` until the first [`Resized::step`] resolves.
+    pub fn size(&self) -> (f64, f64) {
+        self.size
+    }
+
+    /// Await a change to the content's size, returning the new
+    /// `(width, height)`.
+    ///
+    /// The first step resolves with the initial size once the content is
+    /// laid out.
+    pub async fn step(&mut self) -> (f64, f64) {
+        loop {
+            let size = Queue::next(&self.queue).await;
+            if size != self.size {
+                self.size = size;
+                return size;
+            }
+        }
+    }
+}
+
+impl<V: View> Drop for Resized<V> {
+    fn drop(&mut self) {
+        if let Some(observer) = self.observer.take() {
+            observer.disconnect();
+        }
+    }
+}
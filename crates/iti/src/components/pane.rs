@@ -6,12 +6,22 @@
 //! one item in that collection is visible at a time.
 //!
 //! Think of the content represented by a tab.
-use std::collections::HashMap;
+use std::collections::{HashMap, VecDeque};
 
-use mogwai::prelude::*;
+use mogwai::{prelude::*, web::WebElement};
 
 use crate::id::{Id, IdPool};
 
+/// How long a [`Transition`] runs, matching the animations in `iti.css`.
+const TRANSITION_MILLIS: u64 = 150;
+
+const TRANSITION_CLASSES: [&str; 4] = [
+    "pane-fade-enter",
+    "pane-fade-leave",
+    "pane-slide-enter",
+    "pane-slide-leave",
+];
+
 /// Controls how [`Panes`] shows and hides pane content.
 #[derive(Default, Clone, Copy, Debug, PartialEq, Eq)]
 pub enum PaneMode {
@@ -30,6 +40,109 @@ pub enum PaneMode {
     /// visible, preserving scroll position, iframe state, and any other
     /// transient DOM state.
     Retain,
+    /// Like [`PaneMode::Retain`], but panes added with
+    /// [`Panes::add_pane_with`] are built the first time they are selected.
+    ///
+    /// Heavy panes (charts, iframes) are neither built up front nor rebuilt
+    /// on every visit.
+    Lazy,
+}
+
+/// Animation played when [`Panes::select`] changes the visible pane.
+#[derive(Default, Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Transition {
+    /// Switch instantly (default).
+    #[default]
+    None,
+    /// Cross-fade from the old pane to the new one.
+    Fade,
+    /// Fade while sliding the new pane in from the side.
+    Slide,
+}
+
+impl Transition {
+    /// Classes of the entering and leaving panes.
+    fn classes(&self) -> Option<(&'static str, &'static str)> {
+        match self {
+            Transition::None => None,
+            Transition::Fade => Some(("pane-fade-enter", "pane-fade-leave")),
+            Transition::Slide => Some(("pane-slide-enter", "pane-slide-leave")),
+        }
+    }
+}
+
+/// Replace any transition class on `el` with `class`, restarting its
+/// animation.
+fn set_transition_class<V: View>(el: &V::Element, class: Option<&str>) {
+    el.dyn_el(|el: &web_sys::HtmlElement| {
+        let classes = el.class_list();
+        for transition_class in TRANSITION_CLASSES {
+            let _ = classes.remove_1(transition_class);
+        }
+        if let Some(class) = class {
+            // Force a reflow so the animation restarts.
+            let _ = el.offset_width();
+            let _ = classes.add_1(class);
+        }
+    });
+}
+
+/// A slot in [`PaneMode::Retain`] and [`PaneMode::Lazy`]: the default pane's,
+/// or an added pane's.
+enum Slot<T> {
+    Default,
+    Pane(Id<T>),
+}
+
+/// Lifecycle event of a pane, emitted by [`Panes::step`] and
+/// [`RestartPanes::step`] when the selection changes.
+///
+/// Panes can use these to pause timers, load data or stop animations while
+/// they are hidden.
+pub enum PaneEvent<T> {
+    /// The pane with this [`Id`] became visible.
+    Shown(Id<T>),
+    /// The pane with this [`Id`] was hidden.
+    Hidden(Id<T>),
+}
+
+/// Queue of [`PaneEvent`]s, only filled once enabled.
+struct PaneEvents<T> {
+    queue: Option<VecDeque<PaneEvent<T>>>,
+}
+
+impl<T> Default for PaneEvents<T> {
+    fn default() -> Self {
+        Self { queue: None }
+    }
+}
+
+impl<T> PaneEvents<T> {
+    fn set_is_enabled(&mut self, is_enabled: bool) {
+        if is_enabled != self.queue.is_some() {
+            self.queue = is_enabled.then(VecDeque::new);
+        }
+    }
+
+    fn is_enabled(&self) -> bool {
+        self.queue.is_some()
+    }
+
+    fn switched(&mut self, hidden: Option<Id<T>>, shown: Option<&Id<T>>) {
+        if let Some(queue) = self.queue.as_mut() {
+            queue.extend(hidden.map(PaneEvent::Hidden));
+            queue.extend(shown.cloned().map(PaneEvent::Shown));
+        }
+    }
+
+    /// Returns the next queued event. Selection only changes while no one is
+    /// awaiting a step, so an empty queue stays empty.
+    async fn next(&mut self) -> PaneEvent<T> {
+        match self.queue.as_mut().and_then(VecDeque::pop_front) {
+            Some(event) => event,
+            None => std::future::pending().await,
+        }
+    }
 }
 
 /// Result of removing an item from [`Panes`].
@@ -51,6 +164,11 @@ pub struct PaneItemRemoval<T> {
 ///   with [`ProxyChild::replace`].
 /// * [`PaneMode::Retain`] (via [`Panes::new_retained`]) — keeps every pane in
 ///   the DOM inside a wrapper `div` and toggles `display: none`.
+/// * [`PaneMode::Lazy`] (via [`Panes::new_lazy`]) — as `Retain`, but builds
+///   each pane on its first selection.
+///
+/// Panes are kept in the order they were added or inserted, and can be looked
+/// up by index, by [`Id`], or by a string key set with [`Panes::set_key`].
 #[derive(ViewChild, ViewProperties)]
 pub struct Panes<V: View, T> {
     #[child]
@@ -64,6 +182,12 @@ pub struct Panes<V: View, T> {
     default_slot: Option<V::Element>,
     default_pane: T,
     panes: HashMap<Id<T>, T>,
+    factories: HashMap<Id<T>, Box<dyn FnOnce() -> T>>,
+    order: Vec<Id<T>>,
+    keys: HashMap<String, Id<T>>,
+    events: PaneEvents<T>,
+    transition: Transition,
+    leaving: Option<Slot<T>>,
 }
 
 impl<V: View, T: ViewChild<V>> Panes<V, T> {
@@ -87,6 +211,12 @@ impl<V: View, T: ViewChild<V>> Panes<V, T> {
             default_slot: None,
             default_pane: pane,
             panes: HashMap::new(),
+            factories: HashMap::new(),
+            order: vec![],
+            keys: HashMap::new(),
+            events: PaneEvents::default(),
+            transition: Transition::None,
+            leaving: None,
         }
     }
 
@@ -97,6 +227,18 @@ impl<V: View, T: ViewChild<V>> Panes<V, T> {
     /// `display: none` on the slot wrappers so that DOM state (scroll position,
     /// iframe content, etc.) is preserved across switches.
     pub fn new_retained(wrapper: V::Element, pane: T) -> Self {
+        Self::new_with_slots(wrapper, pane, PaneMode::Retain)
+    }
+
+    /// Create a new panes container using [`PaneMode::Lazy`].
+    ///
+    /// Works like [`Panes::new_retained`], except that the factory given to
+    /// [`Panes::add_pane_with`] runs when its pane is first selected.
+    pub fn new_lazy(wrapper: V::Element, pane: T) -> Self {
+        Self::new_with_slots(wrapper, pane, PaneMode::Lazy)
+    }
+
+    fn new_with_slots(wrapper: V::Element, pane: T, mode: PaneMode) -> Self {
         let default_slot = V::Element::new("div");
         default_slot.append_child(&pane);
         wrapper.set_style("display", "flex");
@@ -111,7 +253,7 @@ impl<V: View, T: ViewChild<V>> Panes<V, T> {
 
         Self {
             wrapper,
-            mode: PaneMode::Retain,
+            mode,
             id_pool: IdPool::default(),
             current_id: None,
             child,
@@ -119,6 +261,12 @@ impl<V: View, T: ViewChild<V>> Panes<V, T> {
             default_slot: Some(default_slot),
             default_pane: pane,
             panes: HashMap::new(),
+            factories: HashMap::new(),
+            order: vec![],
+            keys: HashMap::new(),
+            events: PaneEvents::default(),
+            transition: Transition::None,
+            leaving: None,
         }
     }
 
@@ -127,18 +275,84 @@ impl<V: View, T: ViewChild<V>> Panes<V, T> {
         self.mode
     }
 
+    /// Animate switching panes. Off ([`Transition::None`]) by default.
+    ///
+    /// In [`PaneMode::Replace`] only the new pane is animated, as the old
+    /// one leaves the DOM right away. In the other modes the old pane
+    /// animates out on top of the new one and is hidden by [`Panes::step`]
+    /// once the transition ends, or by the next [`Panes::select`].
+    pub fn set_transition(&mut self, transition: Transition) {
+        self.transition = transition;
+        if self.mode != PaneMode::Replace {
+            // The leaving slot is laid over the entering one.
+            self.wrapper.set_style("position", "relative");
+        }
+    }
+
+    pub fn transition(&self) -> Transition {
+        self.transition
+    }
+
+    fn slot(&self, slot: &Slot<T>) -> Option<&V::Element> {
+        match slot {
+            Slot::Default => self.default_slot.as_ref(),
+            Slot::Pane(id) => self.slots.get(id),
+        }
+    }
+
+    /// Hide the slot that is animating out, if any.
+    fn finish_transition(&mut self) {
+        if let Some(slot) = self.leaving.take() {
+            if let Some(el) = self.slot(&slot) {
+                set_transition_class::<V>(el, None);
+                el.set_style("display", "none");
+            }
+        }
+    }
+
     /// Add a pane to the container.
     ///
-    /// In [`PaneMode::Retain`], the pane is immediately appended to the DOM
-    /// inside a hidden wrapper `div`.
+    /// In [`PaneMode::Retain`] and [`PaneMode::Lazy`], the pane is immediately
+    /// appended to the DOM inside a hidden wrapper `div`.
     ///
     /// Returns the [`Id`] allocated for this pane, which can be used with
     /// [`select`] to show or access this pane later.
     ///
     /// [`select`]: Panes::select
     pub fn add_pane(&mut self, pane: T) -> Id<T> {
+        self.insert_pane(self.order.len(), pane)
+    }
+
+    /// Insert a pane at the given index, shifting later panes up by one.
+    ///
+    /// If `index` is out of bounds the pane is added to the end.
+    ///
+    /// Returns the [`Id`] allocated for this pane.
+    pub fn insert_pane(&mut self, index: usize, pane: T) -> Id<T> {
+        let id = self.id_pool.get_id();
+        self.order.insert(index.min(self.order.len()), id.clone());
+        self.attach_pane(id.clone(), pane);
+        id
+    }
+
+    /// Add a pane built by `create`.
+    ///
+    /// In [`PaneMode::Lazy`], `create` runs the first time the pane is
+    /// selected. Until then the pane doesn't exist: it isn't returned by
+    /// [`Panes::get_pane`] or [`Panes::iter`]. In the other modes `create`
+    /// runs right away.
+    pub fn add_pane_with(&mut self, create: impl FnOnce() -> T + 'static) -> Id<T> {
+        if self.mode != PaneMode::Lazy {
+            return self.add_pane(create());
+        }
         let id = self.id_pool.get_id();
-        if self.mode == PaneMode::Retain {
+        self.order.push(id.clone());
+        self.factories.insert(id.clone(), Box::new(create));
+        id
+    }
+
+    fn attach_pane(&mut self, id: Id<T>, pane: T) {
+        if self.mode != PaneMode::Replace {
             let slot = V::Element::new("div");
             slot.set_style("display", "none");
             slot.set_style("flex", "1");
@@ -147,8 +361,15 @@ impl<V: View, T: ViewChild<V>> Panes<V, T> {
             self.wrapper.append_child(&slot);
             self.slots.insert(id.clone(), slot);
         }
-        self.panes.insert(id.clone(), pane);
-        id
+        self.panes.insert(id, pane);
+    }
+
+    /// Returns `true` if the pane with the given `id` has been built.
+    ///
+    /// Only panes added with [`Panes::add_pane_with`] in [`PaneMode::Lazy`]
+    /// can be unbuilt.
+    pub fn is_built(&self, id: &Id<T>) -> bool {
+        self.panes.contains_key(id)
     }
 
     /// Show the pane with the given `id`, hiding the previously active pane.
@@ -159,33 +380,57 @@ impl<V: View, T: ViewChild<V>> Panes<V, T> {
     /// In [`PaneMode::Retain`], the previously active slot gets
     /// `display: none` and the newly active slot has that style removed.
     ///
+    /// In [`PaneMode::Lazy`], a pane that hasn't been built yet is built
+    /// first, then shown as in `Retain`.
+    ///
     /// Returns `true` if the pane was found and selection changed, `false` otherwise.
     pub fn select(&mut self, id: &Id<T>) -> bool {
         if Some(id) != self.current_id.as_ref() {
             match self.mode {
                 PaneMode::Replace => {
                     if let Some(pane) = self.panes.get(id) {
-                        self.current_id = Some(id.clone());
                         self.child.replace(&self.wrapper, pane);
+                        if let Some((enter, _)) = self.transition.classes() {
+                            set_transition_class::<V>(&self.wrapper, Some(enter));
+                        }
+                        let hidden = self.current_id.replace(id.clone());
+                        self.events.switched(hidden, Some(id));
                         return true;
                     }
                 }
-                PaneMode::Retain => {
+                PaneMode::Retain | PaneMode::Lazy => {
+                    if let Some(create) = self.factories.remove(id) {
+                        self.attach_pane(id.clone(), create());
+                    }
                     if self.panes.contains_key(id) {
-                        // Hide the currently active slot.
-                        if let Some(old_id) = &self.current_id {
-                            if let Some(slot) = self.slots.get(old_id) {
-                                slot.set_style("display", "none");
+                        self.finish_transition();
+
+                        // Hide the currently active slot, or start it leaving.
+                        let old = match &self.current_id {
+                            Some(old_id) => Slot::Pane(old_id.clone()),
+                            None => Slot::Default,
+                        };
+                        if let Some(old_slot) = self.slot(&old) {
+                            match self.transition.classes() {
+                                Some((_, leave)) => {
+                                    set_transition_class::<V>(old_slot, Some(leave));
+                                    self.leaving = Some(old);
+                                }
+                                None => {
+                                    old_slot.set_style("display", "none");
+                                }
                             }
-                        } else if let Some(default_slot) = &self.default_slot {
-                            default_slot.set_style("display", "none");
                         }
 
                         // Show the newly selected slot.
                         if let Some(slot) = self.slots.get(id) {
                             slot.remove_style("display");
+                            if let Some((enter, _)) = self.transition.classes() {
+                                set_transition_class::<V>(slot, Some(enter));
+                            }
                         }
-                        self.current_id = Some(id.clone());
+                        let hidden = self.current_id.replace(id.clone());
+                        self.events.switched(hidden, Some(id));
                         return true;
                     }
                 }
@@ -194,6 +439,59 @@ impl<V: View, T: ViewChild<V>> Panes<V, T> {
         false
     }
 
+    /// Show the pane at the given index. See [`Panes::select`].
+    pub fn select_by_index(&mut self, index: usize) -> bool {
+        match self.order.get(index) {
+            Some(id) => {
+                let id = id.clone();
+                self.select(&id)
+            }
+            None => false,
+        }
+    }
+
+    /// Show the pane with the given key. See [`Panes::set_key`].
+    pub fn select_key(&mut self, key: &str) -> bool {
+        match self.keys.get(key) {
+            Some(id) => {
+                let id = id.clone();
+                self.select(&id)
+            }
+            None => false,
+        }
+    }
+
+    /// Give the pane with the given `id` a key, such as a route or a file
+    /// path, that can be used instead of its [`Id`] or index.
+    ///
+    /// Keys stay with their pane as other panes are inserted and removed.
+    /// Setting a key that is already in use moves it to this pane.
+    pub fn set_key(&mut self, id: &Id<T>, key: impl Into<String>) {
+        if self.order.contains(id) {
+            self.keys.insert(key.into(), id.clone());
+        }
+    }
+
+    /// Return the [`Id`] of the pane with the given key.
+    pub fn id_for_key(&self, key: &str) -> Option<&Id<T>> {
+        self.keys.get(key)
+    }
+
+    /// Return the index of the pane with the given `id`.
+    pub fn index_of(&self, id: &Id<T>) -> Option<usize> {
+        self.order.iter().position(|other| other == id)
+    }
+
+    /// Return the number of panes, not counting the default pane.
+    pub fn len(&self) -> usize {
+        self.order.len()
+    }
+
+    /// Returns `true` if no panes have been added.
+    pub fn is_empty(&self) -> bool {
+        self.order.is_empty()
+    }
+
     /// Returns a reference to the currently visible pane.
     pub fn current_pane(&self) -> Option<&T> {
         match &self.current_id {
@@ -226,10 +524,38 @@ impl<V: View, T: ViewChild<V>> Panes<V, T> {
         self.panes.get_mut(id)
     }
 
+    /// Remove the pane at the given index, if any. See [`Panes::remove_by_id`].
+    pub fn remove_pane(&mut self, index: usize) -> Option<PaneItemRemoval<T>> {
+        let id = self.order.get(index)?.clone();
+        self.remove_by_id(&id)
+    }
+
     /// Remove the pane with the given [`Id`], if any.
+    ///
+    /// The pane's slot is removed from the DOM along with it. If the pane was
+    /// visible, the default pane is shown in its place.
+    ///
+    /// A lazy pane that was never built is dropped along with its factory,
+    /// and `None` is returned.
     pub fn remove_by_id(&mut self, id: &Id<T>) -> Option<PaneItemRemoval<T>> {
+        self.finish_transition();
+        self.order.retain(|other| other != id);
+        self.keys.retain(|_, other| other != id);
+        self.factories.remove(id);
         let pane = self.panes.remove(id)?;
+        if let Some(slot) = self.slots.remove(id) {
+            self.wrapper.remove_child(&slot);
+        }
         let was_selected = self.current_id.as_ref() == Some(id);
+        if was_selected {
+            if let Some(default_slot) = &self.default_slot {
+                default_slot.remove_style("display");
+            } else {
+                self.child.replace(&self.wrapper, &self.default_pane);
+            }
+            self.current_id = None;
+            self.events.switched(Some(id.clone()), None);
+        }
         Some(PaneItemRemoval {
             id: id.clone(),
             item: pane,
@@ -237,22 +563,56 @@ impl<V: View, T: ViewChild<V>> Panes<V, T> {
         })
     }
 
-    /// Returns an iterator over all panes.
+    /// Returns an iterator over all panes, in order.
+    ///
+    /// Lazy panes that haven't been built yet are skipped.
     pub fn iter(&self) -> impl Iterator<Item = &T> {
-        self.panes.values()
+        self.order.iter().filter_map(|id| self.panes.get(id))
     }
 
-    /// Returns a mutable iterator over all panes.
+    /// Returns a mutable iterator over all panes, in order.
+    ///
+    /// Lazy panes that haven't been built yet are skipped.
     pub fn iter_mut(&mut self) -> impl Iterator<Item = &mut T> {
-        self.panes.values_mut()
+        let order = &self.order;
+        let mut panes = self.panes.iter_mut().collect::<Vec<_>>();
+        panes.sort_by_key(|(id, _)| order.iter().position(|other| other == *id));
+        panes.into_iter().map(|(_, pane)| pane)
+    }
+
+    /// Turn [`PaneEvent`]s on or off. They are off by default.
+    ///
+    /// ## Note
+    /// Once on, events queue up until they are taken with [`Panes::step`].
+    pub fn set_has_events(&mut self, has_events: bool) {
+        self.events.set_is_enabled(has_events);
+    }
+
+    pub fn has_events(&self) -> bool {
+        self.events.is_enabled()
+    }
+
+    /// Await the next [`PaneEvent`].
+    ///
+    /// Each change of selection emits [`PaneEvent::Hidden`] for the old pane,
+    /// then [`PaneEvent::Shown`] for the new one. Never resolves unless events
+    /// were turned on with [`Panes::set_has_events`].
+    ///
+    /// Also hides the old pane once a [`Transition`] has played out.
+    pub async fn step(&mut self) -> PaneEvent<T> {
+        if self.leaving.is_some() {
+            mogwai::time::wait_millis(TRANSITION_MILLIS).await;
+            self.finish_transition();
+        }
+        self.events.next().await
     }
 }
 
 #[cfg(feature = "library")]
 pub mod library {
-    //! Storybook sandbox for [`Panes`] in [`PaneMode::Retain`] mode.
-
-    use std::collections::HashMap;
+    //! Storybook sandbox for [`Panes`](super::Panes) in
+    //! [`PaneMode::Retain`](super::PaneMode::Retain) mode, driven by
+    //! [`Tabs`].
 
     use futures_lite::FutureExt;
     use mogwai::{prelude::*, web::WebElement};
@@ -260,29 +620,27 @@ pub mod library {
     use crate::{
         components::{
             button::Button,
-            icon::{Icon, IconGlyph, IconSize, IconStyle},
-            tab::{TabItemRemoval, TabList, TabListEvent},
+            tabs::{Tabs, TabsEvent},
         },
-        id::Id,
+        library::log_event,
+        util::time::Interval,
     };
 
-    use super::Panes;
-
     /// Library item demonstrating retained panes.
     ///
     /// Three tabs with scrollable content and a live timer prove that both
-    /// scroll position and async state survive tab switches.
+    /// scroll position and async state survive tab switches. A fourth tab is
+    /// only built when first selected ([`PaneMode::Lazy`](super::PaneMode::Lazy)).
     #[derive(ViewChild)]
     pub struct PaneRetainLibraryItem<V: View> {
         #[child]
         div: V::Element,
-        tabs: TabList<V, V::Element>,
-        panes: Panes<V, V::Element>,
-        tab_ids_to_pane_ids: HashMap<Id<V::Element>, Id<V::Element>>,
+        tabs: Tabs<V, V::Element>,
         new_item_input: V::Element,
         new_item_button: Button<V>,
-        close_icons: Vec<(Id<V::Element>, Icon<V>)>,
+        new_item_count: usize,
         timer_text: V::Text,
+        timer: Interval,
         seconds: u32,
     }
 
@@ -295,10 +653,16 @@ pub mod library {
                     .set_glyph(crate::components::icon::IconGlyph::Plus);
                 b
             };
+            rsx! {
+                let default_pane = p(class = "text-muted mt-2") {
+                    "Select a tab above."
+                }
+            }
+            let mut tabs = Tabs::new_lazy(default_pane);
+            tabs.set_transition(super::Transition::Fade);
             rsx! {
                 let div = div() {
-                    let list = {TabList::default()}
-                    let pane_wrapper = div() {}
+                    {&tabs}
 
                     // TODO: use forms here when they are ready
                     div(class = "row container-fluid border-top") {
@@ -383,86 +747,51 @@ pub mod library {
             }
 
             // -- Assemble ----------------------------------------------------
-            rsx! {
-                let default_pane = p(class = "text-muted mt-2") {
-                    "Select a tab above."
+            // The first tab added is shown by default.
+            let _ = tabs.add_tab("Scrollable A", pane_a);
+            let _ = tabs.add_tab("Scrollable B", pane_b);
+            let _ = tabs.add_tab("Timer", pane_timer);
+            let _ = tabs.add_tab_with("Lazy", || {
+                log::info!("building the lazy pane");
+                rsx! {
+                    let pane = div(
+                        style:border = "1px solid #dee2e6",
+                        style:padding = "1rem",
+                        style:margin_top = "0.5rem",
+                    ) {
+                        h5() { "Lazy Pane" }
+                        p(class = "text-muted") {
+                            "This pane was built the first time its tab was selected,"
+                            br{}
+                            "and is kept from then on."
+                        }
+                    }
                 }
-            }
-
-            let panes = Panes::new_retained(pane_wrapper, default_pane);
+                pane
+            });
 
-            let mut item = Self {
+            Self {
                 div,
-                tabs: list,
-                panes,
+                tabs,
                 timer_text,
+                timer: Interval::new(1000),
                 seconds: 0,
                 new_item_input,
                 new_item_button,
-                close_icons: vec![],
-                tab_ids_to_pane_ids: Default::default(),
-            };
-
-            let (tab_a_id, _) = item.add(
-                {
-                    rsx! { let s = span() { "Scrollable A" } }
-                    s
-                },
-                pane_a,
-            );
-
-            let _ = item.add(
-                {
-                    rsx! { let s = span() { "Scrollable B" } }
-                    s
-                },
-                pane_b,
-            );
-
-            let _ = item.add(
-                {
-                    rsx! { let s = span() { "Timer" } }
-                    s
-                },
-                pane_timer,
-            );
-
-            // Show the first pane by default.
-            item.select(&tab_a_id);
-
-            item
+                new_item_count: 0,
+            }
         }
     }
 
     impl<V: View> PaneRetainLibraryItem<V> {
-        fn add(
-            &mut self,
-            tab_item: V::Element,
-            pane_item: V::Element,
-        ) -> (Id<V::Element>, Id<V::Element>) {
-            let tab_id = self.tabs.push(tab_item);
-            let pane_id = self.panes.add_pane(pane_item);
-            self.tab_ids_to_pane_ids
-                .insert(tab_id.clone(), pane_id.clone());
-            (tab_id, pane_id)
-        }
-
-        fn select(&mut self, id: &Id<V::Element>) {
-            self.tabs.select_by_id(id);
-            if let Some(id) = self.tab_ids_to_pane_ids.get(id) {
-                let _ = self.panes.select(id);
-            }
-        }
-
         pub async fn step(&mut self) {
-            enum Ev<V: View, T> {
+            enum Ev<T> {
                 Timer,
-                Tab(TabListEvent<V, T>),
+                Tab(TabsEvent<T>),
                 NewItem(String),
-                Remove(Id<V::Element>),
             }
             let timer_fut = async {
-                mogwai::time::wait_millis(1000).await;
+                self.timer.step().await;
                 Ev::Timer
             };
             let list_fut = async {
@@ -477,27 +806,15 @@ pub mod library {
                     .unwrap();
                 Ev::NewItem(s)
             };
-            let closes = self
-                .close_icons
-                .iter()
-                .map(|(id, icon)| async {
-                    let _ = icon.listen("click").next().await;
-                    Ev::Remove::<V, V::Element>(id.clone())
-                })
-                .collect::<Vec<_>>();
-            let close_tab_fut = mogwai::future::race_all(closes);
-            let result = timer_fut
-                .or(list_fut)
-                .or(new_tab_fut)
-                .or(close_tab_fut)
-                .await;
+            let result = timer_fut.or(list_fut).or(new_tab_fut).await;
             match result {
-                Ev::Tab(TabListEvent::ItemClicked {
-                    id,
-                    index: _,
-                    event: _,
-                }) => {
-                    self.select(&id);
+                // Tabs selects the clicked tab's pane, and removes the panes
+                // of closed tabs.
+                Ev::Tab(TabsEvent::Selected { index, .. }) => {
+                    log_event("Tabs", format!("Selected tab {index}"));
+                }
+                Ev::Tab(TabsEvent::Closed { index, .. }) => {
+                    log_event("Tabs", format!("Closed tab {index}"));
                 }
                 Ev::Timer => {
                     self.seconds += 1;
@@ -505,14 +822,6 @@ pub mod library {
                         .set_text(format!("{} seconds elapsed", self.seconds));
                 }
                 Ev::NewItem(s) => {
-                    let close_icon =
-                        Icon::with_style(IconGlyph::Xmark, IconSize::Regular, IconStyle::Solid);
-                    rsx! {
-                        let item = div() {
-                            {&close_icon}
-                            {format!("Tab {}", self.close_icons.len()).into_text::<V>()}
-                        }
-                    }
                     rsx! {
                         let pane = div() {
                             span() {
@@ -520,28 +829,21 @@ pub mod library {
                             }
                         }
                     }
-                    let (tab_id, pane_id) = self.add(item, pane);
-                    self.close_icons.push((tab_id.clone(), close_icon));
-
-                    self.tabs.select_by_id(&tab_id);
-                    let _ = self.panes.select(&pane_id);
-                }
-                Ev::Remove(id) => {
-                    if let Some(TabItemRemoval {
-                        id: _,
-                        index,
-                        item: _,
-                        was_selected: true,
-                    }) = self.tabs.remove_by_id(&id)
-                    {
-                        if let Some(pane_id) = self.tab_ids_to_pane_ids.remove(&id) {
-                            let _ = self.panes.remove_by_id(&pane_id);
+                    let label = format!("Tab {}", self.new_item_count);
+                    let id = match self.tabs.add_tab(label, pane) {
+                        Ok(id) => id,
+                        Err(e) => {
+                            log::error!("{e}");
+                            return;
                         }
-
-                        let selected_index = index.min(self.tab_ids_to_pane_ids.len() - 1);
-                        let id = self.tabs.get(selected_index).unwrap().id().clone();
-                        self.select(&id);
+                    };
+                    self.new_item_count += 1;
+                    // Only the added tabs can be closed.
+                    let index = self.tabs.len() - 1;
+                    if let Some(tab) = self.tabs.get_tab_list_mut().get_mut(index) {
+                        tab.set_is_closable(true);
                     }
+                    self.tabs.select(&id);
                 }
             }
         }
@@ -552,6 +854,9 @@ pub mod library {
 ///
 /// Stores pane *factories* (`Box<dyn FnMut() -> T>`). Each time a pane is
 /// selected it is re-created from the factory, ensuring a fresh state.
+///
+/// With [`RestartPanes::set_keep_alive`] the most recently hidden panes are
+/// kept instead, and shown again as they were when revisited.
 #[derive(ViewChild, ViewProperties)]
 pub struct RestartPanes<V: View, T> {
     #[child]
@@ -562,6 +867,10 @@ pub struct RestartPanes<V: View, T> {
     child: ProxyChild<V>,
     pane: T,
     panes: HashMap<Id<T>, Box<dyn FnMut() -> T>>,
+    events: PaneEvents<T>,
+    keep_alive: usize,
+    /// Hidden panes kept alive, most recently shown first.
+    alive: VecDeque<(Id<T>, T)>,
 }
 
 impl<V: View, T: ViewChild<V>> RestartPanes<V, T> {
@@ -581,9 +890,29 @@ impl<V: View, T: ViewChild<V>> RestartPanes<V, T> {
             child,
             pane: default_pane,
             panes: HashMap::new(),
+            events: PaneEvents::default(),
+            keep_alive: 0,
+            alive: VecDeque::new(),
         }
     }
 
+    /// Keep up to `count` hidden panes alive, dropping the least recently
+    /// shown beyond that. Defaults to `0`: every pane is rebuilt on each
+    /// visit.
+    ///
+    /// A pane that is kept alive keeps its state and is shown again as it
+    /// was, instead of being rebuilt from its factory. This is a middle ground
+    /// between [`Panes`] in [`PaneMode::Retain`], which keeps every pane, and
+    /// rebuilding every time.
+    pub fn set_keep_alive(&mut self, count: usize) {
+        self.keep_alive = count;
+        self.alive.truncate(count);
+    }
+
+    pub fn keep_alive(&self) -> usize {
+        self.keep_alive
+    }
+
     /// Add a pane factory to the container.
     ///
     /// The factory is a closure that creates a new pane each time this pane is
@@ -606,21 +935,49 @@ impl<V: View, T: ViewChild<V>> RestartPanes<V, T> {
 
     /// Show the pane with the given `id`, hiding the previously active pane.
     ///
-    /// The pane is recreated fresh from its factory. Returns `true` if the
+    /// The pane is recreated fresh from its factory, unless it is being kept
+    /// alive (see [`RestartPanes::set_keep_alive`]). Returns `true` if the
     /// pane was found and selected, `false` otherwise.
     pub fn select(&mut self, id: &Id<T>) -> bool {
         if Some(id) != self.current_id.as_ref() {
             if let Some(f) = self.panes.get_mut(id) {
-                let pane = f();
-                self.pane = pane;
+                let kept = self
+                    .alive
+                    .iter()
+                    .position(|(alive_id, _)| alive_id == id)
+                    .and_then(|index| self.alive.remove(index));
+                let pane = match kept {
+                    Some((_, pane)) => pane,
+                    None => f(),
+                };
+                let hidden_pane = std::mem::replace(&mut self.pane, pane);
                 self.child.replace(&self.wrapper, &self.pane);
-                self.current_id = Some(id.clone());
+                let hidden = self.current_id.replace(id.clone());
+                if let Some(hidden_id) = &hidden {
+                    if self.keep_alive > 0 {
+                        self.alive.push_front((hidden_id.clone(), hidden_pane));
+                        self.alive.truncate(self.keep_alive);
+                    }
+                }
+                self.events.switched(hidden, Some(id));
                 return true;
             }
         }
         false
     }
 
+    /// Remove the pane factory with the given [`Id`].
+    ///
+    /// ## Note
+    /// If the pane is on display it stays there until another pane is
+    /// selected.
+    ///
+    /// Returns `true` if the pane was found.
+    pub fn remove_by_id(&mut self, id: &Id<T>) -> bool {
+        self.alive.retain(|(alive_id, _)| alive_id != id);
+        self.panes.remove(id).is_some()
+    }
+
     /// Returns a reference to the currently displayed pane.
     pub fn current_pane(&self) -> &T {
         &self.pane
@@ -630,4 +987,27 @@ impl<V: View, T: ViewChild<V>> RestartPanes<V, T> {
     pub fn current_pane_mut(&mut self) -> &mut T {
         &mut self.pane
     }
+
+    /// Turn [`PaneEvent`]s on or off. They are off by default.
+    ///
+    /// ## Note
+    /// Once on, events queue up until they are taken with
+    /// [`RestartPanes::step`].
+    pub fn set_has_events(&mut self, has_events: bool) {
+        self.events.set_is_enabled(has_events);
+    }
+
+    pub fn has_events(&self) -> bool {
+        self.events.is_enabled()
+    }
+
+    /// Await the next [`PaneEvent`].
+    ///
+    /// Each change of selection emits [`PaneEvent::Hidden`] for the old pane,
+    /// which has already been dropped, then [`PaneEvent::Shown`] for the
+    /// freshly built one. Never resolves unless events were turned on with
+    /// [`RestartPanes::set_has_events`].
+    pub async fn step(&mut self) -> PaneEvent<T> {
+        self.events.next().await
+    }
 }
@@ -8,7 +8,6 @@ use futures_lite::FutureExt;
 use mogwai::future::MogwaiFutureExt;
 use mogwai::prelude::*;
 use mogwai::web::prelude::wasm_bindgen_futures;
-use wasm_bindgen::UnwrapThrowExt;
 
 use crate::components::alert::Alert;
 use crate::components::badge::Badge;
@@ -28,6 +27,7 @@ use crate::components::tab::{TabAlignment, TabList, TabListEvent, TabPanel};
 use crate::components::table::library::TableLibraryItem;
 use crate::components::title_bar::TitleBar;
 use crate::components::Flavor;
+use crate::storage::{Key, Persisted};
 
 #[derive(ViewChild)]
 pub struct ProgressBars<V: View> {
@@ -176,6 +176,7 @@ impl<V: View> SectionContent<V> {
                     Step::TabList(TabListEvent::ItemClicked { id, .. }) => {
                         tab_list.select_by_id(&id);
                     }
+                    Step::TabList(TabListEvent::CloseRequested { .. }) => {}
                     Step::TabPanel(_tab_list_event) => {}
                 }
             }
@@ -189,36 +190,26 @@ struct SectionTop<V: View> {
     #[child]
     wrapper: V::Element,
     title: String,
-    enabled: bool,
+    enabled: Persisted<bool>,
     on_click: V::EventListener,
     toggle: Checkbox<V>,
 }
 
 impl<V: View> SectionTop<V> {
-    fn format_enabled_key(title: &str) -> String {
+    fn enabled_key(title: &str) -> Key<bool> {
         let title = title.replace(" ", "-").to_lowercase();
-        format!("section-{title}-enabled")
-    }
-
-    fn read_enabled(title: &str) -> Result<bool, crate::error::Error> {
-        let key = Self::format_enabled_key(title);
-        let maybe_bool: Option<bool> = crate::storage::get_item(&key)?;
-        Ok(maybe_bool.unwrap_or_else(|| {
-            log::info!("{key} was not stored, defaulting");
-            true
-        }))
-    }
-
-    fn write_enabled(&self) -> Result<(), crate::error::Error> {
-        let key = Self::format_enabled_key(&self.title);
-        let enabled = self.enabled;
-        log::info!("writing {key}: {enabled}");
-        crate::storage::set_item(key, &enabled)?;
-        Ok(())
+        Key::with_name(
+            crate::library::STORAGE_NAMESPACE,
+            format!("section-{title}-enabled"),
+        )
     }
 
     fn new(title: &str) -> Self {
-        let enabled = Self::read_enabled(title).unwrap_throw();
+        let key = Self::enabled_key(title);
+        // Before namespacing, the key was stored under its bare name.
+        key.migrate_from(key.name())
+            .unwrap_or_else(|e| log::warn!("could not migrate '{key}': {e}"));
+        let enabled = key.persisted(true);
 
         rsx! {
             let wrapper = span(
@@ -230,7 +221,7 @@ impl<V: View> SectionTop<V> {
                 on:click = on_click
             ) {
                 let toggle = {{
-                    let c = Checkbox::new("", enabled);
+                    let c = Checkbox::new("", *enabled);
                     c.set_style("float", "left");
                     c
                 }}
@@ -251,13 +242,13 @@ impl<V: View> SectionTop<V> {
     async fn step(&mut self) -> bool {
         let _ev = self.on_click.next().await;
 
-        self.enabled = !self.enabled;
+        self.enabled.set(!*self.enabled);
 
-        if self.toggle.is_checked() != self.enabled {
-            self.toggle.set_checked(self.enabled);
+        if self.toggle.is_checked() != *self.enabled {
+            self.toggle.set_checked(*self.enabled);
         }
 
-        self.enabled
+        *self.enabled
     }
 }
 /// A dashed purple section in the Platinum Kit sandbox.
@@ -277,7 +268,7 @@ impl<V: View> Section<V> {
     /// Create a new section with the given title.
     fn new(title: &str, section_content: SectionContent<V>) -> Self {
         let top = SectionTop::new(title);
-        let mut enabled = Proxy::new(top.enabled);
+        let mut enabled = Proxy::new(*top.enabled);
 
         rsx! {
             let wrapper = div(class = "container", style:margin_top = "2em") {
@@ -318,7 +309,6 @@ impl<V: View> Section<V> {
             Step::Content => {}
             Step::Top(enabled) => {
                 log::info!("section {} toggled: {enabled}", self.top.title);
-                self.top.write_enabled().unwrap_throw();
                 self.enabled.set(enabled);
             }
         }
@@ -795,6 +785,7 @@ fn build_dropdowns<V: View>() -> Section<V> {
             match dropdown.step().await {
                 None => dropdown.toggle(),
                 Some(DropdownEvent::ItemClicked { .. }) => dropdown.hide(),
+                Some(DropdownEvent::ItemToggled { .. }) => {}
                 Some(DropdownEvent::Dismissed) => dropdown.hide(),
             }
         }
@@ -999,6 +990,21 @@ fn build_badges<V: View>() -> Section<V> {
         })
         .collect();
 
+    let count_badges: Vec<Badge<V>> = [1, 42, 99, 100, 1000]
+        .into_iter()
+        .map(|count| {
+            let mut badge = Badge::with_count(count, Flavor::Danger);
+            badge.set_pill(true);
+            badge
+        })
+        .collect();
+
+    let dot_badge = {
+        let mut badge = Badge::new("online", Flavor::Success);
+        badge.set_is_dot(true);
+        badge
+    };
+
     rsx! {
         let content = div(class = "panel") {
             div(class = "mb-3") {
@@ -1007,12 +1013,19 @@ fn build_badges<V: View>() -> Section<V> {
                     {&standard_badges}
                 }
             }
-            div() {
+            div(class = "mb-3") {
                 p() { strong() { "Pill" } }
                 div(class = "d-flex flex-wrap gap-2") {
                     {&pill_badges}
                 }
             }
+            div() {
+                p() { strong() { "Counts and dot" } }
+                div(class = "d-flex flex-wrap gap-2 align-items-center") {
+                    {&count_badges}
+                    {&dot_badge}
+                }
+            }
         }
     }
     Section::new("Badges", SectionContent::Any(content))
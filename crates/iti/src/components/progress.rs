@@ -1,21 +1,148 @@
 //! Progress bar component.
 //!
 //! A Bootstrap progress bar with reactive value, flavor, and optional
-//! striped/animated styles.
+//! striped/animated styles, an optional label and an indeterminate mode.
+//!
+//! A progress bar can also update itself from a stream of completion
+//! fractions, or from an async task reporting its progress; see
+//! [`Progress::drive`] and [`Progress::drive_task`].
+use std::{
+    cell::RefCell,
+    future::Future,
+    pin::Pin,
+    rc::Rc,
+    task::{Poll, Waker},
+};
+
+use futures_lite::{FutureExt, Stream, StreamExt};
 use mogwai::prelude::*;
 
 use super::Flavor;
 
+/// Below this value a label doesn't fit inside the bar and is drawn in the
+/// track beside it instead.
+const LABEL_INSIDE_MIN_VALUE: u8 = 20;
+
 struct ProgressState {
     value: u8,
     striped: bool,
     animated: bool,
+    has_label: bool,
+    height: Option<u32>,
+    is_indeterminate: bool,
+}
+
+impl ProgressState {
+    fn bar_class(&self) -> &'static str {
+        if self.is_indeterminate {
+            "progress-bar progress-bar-indeterminate"
+        } else {
+            "progress-bar"
+        }
+    }
+
+    fn bar_width(&self) -> String {
+        if self.is_indeterminate {
+            "30%".to_string()
+        } else {
+            format!("{}%", self.value)
+        }
+    }
+
+    fn track_height(&self) -> String {
+        self.height.map(|h| format!("{h}px")).unwrap_or_default()
+    }
+
+    fn bar_height(&self) -> String {
+        // The bar sits inside the track's 1px border.
+        self.height
+            .map(|h| format!("{}px", h.saturating_sub(2)))
+            .unwrap_or_default()
+    }
+
+    fn is_label_inside(&self) -> bool {
+        self.value >= LABEL_INSIDE_MIN_VALUE || self.is_indeterminate
+    }
+
+    fn inside_label_display(&self) -> &'static str {
+        if self.has_label && self.is_label_inside() {
+            "inline"
+        } else {
+            "none"
+        }
+    }
+
+    fn outside_label_display(&self) -> &'static str {
+        if self.has_label && !self.is_label_inside() {
+            "inline"
+        } else {
+            "none"
+        }
+    }
+}
+
+/// Event emitted by a driven [`Progress`].
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum ProgressEvent {
+    /// The bar moved to a new value.
+    Updated(u8),
+    /// The driving stream or task finished.
+    Finished,
+}
+
+#[derive(Default)]
+struct Reported {
+    fraction: Option<f32>,
+    waker: Option<Waker>,
+}
+
+/// Reports completion from inside a task started with
+/// [`Progress::drive_task`].
+#[derive(Clone, Default)]
+pub struct ProgressReporter {
+    shared: Rc<RefCell<Reported>>,
+}
+
+impl ProgressReporter {
+    /// Report the fraction of work done, from `0.0` to `1.0`.
+    ///
+    /// Only the latest report is kept if several arrive between steps.
+    pub fn report(&self, fraction: f32) {
+        let mut shared = self.shared.borrow_mut();
+        shared.fraction = Some(fraction);
+        if let Some(waker) = shared.waker.take() {
+            waker.wake();
+        }
+    }
+
+    async fn next(&self) -> f32 {
+        futures_lite::future::poll_fn(|cx| {
+            let mut shared = self.shared.borrow_mut();
+            match shared.fraction.take() {
+                Some(fraction) => Poll::Ready(fraction),
+                None => {
+                    shared.waker = Some(cx.waker().clone());
+                    Poll::Pending
+                }
+            }
+        })
+        .await
+    }
 }
 
 /// A Bootstrap progress bar.
 ///
 /// The value ranges from 0 to 100. Setting a value outside this range clamps
 /// it to the nearest bound.
+///
+/// ```ignore
+/// progress.drive(download.fractions());
+/// loop {
+///     if progress.step().await == ProgressEvent::Finished {
+///         break;
+///     }
+/// }
+/// ```
 #[derive(ViewChild, ViewProperties)]
 pub struct Progress<V: View> {
     #[child]
@@ -23,7 +150,10 @@ pub struct Progress<V: View> {
     wrapper: V::Element,
     #[allow(dead_code)]
     bar: V::Element,
+    inside_label: V::Text,
+    outside_label: V::Text,
     state: Proxy<ProgressState>,
+    source: Option<Pin<Box<dyn Stream<Item = f32>>>>,
 }
 
 impl<V: View> Progress<V> {
@@ -33,6 +163,9 @@ impl<V: View> Progress<V> {
             value: clamped,
             striped: false,
             animated: false,
+            has_label: false,
+            height: None,
+            is_indeterminate: false,
         });
 
         rsx! {
@@ -42,18 +175,36 @@ impl<V: View> Progress<V> {
                 aria_valuenow = state(s => format!("{}", s.value)),
                 aria_valuemin = "0",
                 aria_valuemax = "100",
+                style:height = state(s => s.track_height()),
             ) {
                 let bar = div(
-                    class = "progress-bar",
-                    style:width = state(s => format!("{}%", s.value)),
-                ) {}
+                    class = state(s => s.bar_class()),
+                    style:width = state(s => s.bar_width()),
+                    style:height = state(s => s.bar_height()),
+                ) {
+                    span(
+                        class = "progress-label",
+                        style:display = state(s => s.inside_label_display()),
+                    ) {
+                        let inside_label = ""
+                    }
+                }
+                span(
+                    class = "progress-label progress-label-outside",
+                    style:display = state(s => s.outside_label_display()),
+                ) {
+                    let outside_label = ""
+                }
             }
         }
 
         Self {
             wrapper,
             bar,
+            inside_label,
+            outside_label,
             state,
+            source: None,
         }
     }
 
@@ -65,6 +216,98 @@ impl<V: View> Progress<V> {
         self.state.modify(|s| s.value = value.min(100));
     }
 
+    /// Set the value from a fraction of work done, from `0.0` to `1.0`.
+    pub fn set_fraction(&mut self, fraction: f32) {
+        self.set_value((fraction.clamp(0.0, 1.0) * 100.0).round() as u8);
+    }
+
+    /// Update the bar from a stream of completion fractions (`0.0` to
+    /// `1.0`), replacing any previous source.
+    ///
+    /// The bar updates as [`Progress::step`] is awaited.
+    pub fn drive(&mut self, fractions: impl Stream<Item = f32> + 'static) {
+        self.source = Some(Box::pin(fractions));
+    }
+
+    /// Run `task`, updating the bar from the fractions it reports through
+    /// the given [`ProgressReporter`].
+    ///
+    /// The bar fills up when the task completes. Like [`Progress::drive`],
+    /// the task only makes progress while [`Progress::step`] is awaited.
+    pub fn drive_task<F, Fut>(&mut self, task: F)
+    where
+        F: FnOnce(ProgressReporter) -> Fut,
+        Fut: Future<Output = ()> + 'static,
+    {
+        let reporter = ProgressReporter::default();
+        let task: Pin<Box<dyn Future<Output = ()>>> = Box::pin(task(reporter.clone()));
+        let fractions = futures_lite::stream::unfold(Some(task), move |task| {
+            let reporter = reporter.clone();
+            async move {
+                let mut task = task?;
+                let reported = async { Some(reporter.next().await) }
+                    .or(async {
+                        (&mut task).await;
+                        None
+                    })
+                    .await;
+                match reported {
+                    Some(fraction) => Some((fraction, Some(task))),
+                    None => Some((1.0, None)),
+                }
+            }
+        });
+        self.drive(fractions);
+    }
+
+    /// Await the next update from the stream or task set with
+    /// [`Progress::drive`] or [`Progress::drive_task`], applying it to the
+    /// bar.
+    ///
+    /// Never resolves if the bar isn't being driven.
+    pub async fn step(&mut self) -> ProgressEvent {
+        let Some(source) = self.source.as_mut() else {
+            return std::future::pending().await;
+        };
+        match source.next().await {
+            Some(fraction) => {
+                self.set_fraction(fraction);
+                ProgressEvent::Updated(self.state.value)
+            }
+            None => {
+                self.source = None;
+                ProgressEvent::Finished
+            }
+        }
+    }
+
+    /// Show text on the bar, or remove it with `None`.
+    ///
+    /// While the bar is too narrow to hold the label, the label is drawn in
+    /// the empty track beside the bar instead.
+    pub fn set_label(&mut self, label: Option<String>) {
+        let text = label.as_deref().unwrap_or_default();
+        self.inside_label.set_text(text);
+        self.outside_label.set_text(text);
+        self.state.modify(|s| s.has_label = label.is_some());
+    }
+
+    /// Set the height of the track in pixels.
+    pub fn set_height(&mut self, px: u32) {
+        self.state.modify(|s| s.height = Some(px));
+    }
+
+    /// Show a bar sweeping across the track when the total amount of work
+    /// is unknown. The value is kept, and shown again when the progress
+    /// becomes determinate.
+    pub fn set_is_indeterminate(&mut self, is_indeterminate: bool) {
+        self.state.modify(|s| s.is_indeterminate = is_indeterminate);
+    }
+
+    pub fn is_indeterminate(&self) -> bool {
+        self.state.is_indeterminate
+    }
+
     pub fn set_striped(&mut self, striped: bool) {
         self.state.modify(|s| s.striped = striped);
     }
@@ -81,13 +324,15 @@ impl<V: View> Progress<V> {
 
 #[cfg(feature = "library")]
 pub mod library {
-    use std::pin::Pin;
+    use mogwai::future::MogwaiFutureExt;
 
-    use futures_lite::{FutureExt, Stream, StreamExt};
-
-    use crate::components::{
-        button::Button,
-        button_group::{ButtonGroup, ButtonGroupEvent},
+    use crate::{
+        components::{
+            button::Button,
+            button_group::{ButtonGroup, ButtonGroupEvent},
+        },
+        library::{log_event, Knob, KnobEvent, Knobs},
+        util::time::Interval,
     };
 
     use super::*;
@@ -98,92 +343,130 @@ pub mod library {
         pub wrapper: V::Element,
         progress: Progress<V>,
         control_group: ButtonGroup<V>,
+        knobs: Knobs<V>,
+        is_striped: Knob<bool>,
+        is_animated: Knob<bool>,
+        is_indeterminate: Knob<bool>,
         value: u8,
-        is_striped: bool,
-        is_animated: bool,
-        timer: Pin<Box<dyn Stream<Item = ()>>>,
+        is_running_task: bool,
+        timer: Interval,
     }
 
     impl<V: View> Default for ProgressLibraryItem<V> {
         fn default() -> Self {
-            let progress = Progress::new(25);
+            let mut progress = Progress::new(25);
+            progress.set_height(16);
+            progress.set_label(Some("25%".to_string()));
             let mut control_group = ButtonGroup::<V>::default();
             control_group.extend([
                 Button::new("+10", Some(Flavor::Primary)),
                 Button::new("-10", Some(Flavor::Primary)),
-                Button::new("Toggle striped", Some(Flavor::Secondary)),
-                Button::new("Toggle animated", Some(Flavor::Secondary)),
+                Button::new("Run task", Some(Flavor::Success)),
             ]);
             for button in control_group.iter_mut() {
                 button.set_has_icon(false);
             }
 
+            let mut knobs = Knobs::default();
+            let is_striped = knobs.toggle("Striped", false);
+            let is_animated = knobs.toggle("Animated", false);
+            let is_indeterminate = knobs.toggle("Indeterminate", false);
+
             rsx! {
                 let wrapper = div() {
                     div(class = "mb-3") {
                         {&progress}
                     }
                     {&control_group}
+                    {&knobs}
                 }
             }
 
-            let timer = Box::pin(futures_lite::stream::unfold((), |()| async {
-                mogwai::time::wait_millis(500).await;
-                Some(((), ()))
-            }));
+            let timer = Interval::new(500);
 
             Self {
                 wrapper,
                 progress,
                 control_group,
+                knobs,
+                is_striped,
+                is_animated,
+                is_indeterminate,
                 value: 25,
-                is_striped: false,
-                is_animated: false,
+                is_running_task: false,
                 timer,
             }
         }
     }
 
     impl<V: View> ProgressLibraryItem<V> {
+        fn set_value(&mut self) {
+            self.progress.set_value(self.value);
+            self.progress.set_label(Some(format!("{}%", self.value)));
+        }
+
         pub async fn step(&mut self) {
             #[derive(Debug)]
             enum Action {
                 Control(usize),
+                Knob(KnobEvent),
                 Tick,
+                Driven(ProgressEvent),
             }
-            let control = async {
-                let ButtonGroupEvent { index, event: _ } = self.control_group.step().await;
-                Action::Control(index)
-            };
-            let tick = async {
-                self.timer.next().await;
-                Action::Tick
-            };
-            let event = control.or(tick).await;
+            let control = self
+                .control_group
+                .step()
+                .map(|ev: ButtonGroupEvent<V>| Action::Control(ev.index()));
+            let knob = self.knobs.step().map(Action::Knob);
+            let tick = self.timer.step().map(|_| Action::Tick);
+            let driven = self.progress.step().map(Action::Driven);
+            let event = control.or(knob).or(tick).or(driven).await;
             log::info!("event: {event:#?}");
 
             match event {
                 Action::Control(0) => {
                     self.value = self.value.saturating_add(10).min(100);
-                    self.progress.set_value(self.value);
+                    self.set_value();
                 }
                 Action::Control(1) => {
                     self.value = self.value.saturating_sub(10);
-                    self.progress.set_value(self.value);
+                    self.set_value();
                 }
                 Action::Control(2) => {
-                    self.is_striped = !self.is_striped;
-                    self.progress.set_striped(self.is_striped);
-                }
-                Action::Control(3) => {
-                    self.is_animated = !self.is_animated;
-                    self.progress.set_animated(self.is_animated);
+                    self.is_running_task = true;
+                    self.progress.drive_task(|reporter| async move {
+                        // Pretend to do ten chunks of work.
+                        for chunk in 1..=10 {
+                            mogwai::time::wait_millis(300).await;
+                            reporter.report(chunk as f32 / 10.0);
+                        }
+                    });
                 }
                 Action::Control(_) => unreachable!(),
+                Action::Knob(event) => {
+                    if let Some(is_striped) = self.is_striped.changed(&event) {
+                        self.progress.set_striped(is_striped);
+                    }
+                    if let Some(is_animated) = self.is_animated.changed(&event) {
+                        self.progress.set_animated(is_animated);
+                    }
+                    if let Some(is_indeterminate) = self.is_indeterminate.changed(&event) {
+                        self.progress.set_is_indeterminate(is_indeterminate);
+                    }
+                }
+                Action::Driven(ProgressEvent::Updated(value)) => {
+                    self.value = value;
+                    self.set_value();
+                }
+                Action::Driven(ProgressEvent::Finished) => {
+                    log_event("Progress", "Finished");
+                    self.is_running_task = false;
+                }
+                Action::Tick if self.is_running_task => {}
                 Action::Tick => {
                     // Auto-increment wrapping around
                     self.value = if self.value >= 100 { 0 } else { self.value + 1 };
-                    self.progress.set_value(self.value);
+                    self.set_value();
                 }
             }
         }
@@ -279,6 +279,7 @@ impl<V: View> RadioGroup<V> {
 #[cfg(feature = "library")]
 pub mod library {
     use super::*;
+    use crate::library::log_event;
 
     #[derive(ViewChild)]
     pub struct RadioLibraryItem<V: View> {
@@ -354,6 +355,7 @@ pub mod library {
                 "{}: Selected '{}' (index {})",
                 group_name, event.value, event.index
             );
+            log_event("RadioGroup", &msg);
 
             let current_text = self
                 .log
@@ -0,0 +1,200 @@
+//! Viewport breakpoints.
+//!
+//! [`BreakpointWatcher`] tracks which [`Breakpoint`] the viewport is in,
+//! using the same widths as Bootstrap's grid. [`Responsive`] builds on it
+//! to show one of two children depending on the viewport width, e.g. a
+//! collapsed sidebar on phones and a full one on desktops.
+
+use mogwai::{future::race_all, prelude::*};
+
+use super::Breakpoint;
+
+const BREAKPOINTS: [Breakpoint; 5] = [
+    Breakpoint::Sm,
+    Breakpoint::Md,
+    Breakpoint::Lg,
+    Breakpoint::Xl,
+    Breakpoint::Xxl,
+];
+
+/// Tracks the [`Breakpoint`] of the viewport with `matchMedia`.
+pub struct BreakpointWatcher {
+    breakpoint: Proxy<Breakpoint>,
+    queries: Vec<(Breakpoint, web_sys::MediaQueryList)>,
+}
+
+impl Default for BreakpointWatcher {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl BreakpointWatcher {
+    pub fn new() -> Self {
+        let queries = web_sys::window()
+            .map(|window| {
+                BREAKPOINTS
+                    .iter()
+                    .filter_map(|bp| {
+                        let query = format!("(min-width: {}px)", bp.min_width());
+                        Some((*bp, window.match_media(&query).ok()??))
+                    })
+                    .collect::<Vec<_>>()
+            })
+            .unwrap_or_default();
+        let mut watcher = Self {
+            breakpoint: Proxy::new(Breakpoint::Xs),
+            queries,
+        };
+        watcher.breakpoint.set(watcher.measure());
+        watcher
+    }
+
+    fn measure(&self) -> Breakpoint {
+        self.queries
+            .iter()
+            .filter(|(_, query)| query.matches())
+            .map(|(bp, _)| *bp)
+            .max()
+            .unwrap_or(Breakpoint::Xs)
+    }
+
+    /// Returns the current breakpoint.
+    pub fn breakpoint(&self) -> Breakpoint {
+        *self.breakpoint
+    }
+
+    /// Returns `true` if the viewport is at least as wide as `breakpoint`.
+    pub fn is_at_least(&self, breakpoint: Breakpoint) -> bool {
+        *self.breakpoint >= breakpoint
+    }
+
+    /// Returns the reactive breakpoint, for binding in `rsx!`.
+    ///
+    /// ## Note
+    /// It is updated by [`BreakpointWatcher::step`]; setting it yourself
+    /// has no effect on the watcher.
+    pub fn get_proxy_mut(&mut self) -> &mut Proxy<Breakpoint> {
+        &mut self.breakpoint
+    }
+
+    /// Await the viewport crossing into another breakpoint and return it.
+    ///
+    /// A crossing made since the last step, while no one was listening, is
+    /// returned right away.
+    pub async fn step(&mut self) -> Breakpoint {
+        if self.queries.is_empty() {
+            return std::future::pending().await;
+        }
+        loop {
+            let breakpoint = self.measure();
+            if breakpoint != *self.breakpoint {
+                self.breakpoint.set(breakpoint);
+                return breakpoint;
+            }
+            let changes = self.queries.iter().map(|(_, query)| async move {
+                let listener = query.listen("change");
+                let _ = listener.next().await;
+            });
+            race_all(changes).await;
+        }
+    }
+}
+
+/// Shows `narrow` below a breakpoint and `wide` at or above it.
+///
+/// Only the child being shown is in the DOM; both are kept, so each keeps
+/// its state across switches.
+#[derive(ViewChild, ViewProperties)]
+pub struct Responsive<V: View, T> {
+    #[child]
+    #[properties]
+    wrapper: V::Element,
+    child: ProxyChild<V>,
+    watcher: BreakpointWatcher,
+    at: Breakpoint,
+    narrow: T,
+    wide: T,
+}
+
+impl<V: View, T: ViewChild<V>> Responsive<V, T> {
+    pub fn new(at: Breakpoint, narrow: T, wide: T) -> Self {
+        let watcher = BreakpointWatcher::new();
+        let child = if watcher.is_at_least(at) {
+            ProxyChild::new(&wide)
+        } else {
+            ProxyChild::new(&narrow)
+        };
+        rsx! {
+            let wrapper = div(style:display = "contents") {}
+        }
+        wrapper.append_child(&child);
+        Self {
+            wrapper,
+            child,
+            watcher,
+            at,
+            narrow,
+            wide,
+        }
+    }
+
+    /// Returns `true` if the wide child is shown.
+    pub fn is_wide(&self) -> bool {
+        self.watcher.is_at_least(self.at)
+    }
+
+    pub fn get_watcher(&self) -> &BreakpointWatcher {
+        &self.watcher
+    }
+
+    pub fn get_narrow(&self) -> &T {
+        &self.narrow
+    }
+
+    pub fn get_narrow_mut(&mut self) -> &mut T {
+        &mut self.narrow
+    }
+
+    pub fn get_wide(&self) -> &T {
+        &self.wide
+    }
+
+    pub fn get_wide_mut(&mut self) -> &mut T {
+        &mut self.wide
+    }
+
+    /// Returns the child being shown.
+    pub fn current(&self) -> &T {
+        if self.is_wide() {
+            &self.wide
+        } else {
+            &self.narrow
+        }
+    }
+
+    /// Returns the child being shown.
+    pub fn current_mut(&mut self) -> &mut T {
+        if self.is_wide() {
+            &mut self.wide
+        } else {
+            &mut self.narrow
+        }
+    }
+
+    /// Await the viewport crossing into another breakpoint, swap children
+    /// if it crossed the one given to [`Responsive::new`], and return it.
+    pub async fn step(&mut self) -> Breakpoint {
+        let was_wide = self.is_wide();
+        let breakpoint = self.watcher.step().await;
+        let is_wide = self.is_wide();
+        if is_wide != was_wide {
+            if is_wide {
+                self.child.replace(&self.wrapper, &self.wide);
+            } else {
+                self.child.replace(&self.wrapper, &self.narrow);
+            }
+        }
+        breakpoint
+    }
+}
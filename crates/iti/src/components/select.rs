@@ -218,6 +218,7 @@ pub mod library {
     use mogwai::prelude::*;
 
     use super::*;
+    use crate::library::log_event;
 
     /// Gallery sandbox for the [`Select`] component.
     #[derive(ViewChild)]
@@ -263,8 +264,9 @@ pub mod library {
     impl<V: View> SelectLibraryItem<V> {
         pub async fn step(&mut self) {
             let ev = self.select.step().await;
-            self.status_text
-                .set_text(format!("Selected: {} (index {})", ev.value, ev.index));
+            let msg = format!("Selected: {} (index {})", ev.value, ev.index);
+            log_event("Select", &msg);
+            self.status_text.set_text(msg);
         }
     }
 }
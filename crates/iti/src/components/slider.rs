@@ -254,6 +254,7 @@ pub mod library {
     use mogwai::future::MogwaiFutureExt;
 
     use super::*;
+    use crate::library::{log_event, Knob, KnobEvent, Knobs};
 
     #[derive(ViewChild)]
     pub struct SliderLibraryItem<V: View> {
@@ -266,8 +267,8 @@ pub mod library {
         label_b: V::Text,
         label_c: V::Text,
         reset_click: V::EventListener,
-        toggle_click: V::EventListener,
-        disabled: bool,
+        knobs: Knobs<V>,
+        is_disabled: Knob<bool>,
     }
 
     impl<V: View> Default for SliderLibraryItem<V> {
@@ -276,6 +277,9 @@ pub mod library {
             let slider_b = Slider::new(0.0, 1.0, 0.01, 0.5);
             let slider_c = Slider::new(-50.0, 50.0, 5.0, 0.0);
 
+            let mut knobs = Knobs::default();
+            let is_disabled = knobs.toggle("Disabled", false);
+
             rsx! {
                 let wrapper = div() {
                     div(class = "mb-3") {
@@ -301,22 +305,14 @@ pub mod library {
                             {&slider_c}
                         }
                     }
-                    div(class = "btn-group") {
-                        button(
-                            type = "button",
-                            class = "btn btn-sm btn-outline-secondary",
-                            on:click = reset_click,
-                        ) {
-                            "Reset"
-                        }
-                        button(
-                            type = "button",
-                            class = "btn btn-sm btn-outline-secondary",
-                            on:click = toggle_click,
-                        ) {
-                            "Toggle disabled"
-                        }
+                    button(
+                        type = "button",
+                        class = "btn btn-sm btn-outline-secondary",
+                        on:click = reset_click,
+                    ) {
+                        "Reset"
                     }
+                    {&knobs}
                 }
             }
 
@@ -329,8 +325,8 @@ pub mod library {
                 label_b,
                 label_c,
                 reset_click,
-                toggle_click,
-                disabled: false,
+                knobs,
+                is_disabled,
             }
         }
     }
@@ -346,7 +342,7 @@ pub mod library {
                 SliderB(f64),
                 SliderC(f64),
                 Reset,
-                Toggle,
+                Knob(KnobEvent),
             }
 
             let ev = self
@@ -356,17 +352,20 @@ pub mod library {
                 .or(self.slider_b.step().map(|e| Action::SliderB(e.value)))
                 .or(self.slider_c.step().map(|e| Action::SliderC(e.value)))
                 .or(self.reset_click.next().map(|_| Action::Reset))
-                .or(self.toggle_click.next().map(|_| Action::Toggle))
+                .or(self.knobs.step().map(Action::Knob))
                 .await;
 
             match ev {
                 Action::SliderA(v) => {
+                    log_event("Slider", format!("Integer: {v}"));
                     self.label_a.set_text(Self::format_value(v, 0));
                 }
                 Action::SliderB(v) => {
+                    log_event("Slider", format!("Float: {v}"));
                     self.label_b.set_text(Self::format_value(v, 2));
                 }
                 Action::SliderC(v) => {
+                    log_event("Slider", format!("Signed: {v}"));
                     self.label_c.set_text(Self::format_value(v, 0));
                 }
                 Action::Reset => {
@@ -377,9 +376,11 @@ pub mod library {
                     self.label_b.set_text("0.50");
                     self.label_c.set_text("0");
                 }
-                Action::Toggle => {
-                    self.disabled = !self.disabled;
-                    if self.disabled {
+                Action::Knob(event) => {
+                    let Some(is_disabled) = self.is_disabled.changed(&event) else {
+                        return;
+                    };
+                    if is_disabled {
                         self.slider_a.disable();
                         self.slider_b.disable();
                         self.slider_c.disable();
@@ -1,14 +1,38 @@
-//! Page tabs (Bootstrap nav-tabs).
+//! Page tabs (Bootstrap nav-tabs, nav-pills and nav-underline).
 use std::{collections::HashMap, future::Future};
 
 use futures_lite::FutureExt;
-use mogwai::{future::MogwaiFutureExt, prelude::*};
+use mogwai::{
+    future::MogwaiFutureExt,
+    prelude::*,
+    web::{WebElement, WebEvent},
+};
+use wasm_bindgen::JsCast;
 
 use crate::{
-    components::pane::Panes,
+    components::{
+        icon::{Icon, IconGlyph, IconSize},
+        pane::Panes,
+    },
     id::{Id, IdPool},
 };
 
+struct TabItemState {
+    is_active: bool,
+    is_disabled: bool,
+}
+
+impl TabItemState {
+    fn class(&self) -> &str {
+        match (self.is_active, self.is_disabled) {
+            (false, false) => "nav-link",
+            (true, false) => "nav-link active",
+            (false, true) => "nav-link disabled",
+            (true, true) => "nav-link active disabled",
+        }
+    }
+}
+
 /// A single tab within a [`TabList`].
 #[derive(ViewChild, ViewProperties)]
 pub struct TabListItem<V: View, T> {
@@ -17,25 +41,40 @@ pub struct TabListItem<V: View, T> {
     li: V::Element,
     a: V::Element,
     on_click: V::EventListener,
+    close: V::Element,
+    _close_icon: Icon<V>,
     inner: T,
-    is_active: Proxy<bool>,
+    state: Proxy<TabItemState>,
+    is_closable: Proxy<bool>,
     id: Id<T>,
 }
 
 impl<V: View, T: ViewChild<V>> TabListItem<V, T> {
     pub fn new(id: Id<T>, inner: T) -> Self {
-        let mut is_active = Proxy::new(false);
+        let mut state = Proxy::new(TabItemState {
+            is_active: false,
+            is_disabled: false,
+        });
+        let mut is_closable = Proxy::new(false);
+        let close_icon = Icon::new(IconGlyph::Xmark, IconSize::Xs);
         rsx! {
             let li = li(class = "nav-item", style:cursor = "pointer") {
                 let a = a(
-                    class = is_active(active => if *active {
-                        "nav-link active"
-                    } else {
-                        "nav-link"
-                    }),
+                    class = state(s => s.class()),
                     on:click = on_click,
                 ) {
                     {&inner}
+                    let close = span(
+                        class = "nav-link-close",
+                        aria_label = "Close",
+                        style:display = is_closable(closable => if *closable {
+                            ""
+                        } else {
+                            "none"
+                        }),
+                    ) {
+                        {&close_icon}
+                    }
                 }
             }
         }
@@ -44,12 +83,70 @@ impl<V: View, T: ViewChild<V>> TabListItem<V, T> {
             li,
             a,
             on_click,
+            close,
+            _close_icon: close_icon,
             inner,
-            is_active,
+            state,
+            is_closable,
             id,
         }
     }
 
+    /// Returns `true` if this is the selected tab.
+    pub fn is_active(&self) -> bool {
+        self.state.is_active
+    }
+
+    fn set_is_active(&mut self, is_active: bool) {
+        self.state.modify(|s| s.is_active = is_active);
+    }
+
+    /// Disable or enable this tab.
+    ///
+    /// Clicks on a disabled tab are ignored by [`TabList::step`].
+    pub fn set_is_disabled(&mut self, is_disabled: bool) {
+        self.state.modify(|s| s.is_disabled = is_disabled);
+        if is_disabled {
+            self.a.set_property("aria-disabled", "true");
+        } else {
+            self.a.remove_property("aria-disabled");
+        }
+    }
+
+    pub fn is_disabled(&self) -> bool {
+        self.state.is_disabled
+    }
+
+    /// Show or hide this tab's close icon.
+    ///
+    /// Clicking the icon makes [`TabList::step`] return
+    /// [`TabListEvent::CloseRequested`] instead of
+    /// [`TabListEvent::ItemClicked`].
+    pub fn set_is_closable(&mut self, is_closable: bool) {
+        self.is_closable.set(is_closable);
+    }
+
+    pub fn is_closable(&self) -> bool {
+        *self.is_closable
+    }
+
+    /// Returns `true` if the click `event` landed on the close icon.
+    fn is_close_click(&self, event: &V::Event) -> bool {
+        if !*self.is_closable {
+            return false;
+        }
+        let Some(target) = event
+            .dyn_ev(|e: &web_sys::Event| e.target())
+            .flatten()
+            .and_then(|t| t.dyn_into::<web_sys::Node>().ok())
+        else {
+            return false;
+        };
+        self.close
+            .dyn_el(|el: &web_sys::Node| el.contains(Some(&target)))
+            .unwrap_or_default()
+    }
+
     pub fn inner(&self) -> &T {
         &self.inner
     }
@@ -67,6 +164,10 @@ pub enum TabListEvent<V: View, T> {
         index: usize,
         event: V::Event,
     },
+    /// The close icon of a closable tab was clicked.
+    ///
+    /// The tab is not removed automatically, see [`TabList::close`].
+    CloseRequested { id: Id<T>, index: usize },
 }
 
 /// Result of removing an item from the [`TabList`].
@@ -158,30 +259,253 @@ pub enum TabAlignment {
     End,
 }
 
-/// A nav-tabs component.
+/// Visual style of a [`TabList`].
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum TabStyle {
+    /// Folder tabs (`nav-tabs`).
+    #[default]
+    Tabs,
+    /// Pill buttons (`nav-pills`).
+    Pills,
+    /// Underlined labels (`nav-underline`).
+    Underline,
+}
+
+impl TabStyle {
+    fn class(&self) -> &str {
+        match self {
+            TabStyle::Tabs => "nav-tabs",
+            TabStyle::Pills => "nav-pills",
+            TabStyle::Underline => "nav-underline",
+        }
+    }
+}
+
+/// How the tabs of a [`TabList`] share the available width.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum TabLayout {
+    /// Each tab is as wide as its label.
+    #[default]
+    Natural,
+    /// Tabs grow to fill the width, in proportion to their labels
+    /// (`nav-fill`).
+    Fill,
+    /// Tabs fill the width, all equally wide (`nav-justified`).
+    Justified,
+}
+
+/// What a [`TabList`] does with tabs that don't fit in its width.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum TabOverflow {
+    /// Tabs wrap onto another row.
+    #[default]
+    Wrap,
+    /// Tabs stay on one row that scrolls sideways. Chevron buttons appear at
+    /// either end while the tabs overflow.
+    Scroll,
+}
+
+struct TabListState {
+    style: TabStyle,
+    layout: TabLayout,
+    overflow: TabOverflow,
+    is_vertical: bool,
+    is_card_header: bool,
+}
+
+impl TabListState {
+    fn class(&self) -> String {
+        let layout = match self.layout {
+            TabLayout::Natural => "",
+            TabLayout::Fill => " nav-fill",
+            TabLayout::Justified => " nav-justified",
+        };
+        let overflow = match self.overflow {
+            TabOverflow::Wrap => "",
+            TabOverflow::Scroll => " nav-scroll",
+        };
+        let vertical = if self.is_vertical { " flex-column" } else { "" };
+        let card_header = if self.is_card_header {
+            " card-header-tabs"
+        } else {
+            ""
+        };
+        format!(
+            "nav {}{layout}{overflow}{vertical}{card_header}",
+            self.style.class()
+        )
+    }
+}
+
+/// One of the chevron buttons at either end of a scrolling [`TabList`].
+struct ScrollButton<V: View> {
+    li: V::Element,
+    button: V::Element,
+    click: V::EventListener,
+    _icon: Icon<V>,
+}
+
+impl<V: View> ScrollButton<V> {
+    fn new(glyph: IconGlyph, label: &str) -> Self {
+        let icon = Icon::new(glyph, IconSize::Sm);
+        rsx! {
+            let li = li(class = "nav-scroll-button", style:display = "none") {
+                let button = button(
+                    type = "button",
+                    aria_label = label,
+                    on:click = click,
+                ) {
+                    {&icon}
+                }
+            }
+        }
+        Self {
+            li,
+            button,
+            click,
+            _icon: icon,
+        }
+    }
+
+    fn set_is_shown(&self, is_shown: bool) {
+        if is_shown {
+            self.li.remove_style("display");
+        } else {
+            self.li.set_style("display", "none");
+        }
+    }
+
+    fn set_is_disabled(&self, is_disabled: bool) {
+        if is_disabled {
+            self.button.set_property("disabled", "");
+        } else {
+            self.button.remove_property("disabled");
+        }
+    }
+
+    fn width(&self) -> i32 {
+        self.li
+            .dyn_el(|el: &web_sys::HtmlElement| el.offset_width())
+            .unwrap_or_default()
+    }
+}
+
+/// A nav component: folder tabs by default, or pills or underlined labels.
+///
+/// See [`TabList::set_tab_style`], [`TabList::set_layout`],
+/// [`TabList::set_overflow`] and [`TabList::set_is_vertical`].
 #[derive(ViewChild, ViewProperties)]
 pub struct TabList<V: View, T> {
     #[child]
     #[properties]
     ul: V::Element,
+    state: Proxy<TabListState>,
     entries: Vec<TabEntry<V, T>>,
     id_pool: IdPool<T>,
+    is_closable: bool,
+    scroll_back: ScrollButton<V>,
+    scroll_forward: ScrollButton<V>,
+    scrolled: V::EventListener,
+    resized: V::EventListener,
 }
 
 impl<V: View, T: ViewChild<V>> Default for TabList<V, T> {
     fn default() -> Self {
+        let mut state = Proxy::new(TabListState {
+            style: TabStyle::Tabs,
+            layout: TabLayout::Natural,
+            overflow: TabOverflow::Wrap,
+            is_vertical: false,
+            is_card_header: false,
+        });
+        let scroll_back = ScrollButton::new(IconGlyph::ChevronLeft, "Scroll tabs back");
+        let scroll_forward = ScrollButton::new(IconGlyph::ChevronRight, "Scroll tabs forward");
+        // The chevrons stay the first and last children, around the entries.
         rsx! {
-            let ul = ul(class = "nav nav-tabs") {}
+            let ul = ul(
+                class = state(s => s.class()),
+                on:scroll = scrolled,
+                window:resize = resized,
+            ) {
+                {&scroll_back.li}
+                {&scroll_forward.li}
+            }
         }
         Self {
             ul,
+            state,
             entries: vec![],
             id_pool: Default::default(),
+            is_closable: false,
+            scroll_back,
+            scroll_forward,
+            scrolled,
+            resized,
         }
     }
 }
 
 impl<V: View, T: ViewChild<V>> TabList<V, T> {
+    pub fn set_tab_style(&mut self, style: TabStyle) {
+        self.state.modify(|s| s.style = style);
+    }
+
+    pub fn tab_style(&self) -> TabStyle {
+        self.state.style
+    }
+
+    pub fn set_layout(&mut self, layout: TabLayout) {
+        self.state.modify(|s| s.layout = layout);
+    }
+
+    pub fn layout(&self) -> TabLayout {
+        self.state.layout
+    }
+
+    /// Wrap or scroll tabs that don't fit in the list's width.
+    ///
+    /// With [`TabOverflow::Scroll`] the chevrons are shown and hidden from
+    /// [`TabList::step`], as tabs are added and the window is resized.
+    pub fn set_overflow(&mut self, overflow: TabOverflow) {
+        self.state.modify(|s| s.overflow = overflow);
+        self.update_overflow();
+    }
+
+    pub fn overflow(&self) -> TabOverflow {
+        self.state.overflow
+    }
+
+    /// Stack the tabs vertically (`flex-column`), e.g. for a sidebar.
+    pub fn set_is_vertical(&mut self, is_vertical: bool) {
+        self.state.modify(|s| s.is_vertical = is_vertical);
+    }
+
+    pub fn is_vertical(&self) -> bool {
+        self.state.is_vertical
+    }
+
+    /// Style the list for a card header. See
+    /// [`Card::set_header_tabs`](super::card::Card::set_header_tabs).
+    pub(crate) fn set_is_card_header(&mut self, is_card_header: bool) {
+        self.state.modify(|s| s.is_card_header = is_card_header);
+    }
+
+    /// Give every tab a close icon, including tabs added later.
+    ///
+    /// See [`TabListEvent::CloseRequested`] and [`TabList::close`].
+    pub fn set_is_closable(&mut self, is_closable: bool) {
+        self.is_closable = is_closable;
+        for entry in self.entries.iter_mut() {
+            if let Some(item) = entry.as_item_mut() {
+                item.set_is_closable(is_closable);
+            }
+        }
+    }
+
+    pub fn is_closable(&self) -> bool {
+        self.is_closable
+    }
+
     /// Return the number of tabs (spacers are not counted).
     pub fn len(&self) -> usize {
         self.entries
@@ -202,6 +526,16 @@ impl<V: View, T: ViewChild<V>> TabList<V, T> {
         self.entries.iter().filter_map(|e| e.as_item()).nth(index)
     }
 
+    /// Return a mutable reference to the [`TabListItem`] at the given tab index.
+    ///
+    /// The index counts only tab items, not spacers.
+    pub fn get_mut(&mut self, index: usize) -> Option<&mut TabListItem<V, T>> {
+        self.entries
+            .iter_mut()
+            .filter_map(|e| e.as_item_mut())
+            .nth(index)
+    }
+
     /// Iterator over all tab items (spacers are skipped).
     pub fn iter(&self) -> impl Iterator<Item = &TabListItem<V, T>> {
         self.entries.iter().filter_map(|e| e.as_item())
@@ -210,10 +544,10 @@ impl<V: View, T: ViewChild<V>> TabList<V, T> {
     /// Push a new tab and return a unique identifier for that tab.
     pub fn push(&mut self, item: T) -> Id<T> {
         let id = self.id_pool.get_id();
-        let item = TabListItem::new(id.clone(), item);
+        let mut item = TabListItem::new(id.clone(), item);
+        item.set_is_closable(self.is_closable);
         let entry = TabEntry::Item(item);
-        self.ul.append_child(&entry);
-        self.entries.push(entry);
+        self.append_entry(entry);
         if self.len() == 1 {
             self.select_by_index(0);
         }
@@ -247,7 +581,8 @@ impl<V: View, T: ViewChild<V>> TabList<V, T> {
     /// bounds the tab is appended to the end.
     pub fn insert(&mut self, index: usize, item: T) -> Id<T> {
         let id = self.id_pool.get_id();
-        let item = TabListItem::new(id.clone(), item);
+        let mut item = TabListItem::new(id.clone(), item);
+        item.set_is_closable(self.is_closable);
         let entry = TabEntry::Item(item);
 
         // Find the entry-vec position of the Nth tab item.
@@ -264,8 +599,7 @@ impl<V: View, T: ViewChild<V>> TabList<V, T> {
                 .insert_child_before(&entry, Some(self.entries[pos].element()));
             self.entries.insert(pos, entry);
         } else {
-            self.ul.append_child(&entry);
-            self.entries.push(entry);
+            self.append_entry(entry);
         }
         id
     }
@@ -277,7 +611,7 @@ impl<V: View, T: ViewChild<V>> TabList<V, T> {
         for (entry_i, entry) in self.entries.iter().enumerate() {
             if let Some(item) = entry.as_item() {
                 if &item.id == id {
-                    found = Some((entry_i, tab_index, *item.is_active));
+                    found = Some((entry_i, tab_index, item.is_active()));
                     break;
                 }
                 tab_index += 1;
@@ -300,11 +634,44 @@ impl<V: View, T: ViewChild<V>> TabList<V, T> {
         }
     }
 
+    /// Remove the tab at the given tab index, the way an editor closes a tab.
+    ///
+    /// If the removed tab was selected, its right neighbour is selected in its
+    /// place — or its left neighbour if it was the last tab.
+    ///
+    /// Returns the removal and the [`Id`] of the newly selected tab, if the
+    /// selection moved. Returns `None` if the given `index` was out of bounds.
+    pub fn close(&mut self, index: usize) -> Option<(TabItemRemoval<T>, Option<Id<T>>)> {
+        let id = self.get(index)?.id.clone();
+        let removal = self.remove_by_id(&id)?;
+        let selected = if removal.was_selected && !self.is_empty() {
+            self.select_by_index(removal.index.min(self.len() - 1))
+        } else {
+            None
+        };
+        Some((removal, selected))
+    }
+
+    /// Disable or enable the tab at the given tab index.
+    ///
+    /// Clicks on a disabled tab are ignored by [`TabList::step`]. Does nothing
+    /// if the given `index` was out of bounds.
+    pub fn set_disabled(&mut self, index: usize, is_disabled: bool) {
+        if let Some(item) = self.get_mut(index) {
+            item.set_is_disabled(is_disabled);
+        }
+    }
+
+    /// Return the tab index of the selected tab, if any.
+    pub fn selected(&self) -> Option<usize> {
+        self.iter().position(|item| item.is_active())
+    }
+
     /// Deselect all tabs.
     pub fn deselect_all(&mut self) {
         for entry in self.entries.iter_mut() {
             if let Some(item) = entry.as_item_mut() {
-                item.is_active.set(false);
+                item.set_is_active(false);
             }
         }
     }
@@ -319,7 +686,7 @@ impl<V: View, T: ViewChild<V>> TabList<V, T> {
         let mut tab_i = 0;
         for entry in self.entries.iter_mut() {
             if let Some(item) = entry.as_item_mut() {
-                item.is_active.set(tab_i == index);
+                item.set_is_active(tab_i == index);
                 if tab_i == index {
                     id = Some(item.id.clone());
                 }
@@ -333,7 +700,8 @@ impl<V: View, T: ViewChild<V>> TabList<V, T> {
     pub fn select_by_id(&mut self, id: &Id<T>) {
         for entry in self.entries.iter_mut() {
             if let Some(item) = entry.as_item_mut() {
-                item.is_active.set(&item.id == id);
+                let is_active = &item.id == id;
+                item.set_is_active(is_active);
             }
         }
     }
@@ -341,9 +709,7 @@ impl<V: View, T: ViewChild<V>> TabList<V, T> {
     /// Push a spacer onto the end of the tab bar.
     pub fn push_spacer(&mut self) {
         let spacer = TabSpacer::new();
-        let entry = TabEntry::Spacer(spacer);
-        self.ul.append_child(&entry);
-        self.entries.push(entry);
+        self.append_entry(TabEntry::Spacer(spacer));
     }
 
     /// Insert a spacer before the tab identified by `tab_id`.
@@ -380,8 +746,7 @@ impl<V: View, T: ViewChild<V>> TabList<V, T> {
                     .insert_child_before(&entry, Some(next_entry.element()));
                 self.entries.insert(insert_pos, entry);
             } else {
-                self.ul.append_child(&entry);
-                self.entries.push(entry);
+                self.append_entry(entry);
             }
         }
     }
@@ -398,15 +763,67 @@ impl<V: View, T: ViewChild<V>> TabList<V, T> {
         });
     }
 
+    /// Add an entry to the end of the list, before the forward chevron.
+    fn append_entry(&mut self, entry: TabEntry<V, T>) {
+        self.ul
+            .insert_child_before(&entry, Some(&self.scroll_forward.li));
+        self.entries.push(entry);
+    }
+
+    /// Show the chevrons if the tabs overflow, and disable each one once the
+    /// list is scrolled all the way to its end.
+    fn update_overflow(&self) {
+        // Shown chevrons take up room in the strip, so leave them out when
+        // measuring the tabs.
+        let chevrons = self.scroll_back.width() + self.scroll_forward.width();
+        let extent = self.ul.dyn_el(|el: &web_sys::Element| {
+            (el.scroll_left(), el.scroll_width(), el.client_width())
+        });
+        match extent {
+            Some((left, width, visible))
+                if self.state.overflow == TabOverflow::Scroll && width - chevrons > visible =>
+            {
+                self.scroll_back.set_is_shown(true);
+                self.scroll_forward.set_is_shown(true);
+                self.scroll_back.set_is_disabled(left <= 0);
+                self.scroll_forward
+                    .set_is_disabled(left + visible >= width - 1);
+            }
+            _ => {
+                self.scroll_back.set_is_shown(false);
+                self.scroll_forward.set_is_shown(false);
+            }
+        }
+    }
+
+    /// Scroll the list by most of its visible width, back (`-1.0`) or
+    /// forward (`1.0`).
+    fn scroll_by_page(&self, direction: f64) {
+        self.ul.dyn_el(|el: &web_sys::Element| {
+            let page = f64::from(el.client_width()) * 0.8;
+            el.scroll_by_with_x_and_y(direction * page, 0.0);
+        });
+    }
+
     fn item_events(&self) -> impl Future<Output = TabListEvent<V, T>> + '_ {
         let mut race = std::future::pending().boxed_local();
         for (index, item) in self.iter().enumerate() {
+            if item.is_disabled() {
+                continue;
+            }
             let click = async move {
                 let event = item.on_click.next().await;
-                TabListEvent::ItemClicked {
-                    id: item.id.clone(),
-                    index,
-                    event,
+                if item.is_close_click(&event) {
+                    TabListEvent::CloseRequested {
+                        id: item.id.clone(),
+                        index,
+                    }
+                } else {
+                    TabListEvent::ItemClicked {
+                        id: item.id.clone(),
+                        index,
+                        event,
+                    }
                 }
             };
             race = race.or(click).boxed_local();
@@ -414,8 +831,29 @@ impl<V: View, T: ViewChild<V>> TabList<V, T> {
         race
     }
 
+    /// Await the next click on a tab.
+    ///
+    /// Scrolling and resizing are handled here too, without returning.
     pub async fn step(&self) -> TabListEvent<V, T> {
-        self.item_events().await
+        enum Overflow {
+            Back,
+            Forward,
+            Changed,
+        }
+        loop {
+            self.update_overflow();
+            let back = self.scroll_back.click.next().map(|_| Overflow::Back);
+            let forward = self.scroll_forward.click.next().map(|_| Overflow::Forward);
+            let scrolled = self.scrolled.next().map(|_| Overflow::Changed);
+            let resized = self.resized.next().map(|_| Overflow::Changed);
+            let overflow = back.or(forward).or(scrolled).or(resized);
+            match self.item_events().map(Ok).or(overflow.map(Err)).await {
+                Ok(ev) => return ev,
+                Err(Overflow::Back) => self.scroll_by_page(-1.0),
+                Err(Overflow::Forward) => self.scroll_by_page(1.0),
+                Err(Overflow::Changed) => {}
+            }
+        }
     }
 }
 
@@ -522,7 +960,7 @@ impl<V: View, T: ViewChild<V>, P: ViewChild<V>> TabPanel<V, T, P> {
     /// Returns a reference to the active pane, if any.
     pub fn get_active_pane(&self) -> Option<&P> {
         self.tabs.iter().find_map(|tab| {
-            tab.is_active.then_some(())?;
+            tab.is_active().then_some(())?;
             let pane_id = self.tabs_to_panes.get(&tab.id)?;
             self.panes.get_pane(pane_id)
         })
@@ -534,7 +972,7 @@ impl<V: View, T: ViewChild<V>, P: ViewChild<V>> TabPanel<V, T, P> {
             .tabs
             .iter()
             .find_map(|tab| {
-                tab.is_active.then_some(())?;
+                tab.is_active().then_some(())?;
                 self.tabs_to_panes.get(&tab.id)
             })?
             .clone();
@@ -554,14 +992,8 @@ impl<V: View, T: ViewChild<V>, P: ViewChild<V>> TabPanel<V, T, P> {
     /// Step the tabs.
     pub async fn step(&mut self) -> TabListEvent<V, T> {
         let ev = self.tabs.step().await;
-        match &ev {
-            TabListEvent::ItemClicked {
-                id,
-                index: _,
-                event: _,
-            } => {
-                self.select(id);
-            }
+        if let TabListEvent::ItemClicked { id, .. } = &ev {
+            self.select(id);
         }
         ev
     }
@@ -592,10 +1024,23 @@ impl<V: View, T: ViewChild<V>, P: ViewChild<V>> TabPanel<V, T, P> {
 #[cfg(feature = "library")]
 pub mod library {
 
-    use crate::components::{pane::RestartPanes, widget::Widget};
+    use futures_lite::{Stream, StreamExt};
+
+    use crate::{
+        components::{pane::RestartPanes, widget::Widget},
+        util::time::Interval,
+    };
 
     use super::*;
 
+    /// An endless stream ticking once a second, for the panes' widgets.
+    fn every_second() -> impl Stream<Item = ()> {
+        futures_lite::stream::unfold(Interval::new(1000), |interval| async move {
+            interval.step().await;
+            Some(((), interval))
+        })
+    }
+
     #[derive(ViewChild)]
     pub struct TabListLibraryItem<V: View> {
         #[child]
@@ -637,19 +1082,14 @@ pub mod library {
                         }
                     }
                 }
+                let mut loops = 0u32;
                 Widget::new(
                     wrapper,
-                    futures_lite::stream::unfold(
-                        (count_text, loop_text, 0.0f32, 0u32),
-                        |(count_text, loop_text, mut count, mut loops)| async move {
-                            let elapsed = mogwai::time::wait_millis(1000).await as f32;
-                            count += elapsed as f32 / 1000.0;
-                            loops += 1;
-                            count_text.set_text(format!("{count} seconds, {loops} loops"));
-                            loop_text.set_text(format!("{loops} loops have run"));
-                            Some(((), (count_text, loop_text, count, loops)))
-                        },
-                    ),
+                    every_second().map(move |()| {
+                        loops += 1;
+                        count_text.set_text(format!("{loops} seconds"));
+                        loop_text.set_text(format!("{loops} loops have run"));
+                    }),
                 )
             });
             item.pane_ids.push(id_0);
@@ -669,18 +1109,13 @@ pub mod library {
                     }
                 }
 
+                let mut loops = 0u32;
                 Widget::new(
                     html,
-                    futures_lite::stream::unfold(
-                        (count_text, 0f32, 0u32),
-                        |(count_text, mut count, mut loops)| async move {
-                            let elapsed = mogwai::time::wait_millis(1000).await as f32;
-                            count += elapsed as f32 / 1000.0;
-                            loops += 1;
-                            count_text.set_text(format!("{count} seconds, {loops} loops"));
-                            Some(((), (count_text, count, loops)))
-                        },
-                    ),
+                    every_second().map(move |()| {
+                        loops += 1;
+                        count_text.set_text(format!("{loops} seconds"));
+                    }),
                 )
             });
             item.pane_ids.push(id_1);
@@ -347,28 +347,28 @@ impl<V: View, T> Table<V, T> {
         // Create sort arrow column
         let mut sort_order = Proxy::new(SortOrder::Ascending);
 
-        // Resolve sort-arrow image URLs. With `embed-assets`, the SVG
+        // Resolve sort-arrow image URLs. With `embed-css`, the SVG
         // bytes are compiled in and exposed as Blob URLs (memoized in
         // `assets::embedded`). Without the feature we fall back to the
         // relative path served by Trunk's `copy-dir` directive.
-        let asc_src: String = if cfg!(feature = "embed-assets") {
-            #[cfg(feature = "embed-assets")]
+        let asc_src: String = if cfg!(feature = "embed-css") {
+            #[cfg(feature = "embed-css")]
             {
                 crate::assets::embedded::blob_url_for_table_sort_asc()
             }
-            #[cfg(not(feature = "embed-assets"))]
+            #[cfg(not(feature = "embed-css"))]
             {
                 unreachable!()
             }
         } else {
             "svg/table-sort-asc.svg".to_string()
         };
-        let desc_src: String = if cfg!(feature = "embed-assets") {
-            #[cfg(feature = "embed-assets")]
+        let desc_src: String = if cfg!(feature = "embed-css") {
+            #[cfg(feature = "embed-css")]
             {
                 crate::assets::embedded::blob_url_for_table_sort_desc()
             }
-            #[cfg(not(feature = "embed-assets"))]
+            #[cfg(not(feature = "embed-css"))]
             {
                 unreachable!()
             }
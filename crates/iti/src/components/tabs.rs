@@ -0,0 +1,300 @@
+//! Tabs wired to panes.
+//!
+//! [`Tabs`] owns a [`TabList`] and a pane container and keeps the two in
+//! sync, so clicking a tab shows its pane.
+use std::collections::HashMap;
+
+use mogwai::prelude::*;
+use snafu::prelude::*;
+
+use crate::{
+    components::{
+        pane::{Panes, RestartPanes, Transition},
+        tab::{TabList, TabListEvent},
+    },
+    id::Id,
+};
+
+/// All tabs errors.
+#[derive(Debug, Snafu)]
+pub enum Error {
+    #[snafu(display("Tabs created with new_restarting need a pane factory, see add_tab_with"))]
+    NeedsFactory,
+}
+
+/// Event emitted by [`Tabs::step`].
+pub enum TabsEvent<T> {
+    /// A tab was clicked and its pane is now shown.
+    Selected { id: Id<T>, index: usize },
+    /// A closable tab was closed and its pane removed.
+    ///
+    /// If it was the selected tab, a neighbouring tab is now selected.
+    Closed { id: Id<T>, index: usize },
+}
+
+impl<T> TabsEvent<T> {
+    /// Returns the tab index associated with this event.
+    pub fn index(&self) -> usize {
+        match self {
+            TabsEvent::Selected { index, .. } | TabsEvent::Closed { index, .. } => *index,
+        }
+    }
+}
+
+enum TabPanes<V: View, T> {
+    Static(Panes<V, T>),
+    Restart(RestartPanes<V, T>),
+}
+
+/// A [`TabList`] above the panes it switches between.
+///
+/// Each tab is a text label paired with a pane, identified by the pane's
+/// [`Id`]. The panes live in a [`Panes`] (see [`Tabs::new`] and
+/// [`Tabs::new_retained`]) or a [`RestartPanes`] (see
+/// [`Tabs::new_restarting`]).
+#[derive(ViewChild, ViewProperties)]
+pub struct Tabs<V: View, T> {
+    #[child]
+    #[properties]
+    wrapper: V::Element,
+    tab_list: TabList<V, V::Element>,
+    panes: TabPanes<V, T>,
+    tabs_to_panes: HashMap<Id<V::Element>, Id<T>>,
+}
+
+impl<V: View, T: ViewChild<V>> Tabs<V, T> {
+    fn with_panes(f: impl FnOnce(V::Element) -> TabPanes<V, T>) -> Self {
+        rsx! {
+            let wrapper = div(class = "tabs") {
+                let tab_list = {TabList::default()}
+                let content = div(class = "tab-content") {}
+            }
+        }
+        Self {
+            wrapper,
+            tab_list,
+            panes: f(content),
+            tabs_to_panes: HashMap::new(),
+        }
+    }
+
+    /// Create tabs whose panes swap in and out of the DOM.
+    ///
+    /// `default_pane` is shown until a tab is added. See [`Panes::new`].
+    pub fn new(default_pane: T) -> Self {
+        Self::with_panes(|content| TabPanes::Static(Panes::new(content, default_pane)))
+    }
+
+    /// Create tabs whose panes all stay in the DOM, keeping their scroll
+    /// position and other transient state. See [`Panes::new_retained`].
+    pub fn new_retained(default_pane: T) -> Self {
+        Self::with_panes(|content| TabPanes::Static(Panes::new_retained(content, default_pane)))
+    }
+
+    /// Create tabs whose panes stay in the DOM like [`Tabs::new_retained`],
+    /// but are only built when first shown. See [`Panes::new_lazy`] and
+    /// [`Tabs::add_tab_with`].
+    pub fn new_lazy(default_pane: T) -> Self {
+        Self::with_panes(|content| TabPanes::Static(Panes::new_lazy(content, default_pane)))
+    }
+
+    /// Create tabs whose panes are rebuilt from a factory each time they are
+    /// shown. See [`RestartPanes::new`] and [`Tabs::add_tab_with`].
+    pub fn new_restarting(default_pane: T) -> Self {
+        Self::with_panes(|content| TabPanes::Restart(RestartPanes::new(content, default_pane)))
+    }
+
+    /// Return a reference to the [`TabList`], e.g. to change its style.
+    pub fn get_tab_list(&self) -> &TabList<V, V::Element> {
+        &self.tab_list
+    }
+
+    /// Return a mutable reference to the [`TabList`].
+    ///
+    /// ## Note
+    /// Adding or removing tabs here puts the tabs and panes out of sync. Use
+    /// [`Tabs::add_tab`] and [`Tabs::remove_tab`] instead.
+    pub fn get_tab_list_mut(&mut self) -> &mut TabList<V, V::Element> {
+        &mut self.tab_list
+    }
+
+    /// Animate switching panes. See [`Panes::set_transition`].
+    ///
+    /// Has no effect on tabs created with [`Tabs::new_restarting`].
+    pub fn set_transition(&mut self, transition: Transition) {
+        if let TabPanes::Static(panes) = &mut self.panes {
+            panes.set_transition(transition);
+        }
+    }
+
+    /// Add a tab showing `pane`, returning the pane's [`Id`].
+    ///
+    /// The first tab added is selected.
+    ///
+    /// ## Errors
+    /// Errs if these tabs were created with [`Tabs::new_restarting`], which
+    /// needs a factory — use [`Tabs::add_tab_with`].
+    pub fn add_tab(&mut self, label: impl AsRef<str>, pane: T) -> Result<Id<T>, Error> {
+        let id = match &mut self.panes {
+            TabPanes::Static(panes) => panes.add_pane(pane),
+            TabPanes::Restart(_) => return NeedsFactorySnafu.fail(),
+        };
+        self.push_label(label, id.clone());
+        Ok(id)
+    }
+
+    /// Add a tab whose pane is built by `create`, returning the pane's [`Id`].
+    ///
+    /// With [`Tabs::new_restarting`] the pane is rebuilt every time the tab is
+    /// selected and with [`Tabs::new_lazy`] it is built when first selected.
+    /// Otherwise `create` is called once, right away.
+    ///
+    /// The first tab added is selected.
+    pub fn add_tab_with(
+        &mut self,
+        label: impl AsRef<str>,
+        create: impl FnMut() -> T + 'static,
+    ) -> Id<T> {
+        let id = match &mut self.panes {
+            TabPanes::Static(panes) => panes.add_pane_with(create),
+            TabPanes::Restart(panes) => panes.add_pane(create),
+        };
+        self.push_label(label, id.clone());
+        id
+    }
+
+    fn push_label(&mut self, label: impl AsRef<str>, id: Id<T>) {
+        let text = V::Text::new(label);
+        rsx! {
+            let span = span() {
+                {text}
+            }
+        }
+        let tab_id = self.tab_list.push(span);
+        self.tabs_to_panes.insert(tab_id, id.clone());
+        if self.tab_list.len() == 1 {
+            self.select(&id);
+        }
+    }
+
+    fn tab_id(&self, id: &Id<T>) -> Option<Id<V::Element>> {
+        self.tabs_to_panes
+            .iter()
+            .find_map(|(tab_id, pane_id)| (pane_id == id).then(|| tab_id.clone()))
+    }
+
+    /// Remove the tab and pane with the given [`Id`].
+    ///
+    /// If it was the selected tab, a neighbouring tab is selected instead.
+    ///
+    /// Returns the tab index the tab had, or `None` if it wasn't found.
+    pub fn remove_tab(&mut self, id: &Id<T>) -> Option<usize> {
+        let tab_id = self.tab_id(id)?;
+        let index = self.tab_list.iter().position(|item| item.id() == &tab_id)?;
+        self.close(index)
+    }
+
+    /// Close the tab at `index` and remove its pane, selecting a neighbour.
+    fn close(&mut self, index: usize) -> Option<usize> {
+        let (removal, selected) = self.tab_list.close(index)?;
+        if let Some(pane_id) = self.tabs_to_panes.remove(&removal.id) {
+            match &mut self.panes {
+                TabPanes::Static(panes) => {
+                    let _ = panes.remove_by_id(&pane_id);
+                }
+                TabPanes::Restart(panes) => {
+                    let _ = panes.remove_by_id(&pane_id);
+                }
+            }
+        }
+        if let Some(pane_id) = selected.and_then(|tab_id| self.tabs_to_panes.get(&tab_id)) {
+            let pane_id = pane_id.clone();
+            self.select_pane(&pane_id);
+        }
+        Some(removal.index)
+    }
+
+    fn select_pane(&mut self, id: &Id<T>) -> bool {
+        match &mut self.panes {
+            TabPanes::Static(panes) => panes.select(id),
+            TabPanes::Restart(panes) => panes.select(id),
+        }
+    }
+
+    /// Select the tab with the given [`Id`] and show its pane.
+    ///
+    /// Returns `false` if no such tab exists.
+    pub fn select(&mut self, id: &Id<T>) -> bool {
+        let Some(tab_id) = self.tab_id(id) else {
+            return false;
+        };
+        self.tab_list.select_by_id(&tab_id);
+        self.select_pane(id);
+        true
+    }
+
+    /// Return the tab index of the selected tab, if any.
+    pub fn selected(&self) -> Option<usize> {
+        self.tab_list.selected()
+    }
+
+    /// Return the number of tabs.
+    pub fn len(&self) -> usize {
+        self.tab_list.len()
+    }
+
+    /// Returns `true` if there are no tabs.
+    pub fn is_empty(&self) -> bool {
+        self.tab_list.is_empty()
+    }
+
+    /// Returns a reference to the currently visible pane.
+    pub fn current_pane(&self) -> Option<&T> {
+        match &self.panes {
+            TabPanes::Static(panes) => panes.current_pane(),
+            TabPanes::Restart(panes) => Some(panes.current_pane()),
+        }
+    }
+
+    /// Returns a mutable reference to the currently visible pane.
+    pub fn current_pane_mut(&mut self) -> Option<&mut T> {
+        match &mut self.panes {
+            TabPanes::Static(panes) => panes.current_pane_mut(),
+            TabPanes::Restart(panes) => Some(panes.current_pane_mut()),
+        }
+    }
+
+    /// Returns a reference to the pane with the given `id`, if it exists.
+    ///
+    /// With [`Tabs::new_restarting`] only the visible pane exists.
+    pub fn get_pane(&self, id: &Id<T>) -> Option<&T> {
+        match &self.panes {
+            TabPanes::Static(panes) => panes.get_pane(id),
+            TabPanes::Restart(panes) => {
+                let tab = self.tab_list.iter().find(|item| item.is_active())?;
+                (self.tabs_to_panes.get(tab.id()) == Some(id)).then(|| panes.current_pane())
+            }
+        }
+    }
+
+    /// Await a tab being clicked or closed, and update the panes to match.
+    pub async fn step(&mut self) -> TabsEvent<T> {
+        loop {
+            match self.tab_list.step().await {
+                TabListEvent::ItemClicked { id, index, .. } => {
+                    if let Some(pane_id) = self.tabs_to_panes.get(&id).cloned() {
+                        self.tab_list.select_by_id(&id);
+                        self.select_pane(&pane_id);
+                        return TabsEvent::Selected { id: pane_id, index };
+                    }
+                }
+                TabListEvent::CloseRequested { id, index } => {
+                    if let Some(pane_id) = self.tabs_to_panes.get(&id).cloned() {
+                        self.close(index);
+                        return TabsEvent::Closed { id: pane_id, index };
+                    }
+                }
+            }
+        }
+    }
+}
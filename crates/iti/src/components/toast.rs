@@ -1,27 +1,80 @@
 //! Toast notification component.
 //!
-//! A Bootstrap toast with reactive title, body, and flavor.  Show/hide is
+//! A Bootstrap toast with reactive title, body, and flavor, plus optional
+//! custom body content and a row of action buttons.  Show/hide is
 //! managed in pure Rust via a reactive `Proxy<bool>` — no Bootstrap JS required.
+//!
+//! [`ToastManager`] stacks toasts in a corner of the viewport and dismisses
+//! them on timers, for app-wide notifications.
+use std::{cell::Cell, time::Duration};
+
+use futures_lite::FutureExt;
 use mogwai::prelude::*;
 
-use super::Flavor;
+use super::{
+    button::{Button, ButtonSize},
+    Flavor,
+};
+use crate::{
+    id::{Id, IdPool},
+    util::time::{now, wait_until},
+};
 
 /// Event emitted by a [`Toast`].
 pub enum ToastEvent {
     /// The close button was clicked.
     Closed,
+    /// The action button at this index was clicked.
+    ///
+    /// See [`Toast::push_action`].
+    Action(usize),
+    /// The time set with [`Toast::set_auto_hide`] ran out.
+    ///
+    /// The toast stays visible until it is hidden with [`Toast::hide`].
+    Expired,
+}
+
+/// Where the auto-hide countdown of a [`Toast`] is at.
+#[derive(Clone, Copy)]
+enum Countdown {
+    Off,
+    /// Counting down to this time, in milliseconds since the epoch.
+    Until(f64),
+    /// Paused by the pointer with this many milliseconds left.
+    Paused(f64),
 }
 
 struct ToastState {
     flavor: Flavor,
     visible: bool,
+    has_countdown: bool,
+    /// `true` while the auto-hide countdown runs.
+    is_counting: bool,
+}
+
+impl ToastState {
+    fn countdown_class(&self) -> &'static str {
+        if self.is_counting {
+            "toast-countdown counting"
+        } else {
+            "toast-countdown"
+        }
+    }
+
+    fn countdown_display(&self) -> &'static str {
+        if self.has_countdown && self.is_counting {
+            "block"
+        } else {
+            "none"
+        }
+    }
 }
 
 /// A Bootstrap toast notification.
 ///
 /// Call [`Toast::show`] to make it visible and [`Toast::step`] to await user
-/// interaction. The toast does **not** auto-dismiss; the caller is responsible
-/// for racing a timer against `step()` if auto-dismiss is desired.
+/// interaction. With [`Toast::set_auto_hide`] it is only shown for a limited
+/// time.
 #[derive(ViewChild, ViewProperties)]
 pub struct Toast<V: View> {
     #[child]
@@ -29,8 +82,17 @@ pub struct Toast<V: View> {
     div: V::Element,
     title: V::Text,
     body: V::Text,
+    body_el: V::Element,
+    body_child: ProxyChild<V>,
+    actions_el: V::Element,
+    actions: Vec<Button<V>>,
     state: Proxy<ToastState>,
     close_click: V::EventListener,
+    mouseenter: V::EventListener,
+    mouseleave: V::EventListener,
+    countdown: V::Element,
+    auto_hide: Option<Duration>,
+    deadline: Cell<Countdown>,
 }
 
 impl<V: View> Toast<V> {
@@ -38,6 +100,8 @@ impl<V: View> Toast<V> {
         let mut state = Proxy::new(ToastState {
             flavor,
             visible: false,
+            has_countdown: true,
+            is_counting: false,
         });
 
         rsx! {
@@ -52,6 +116,8 @@ impl<V: View> Toast<V> {
                 role = "alert",
                 aria_live = "assertive",
                 aria_atomic = "true",
+                on:mouseenter = mouseenter,
+                on:mouseleave = mouseleave,
             ) {
                 div(
                     class = state(s => format!(
@@ -68,21 +134,46 @@ impl<V: View> Toast<V> {
                         on:click = close_click,
                     ) {}
                 }
-                div(class = "toast-body") {
+                let body_el = div(class = "toast-body") {
                     let body_text = ""
                 }
+                let actions_el = div(
+                    class = "toast-actions",
+                    style:display = "none",
+                ) {}
+                let countdown = div(
+                    class = state(s => s.countdown_class()),
+                    style:display = state(s => s.countdown_display()),
+                ) {}
             }
         }
 
         title_text.set_text(title);
         body_text.set_text(body);
 
+        let body_child = ProxyChild::new(&{
+            rsx! {
+                let placeholder = span() {}
+            }
+            placeholder
+        });
+        body_el.append_child(&body_child);
+
         Self {
             div,
             title: title_text,
             body: body_text,
+            body_el,
+            body_child,
+            actions_el,
+            actions: vec![],
             state,
             close_click,
+            mouseenter,
+            mouseleave,
+            countdown,
+            auto_hide: None,
+            deadline: Cell::new(Countdown::Off),
         }
     }
 
@@ -94,24 +185,346 @@ impl<V: View> Toast<V> {
         self.body.set_text(body);
     }
 
+    /// Replace the body with arbitrary content, clearing the body text.
+    pub fn set_body_content(&mut self, content: &impl ViewChild<V>) {
+        self.body.set_text("");
+        self.body_child.replace(&self.body_el, content);
+    }
+
+    /// Add an action button (e.g. "Undo") below the body, returning its
+    /// index.
+    ///
+    /// Clicks are reported by [`Toast::step`] as [`ToastEvent::Action`].
+    pub fn push_action(&mut self, label: impl AsRef<str>) -> usize {
+        let mut button = Button::new(label, None);
+        button.set_has_icon(false);
+        button.set_size(ButtonSize::Small);
+        self.actions_el.append_child(&button);
+        self.actions_el.remove_style("display");
+        self.actions.push(button);
+        self.actions.len() - 1
+    }
+
+    pub fn get_action_mut(&mut self, index: usize) -> Option<&mut Button<V>> {
+        self.actions.get_mut(index)
+    }
+
+    /// Remove every action button.
+    pub fn clear_actions(&mut self) {
+        for button in self.actions.drain(..) {
+            self.actions_el.remove_child(&button);
+        }
+        self.actions_el.set_style("display", "none");
+    }
+
     pub fn set_flavor(&mut self, flavor: Flavor) {
         self.state.modify(|s| s.flavor = flavor);
     }
 
-    /// Make the toast visible.
+    /// Make the toast visible, restarting the countdown set with
+    /// [`Toast::set_auto_hide`].
     pub fn show(&mut self) {
         self.state.modify(|s| s.visible = true);
+        self.start_countdown();
     }
 
     /// Hide the toast.
     pub fn hide(&mut self) {
-        self.state.modify(|s| s.visible = false);
+        self.deadline.set(Countdown::Off);
+        self.state.modify(|s| {
+            s.visible = false;
+            s.is_counting = false;
+        });
     }
 
-    /// Await the next toast event (currently only [`ToastEvent::Closed`]).
+    /// Show or hide the strip that shrinks while the auto-hide countdown
+    /// runs. Shown by default.
+    pub fn set_has_countdown(&mut self, has_countdown: bool) {
+        self.state.modify(|s| s.has_countdown = has_countdown);
+    }
+
+    /// Have [`Toast::step`] return [`ToastEvent::Expired`] once the toast
+    /// has been shown for `duration`, or never with `None` (the default).
+    ///
+    /// The countdown starts with [`Toast::show`], or right away if the
+    /// toast is visible, and pauses while the pointer is over the toast.
+    pub fn set_auto_hide(&mut self, duration: Option<Duration>) {
+        self.auto_hide = duration;
+        if self.state.visible {
+            self.start_countdown();
+        }
+    }
+
+    pub fn auto_hide(&self) -> Option<Duration> {
+        self.auto_hide
+    }
+
+    fn start_countdown(&mut self) {
+        let Some(duration) = self.auto_hide else {
+            self.deadline.set(Countdown::Off);
+            self.state.modify(|s| s.is_counting = false);
+            return;
+        };
+        let millis = duration.as_millis();
+        self.countdown
+            .set_style("animation-duration", &format!("{millis}ms"));
+        self.deadline.set(Countdown::Until(now() + millis as f64));
+        self.state.modify(|s| s.is_counting = false);
+        // Force a reflow so the strip's animation restarts.
+        self.countdown
+            .dyn_el(|el: &web_sys::HtmlElement| el.offset_width());
+        self.state.modify(|s| s.is_counting = true);
+    }
+
+    /// Await the auto-hide countdown running out, pausing it while the
+    /// pointer is over the toast.
+    async fn expired(&self) {
+        loop {
+            match self.deadline.get() {
+                Countdown::Off => std::future::pending().await,
+                Countdown::Until(deadline) => {
+                    let timeout = async {
+                        wait_until(deadline).await;
+                        true
+                    };
+                    let hovered = async {
+                        self.mouseenter.next().await;
+                        false
+                    };
+                    if timeout.or(hovered).await {
+                        self.deadline.set(Countdown::Off);
+                        return;
+                    }
+                    let remaining = (deadline - now()).max(0.0);
+                    self.deadline.set(Countdown::Paused(remaining));
+                }
+                Countdown::Paused(remaining) => {
+                    self.mouseleave.next().await;
+                    self.deadline.set(Countdown::Until(now() + remaining));
+                }
+            }
+        }
+    }
+
+    /// Await the close button or an action button being clicked, or the
+    /// auto-hide countdown running out.
     pub async fn step(&self) -> ToastEvent {
-        self.close_click.next().await;
-        ToastEvent::Closed
+        use mogwai::future::*;
+
+        let closed = async {
+            self.close_click.next().await;
+            ToastEvent::Closed
+        };
+        let expired = async {
+            self.expired().await;
+            ToastEvent::Expired
+        };
+        let closed = closed.or(expired);
+        if self.actions.is_empty() {
+            return closed.await;
+        }
+        let actions = race_all(
+            self.actions
+                .iter()
+                .enumerate()
+                .map(|(index, button)| button.step().map(move |_| ToastEvent::Action(index))),
+        );
+        closed.or(actions).await
+    }
+}
+
+/// Corner of the viewport a [`ToastManager`] stacks its toasts in.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum ToastPosition {
+    TopStart,
+    TopEnd,
+    BottomStart,
+    #[default]
+    BottomEnd,
+}
+
+impl ToastPosition {
+    fn class(&self) -> &'static str {
+        match self {
+            ToastPosition::TopStart => "toast-container toast-container-top-start",
+            ToastPosition::TopEnd => "toast-container toast-container-top-end",
+            ToastPosition::BottomStart => "toast-container toast-container-bottom-start",
+            ToastPosition::BottomEnd => "toast-container toast-container-bottom-end",
+        }
+    }
+}
+
+/// Event emitted by a [`ToastManager`].
+pub enum ToastManagerEvent<V: View> {
+    /// The toast's close button was clicked.
+    Closed(Id<Toast<V>>),
+    /// The toast's timeout ran out.
+    Expired(Id<Toast<V>>),
+    /// One of the toast's action buttons was clicked. The toast stays.
+    Action { id: Id<Toast<V>>, index: usize },
+}
+
+struct ManagedToast<V: View> {
+    id: Id<Toast<V>>,
+    toast: Toast<V>,
+    /// Time (in milliseconds since the epoch) the toast is dismissed at.
+    expires_at: Option<f64>,
+}
+
+/// An app-wide notifier that stacks [`Toast`]s in a fixed corner of the
+/// viewport.
+///
+/// Toasts are removed when closed or when their timeout runs out. A single
+/// [`ToastManager::step`] drives all of them.
+///
+/// ```ignore
+/// let mut toasts = ToastManager::default();
+/// mogwai::web::body().append_child(&toasts);
+/// toasts.push("Saved", "Your document was saved.", Flavor::Success, Some(Duration::from_secs(4)));
+/// loop {
+///     toasts.step().await;
+/// }
+/// ```
+#[derive(ViewChild, ViewProperties)]
+pub struct ToastManager<V: View> {
+    #[child]
+    #[properties]
+    container: V::Element,
+    position: Proxy<ToastPosition>,
+    toasts: Vec<ManagedToast<V>>,
+    id_pool: IdPool<Toast<V>>,
+}
+
+impl<V: View> Default for ToastManager<V> {
+    fn default() -> Self {
+        Self::new(ToastPosition::default())
+    }
+}
+
+impl<V: View> ToastManager<V> {
+    pub fn new(position: ToastPosition) -> Self {
+        let mut position = Proxy::new(position);
+
+        rsx! {
+            let container = div(class = position(p => p.class())) {}
+        }
+
+        Self {
+            container,
+            position,
+            toasts: vec![],
+            id_pool: IdPool::default(),
+        }
+    }
+
+    pub fn set_position(&mut self, position: ToastPosition) {
+        self.position.set(position);
+    }
+
+    pub fn position(&self) -> ToastPosition {
+        *self.position
+    }
+
+    /// Show a new toast below the others, returning its id.
+    ///
+    /// With a `timeout` the toast is dismissed automatically once it runs
+    /// out; otherwise it stays until closed.
+    pub fn push(
+        &mut self,
+        title: impl AsRef<str>,
+        body: impl AsRef<str>,
+        flavor: Flavor,
+        timeout: Option<Duration>,
+    ) -> Id<Toast<V>> {
+        let mut toast = Toast::new(title, body, flavor);
+        toast.show();
+        self.container.append_child(&toast);
+        let id = self.id_pool.get_id();
+        self.toasts.push(ManagedToast {
+            id: id.clone(),
+            toast,
+            expires_at: timeout.map(|t| js_sys::Date::now() + t.as_millis() as f64),
+        });
+        id
+    }
+
+    /// Remove a toast before it is closed or expires.
+    pub fn remove(&mut self, id: &Id<Toast<V>>) -> Option<Toast<V>> {
+        let index = self.toasts.iter().position(|t| &t.id == id)?;
+        let managed = self.toasts.remove(index);
+        self.container.remove_child(&managed.toast);
+        Some(managed.toast)
+    }
+
+    pub fn get(&self, id: &Id<Toast<V>>) -> Option<&Toast<V>> {
+        self.toasts.iter().find(|t| &t.id == id).map(|t| &t.toast)
+    }
+
+    pub fn get_mut(&mut self, id: &Id<Toast<V>>) -> Option<&mut Toast<V>> {
+        self.toasts
+            .iter_mut()
+            .find(|t| &t.id == id)
+            .map(|t| &mut t.toast)
+    }
+
+    pub fn len(&self) -> usize {
+        self.toasts.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.toasts.is_empty()
+    }
+
+    /// Await the next toast being closed or expiring, and remove it, or the
+    /// next click on a toast's action button.
+    ///
+    /// Never resolves while there are no toasts.
+    pub async fn step(&mut self) -> ToastManagerEvent<V> {
+        use mogwai::future::*;
+
+        if self.toasts.is_empty() {
+            return std::future::pending().await;
+        }
+
+        let toast_events = race_all(
+            self.toasts
+                .iter()
+                .enumerate()
+                .map(|(index, t)| t.toast.step().map(move |event| Ok((index, event)))),
+        );
+        let next_expiry = self
+            .toasts
+            .iter()
+            .enumerate()
+            .filter_map(|(index, t)| Some((index, t.expires_at?)))
+            .min_by(|a, b| a.1.total_cmp(&b.1));
+        let expired = async {
+            match next_expiry {
+                Some((index, expires_at)) => {
+                    let remaining = (expires_at - js_sys::Date::now()).max(0.0);
+                    mogwai::time::wait_millis(remaining as u64).await;
+                    Err(index)
+                }
+                None => std::future::pending().await,
+            }
+        };
+
+        match toast_events.or(expired).await {
+            Ok((index, ToastEvent::Action(action))) => ToastManagerEvent::Action {
+                id: self.toasts[index].id.clone(),
+                index: action,
+            },
+            Ok((index, ToastEvent::Closed)) => {
+                let managed = self.toasts.remove(index);
+                self.container.remove_child(&managed.toast);
+                ToastManagerEvent::Closed(managed.id)
+            }
+            Ok((index, ToastEvent::Expired)) | Err(index) => {
+                let managed = self.toasts.remove(index);
+                self.container.remove_child(&managed.toast);
+                ToastManagerEvent::Expired(managed.id)
+            }
+        }
     }
 }
 
@@ -121,14 +534,18 @@ pub mod library {
     use mogwai::future::MogwaiFutureExt;
 
     use super::*;
+    use crate::library::log_event;
 
     #[derive(ViewChild)]
     pub struct ToastLibraryItem<V: View> {
         #[child]
         pub wrapper: V::Element,
         toast: Toast<V>,
+        manager: ToastManager<V>,
         show_click: V::EventListener,
+        notify_click: V::EventListener,
         toast_count: usize,
+        notify_count: usize,
     }
 
     impl<V: View> Default for ToastLibraryItem<V> {
@@ -138,7 +555,9 @@ pub mod library {
                 "Hello! This is a toast message.",
                 Flavor::Primary,
             );
+            toast.push_action("Undo");
             toast.show();
+            let manager = ToastManager::default();
 
             rsx! {
                 let wrapper = div() {
@@ -148,45 +567,85 @@ pub mod library {
                             class = "btn btn-sm btn-outline-primary",
                             on:click = show_click,
                         ) {
-                            "Show toast"
+                            "Show toast for 5s"
+                        }
+                        button(
+                            type = "button",
+                            class = "btn btn-sm btn-outline-primary ms-1",
+                            on:click = notify_click,
+                        ) {
+                            "Notify"
                         }
                     }
                     div(class = "toast-container position-relative") {
                         {&toast}
                     }
+                    {&manager}
                 }
             }
 
             Self {
                 wrapper,
                 toast,
+                manager,
                 show_click,
+                notify_click,
                 toast_count: 0,
+                notify_count: 0,
             }
         }
     }
 
+    enum Action {
+        Toast(ToastEvent),
+        Show,
+        Notify,
+        Manager,
+    }
+
     impl<V: View> ToastLibraryItem<V> {
         pub async fn step(&mut self) {
-            match self
+            let action = self
                 .toast
                 .step()
-                .map(Ok)
-                .or(self.show_click.next().map(Err))
-                .await
-            {
-                Ok(ToastEvent::Closed) => {
+                .map(Action::Toast)
+                .or(self.show_click.next().map(|_| Action::Show))
+                .or(self.notify_click.next().map(|_| Action::Notify))
+                .or(self.manager.step().map(|_| Action::Manager))
+                .await;
+            match action {
+                Action::Toast(ToastEvent::Closed) => {
+                    log_event("Toast", "Closed");
+                    self.toast.hide();
+                }
+                Action::Toast(ToastEvent::Action(_)) => {
+                    log_event("Toast", "Clicked Undo");
+                    self.toast.set_body("Undone.");
+                }
+                Action::Toast(ToastEvent::Expired) => {
+                    log_event("Toast", "Expired");
                     self.toast.hide();
                 }
-                Err(_) => {
+                Action::Show => {
                     self.toast_count += 1;
                     self.toast.set_body(format!(
                         "Shown {} time{}!",
                         self.toast_count,
                         if self.toast_count == 1 { "" } else { "s" }
                     ));
+                    self.toast.set_auto_hide(Some(Duration::from_secs(5)));
                     self.toast.show();
                 }
+                Action::Notify => {
+                    self.notify_count += 1;
+                    self.manager.push(
+                        format!("Notification {}", self.notify_count),
+                        "This one disappears after three seconds.",
+                        Flavor::Info,
+                        Some(Duration::from_secs(3)),
+                    );
+                }
+                Action::Manager => {}
             }
         }
     }
@@ -0,0 +1,89 @@
+//! Undo and redo buttons.
+//!
+//! [`UndoRedoButtons`] is a [`ButtonGroup`] wired to a
+//! [`History`](crate::state::History).
+
+use mogwai::prelude::*;
+
+use crate::{
+    components::{button::Button, button_group::ButtonGroup, icon::IconGlyph},
+    state::History,
+};
+
+const UNDO: usize = 0;
+const REDO: usize = 1;
+
+/// A pair of undo and redo buttons for a [`History`].
+///
+/// Each button is disabled while there is nothing for it to do.
+pub struct UndoRedoButtons<V: View> {
+    group: ButtonGroup<V>,
+}
+
+impl<V: View> ViewChild<V> for UndoRedoButtons<V> {
+    fn as_append_arg(
+        &self,
+    ) -> AppendArg<V, impl Iterator<Item = std::borrow::Cow<'_, <V as View>::Node>>> {
+        self.group.as_append_arg()
+    }
+}
+
+impl<V: View> Default for UndoRedoButtons<V> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<V: View> UndoRedoButtons<V> {
+    pub fn new() -> Self {
+        let group: ButtonGroup<V> = [(IconGlyph::Undo, "Undo"), (IconGlyph::Redo, "Redo")]
+            .into_iter()
+            .map(|(glyph, label)| {
+                let button = Button::icon_only(glyph, label);
+                button.disable();
+                button
+            })
+            .collect();
+        Self { group }
+    }
+
+    pub fn get_group(&self) -> &ButtonGroup<V> {
+        &self.group
+    }
+
+    pub fn get_group_mut(&mut self) -> &mut ButtonGroup<V> {
+        &mut self.group
+    }
+
+    /// Enable or disable the buttons to match `history`.
+    ///
+    /// [`UndoRedoButtons::step`] does this itself; call it after changing
+    /// the history elsewhere.
+    pub fn sync<T>(&self, history: &History<T>) {
+        let states = [(UNDO, history.can_undo()), (REDO, history.can_redo())];
+        for (index, is_enabled) in states {
+            if let Some(button) = self.group.get(index) {
+                if is_enabled {
+                    button.enable();
+                } else {
+                    button.disable();
+                }
+            }
+        }
+    }
+
+    /// Await a click, then undo or redo the last change to `history`.
+    ///
+    /// Returns `true` for undo and `false` for redo.
+    pub async fn step<T>(&mut self, history: &mut History<T>) -> bool {
+        self.sync(history);
+        let is_undo = self.group.step().await.index() == UNDO;
+        if is_undo {
+            history.undo();
+        } else {
+            history.redo();
+        }
+        self.sync(history);
+        is_undo
+    }
+}
@@ -2,18 +2,71 @@
 //!
 //! [`Widget`] is a useful generic container for UI and a stream of events.
 
-use std::pin::Pin;
+use std::{
+    pin::Pin,
+    task::{Context, Poll},
+};
 
 use futures_lite::{Stream, StreamExt};
 use mogwai::prelude::*;
 
+type BoxedStream<T> = Pin<Box<dyn Stream<Item = T>>>;
+
+/// Two streams merged by [`Widget::merge`].
+///
+/// The streams take turns at being polled first, so a busy one can't
+/// starve the other, and the merge ends once both have.
+struct Merge<T> {
+    /// The streams, `None` once ended.
+    streams: [Option<BoxedStream<T>>; 2],
+    /// Index of the stream polled first next time.
+    first: usize,
+}
+
+impl<T> Stream for Merge<T> {
+    type Item = T;
+
+    fn poll_next(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<T>> {
+        let first = self.first;
+        self.first = 1 - first;
+        for index in [first, 1 - first] {
+            let Some(stream) = self.streams[index].as_mut() else {
+                continue;
+            };
+            match stream.as_mut().poll_next(cx) {
+                Poll::Ready(Some(item)) => return Poll::Ready(Some(item)),
+                Poll::Ready(None) => self.streams[index] = None,
+                Poll::Pending => {}
+            }
+        }
+        if self.streams.iter().all(Option::is_none) {
+            Poll::Ready(None)
+        } else {
+            Poll::Pending
+        }
+    }
+}
+
+/// An event from one of the streams of a [`Widget`] built with
+/// [`Widget::named`].
+#[derive(Clone, Debug, PartialEq)]
+pub struct Named<T> {
+    /// Name the stream was added under.
+    pub name: &'static str,
+    /// The event itself.
+    pub event: T,
+}
+
 /// Container for some arbitrary user interface paired with an event stream.
+///
+/// The stream can be adapted with [`Widget::map`], [`Widget::filter`] and
+/// [`Widget::merge`] without re-boxing it by hand.
 #[derive(ViewChild, ViewProperties)]
 pub struct Widget<V: View, T> {
     #[child]
     #[properties]
     wrapper: V::Element,
-    stream: Pin<Box<dyn Stream<Item = T>>>,
+    stream: BoxedStream<T>,
 }
 
 impl<V: View, T> Widget<V, T> {
@@ -32,3 +85,43 @@ impl<V: View, T> Widget<V, T> {
         }
     }
 }
+
+impl<V: View, T: 'static> Widget<V, T> {
+    /// Convert each event with `f`.
+    pub fn map<U>(self, f: impl FnMut(T) -> U + 'static) -> Widget<V, U> {
+        Widget::new(self.wrapper, self.stream.map(f))
+    }
+
+    /// Drop the events for which `f` returns `false`.
+    pub fn filter(self, f: impl FnMut(&T) -> bool + 'static) -> Self {
+        Widget::new(self.wrapper, self.stream.filter(f))
+    }
+
+    /// Also emit the events of `other`.
+    ///
+    /// ## Note
+    /// While both streams have events ready they take turns, and the
+    /// merged stream only ends once both have.
+    pub fn merge(self, other: impl Stream<Item = T> + 'static) -> Self {
+        let merged = Merge {
+            streams: [Some(self.stream), Some(other.boxed_local())],
+            first: 0,
+        };
+        Widget::new(self.wrapper, merged)
+    }
+}
+
+impl<V: View, T: 'static> Widget<V, Named<T>> {
+    /// Create a widget whose events come from several named streams.
+    ///
+    /// Add the streams with [`Widget::with_stream`]; each event is tagged
+    /// with the name of the stream it came from.
+    pub fn named(wrapper: V::Element) -> Self {
+        Widget::new(wrapper, futures_lite::stream::pending())
+    }
+
+    /// Add a stream whose events are tagged with `name`.
+    pub fn with_stream(self, name: &'static str, stream: impl Stream<Item = T> + 'static) -> Self {
+        self.merge(stream.map(move |event| Named { name, event }))
+    }
+}
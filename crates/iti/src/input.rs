@@ -0,0 +1,303 @@
+//! Keyboard shortcuts.
+//!
+//! [`Shortcuts`] is a registry of key combos such as `"mod+k"` or
+//! sequences such as `"g then i"`, each with an id of your choosing.
+//! [`Shortcuts::step`] awaits the next shortcut pressed anywhere in the
+//! document and returns its id.
+//!
+//! ```ignore
+//! let mut shortcuts = Shortcuts::new();
+//! shortcuts.add(Action::Search, "mod+k")?;
+//! shortcuts.add(Action::GoToInbox, "g then i")?;
+//! loop {
+//!     match shortcuts.step().await {
+//!         Action::Search => palette.show(),
+//!         Action::GoToInbox => router.navigate(Page::Inbox),
+//!     }
+//! }
+//! ```
+
+use std::{cell::RefCell, rc::Rc};
+
+use snafu::prelude::*;
+use wasm_bindgen::JsCast;
+
+use crate::util::queue::QueuedListener;
+
+/// Keys pressed further apart than this don't continue a sequence.
+const SEQUENCE_TIMEOUT_MILLIS: f64 = 1000.0;
+
+/// All shortcut errors.
+#[derive(Debug, Snafu)]
+pub enum Error {
+    #[snafu(display("Empty key combo in shortcut '{shortcut}'"))]
+    EmptyCombo { shortcut: String },
+    #[snafu(display("Unknown modifier '{modifier}' in shortcut '{shortcut}'"))]
+    UnknownModifier { modifier: String, shortcut: String },
+}
+
+/// A key together with the modifiers held with it.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct KeyCombo {
+    /// The key, lowercased, as in `KeyboardEvent.key` (e.g. `"k"`,
+    /// `"enter"`, `"arrowdown"`), except that the space bar is `"space"`.
+    pub key: String,
+    pub ctrl: bool,
+    pub alt: bool,
+    pub shift: bool,
+    pub meta: bool,
+    /// Either Ctrl or Meta (⌘), so one combo works on every platform.
+    pub is_mod: bool,
+}
+
+impl KeyCombo {
+    /// Parse a combo such as `"mod+k"`, `"shift+?"` or `"escape"`.
+    ///
+    /// Modifiers are `ctrl`, `alt` (or `option`), `shift`, `meta` (or `cmd`)
+    /// and `mod`.
+    pub fn parse(combo: &str) -> Result<Self, Error> {
+        let mut parts = combo.trim().split('+').map(str::trim).collect::<Vec<_>>();
+        let key = parts.pop().unwrap_or_default();
+        ensure!(
+            !key.is_empty(),
+            EmptyComboSnafu {
+                shortcut: combo.to_string()
+            }
+        );
+        let mut parsed = KeyCombo {
+            key: key.to_lowercase(),
+            ..Default::default()
+        };
+        for modifier in parts {
+            match modifier.to_lowercase().as_str() {
+                "ctrl" | "control" => parsed.ctrl = true,
+                "alt" | "option" => parsed.alt = true,
+                "shift" => parsed.shift = true,
+                "meta" | "cmd" => parsed.meta = true,
+                "mod" => parsed.is_mod = true,
+                _ => {
+                    return UnknownModifierSnafu {
+                        modifier,
+                        shortcut: combo,
+                    }
+                    .fail()
+                }
+            }
+        }
+        Ok(parsed)
+    }
+
+    fn from_event(ev: &web_sys::KeyboardEvent) -> Self {
+        let key = match ev.key().as_str() {
+            " " => "space".to_string(),
+            key => key.to_lowercase(),
+        };
+        KeyCombo {
+            key,
+            ctrl: ev.ctrl_key(),
+            alt: ev.alt_key(),
+            shift: ev.shift_key(),
+            meta: ev.meta_key(),
+            is_mod: false,
+        }
+    }
+
+    /// Returns `true` if a Ctrl, Alt or Meta modifier is part of the combo.
+    fn has_command_modifier(&self) -> bool {
+        self.ctrl || self.alt || self.meta || self.is_mod
+    }
+
+    fn matches(&self, pressed: &KeyCombo) -> bool {
+        let ctrl_and_meta = if self.is_mod {
+            pressed.ctrl || pressed.meta
+        } else {
+            pressed.ctrl == self.ctrl && pressed.meta == self.meta
+        };
+        // Symbols like "?" already imply shift on most layouts.
+        let is_symbol = !self.key.chars().all(char::is_alphanumeric);
+        let shift = is_symbol || pressed.shift == self.shift;
+        self.key == pressed.key && ctrl_and_meta && pressed.alt == self.alt && shift
+    }
+}
+
+struct Binding<K> {
+    id: K,
+    sequence: Vec<KeyCombo>,
+    scope: Option<String>,
+}
+
+fn is_modifier_key(key: &str) -> bool {
+    matches!(
+        key,
+        "control" | "alt" | "shift" | "meta" | "os" | "altgraph"
+    )
+}
+
+fn is_form_field(target: Option<web_sys::EventTarget>) -> bool {
+    let Some(el) = target.and_then(|t| t.dyn_into::<web_sys::HtmlElement>().ok()) else {
+        return false;
+    };
+    matches!(el.tag_name().as_str(), "INPUT" | "TEXTAREA" | "SELECT") || el.is_content_editable()
+}
+
+/// The shortcuts and the keys pressed so far, shared with the keydown
+/// listener.
+struct Registry<K> {
+    bindings: Vec<Binding<K>>,
+    scopes: Vec<String>,
+    pressed: Vec<KeyCombo>,
+    last_pressed_at: f64,
+}
+
+impl<K: Clone> Registry<K> {
+    /// Record a keypress, returning the id of the shortcut it completes.
+    fn press(&mut self, ev: &web_sys::KeyboardEvent) -> Option<K> {
+        let combo = KeyCombo::from_event(ev);
+        if is_modifier_key(&combo.key) {
+            return None;
+        }
+        let now = js_sys::Date::now();
+        if now - self.last_pressed_at > SEQUENCE_TIMEOUT_MILLIS {
+            self.pressed.clear();
+        }
+        self.last_pressed_at = now;
+        self.pressed.push(combo);
+
+        let in_field = is_form_field(ev.target());
+        let scope = self.scopes.last();
+        let pressed = &self.pressed;
+        let found = self
+            .bindings
+            .iter()
+            .filter(|b| b.scope.is_none() || b.scope.as_ref() == scope)
+            .filter(|b| !in_field || b.sequence.iter().all(KeyCombo::has_command_modifier))
+            .filter(|b| b.sequence.len() <= pressed.len())
+            .filter(|b| {
+                let start = pressed.len() - b.sequence.len();
+                b.sequence
+                    .iter()
+                    .zip(&pressed[start..])
+                    .all(|(combo, pressed)| combo.matches(pressed))
+            })
+            .max_by_key(|b| b.sequence.len())
+            .map(|b| b.id.clone());
+
+        let longest = self.bindings.iter().map(|b| b.sequence.len()).max();
+        if found.is_some() {
+            ev.prevent_default();
+            self.pressed.clear();
+        } else if let Some(longest) = longest {
+            let excess = self.pressed.len().saturating_sub(longest);
+            self.pressed.drain(..excess);
+        }
+        found
+    }
+}
+
+/// A registry of keyboard shortcuts.
+///
+/// Shortcuts are either global or belong to a scope. Only global shortcuts
+/// and those in the innermost scope (see [`Shortcuts::push_scope`]) fire,
+/// so e.g. an open dialog can take over keys from the page beneath it.
+///
+/// Keys typed into inputs, textareas, selects and editable content are
+/// ignored, unless the shortcut uses Ctrl, Alt, Meta or `mod`.
+///
+/// Keys are listened for from creation until the registry is dropped, so
+/// shortcuts pressed between steps are queued rather than missed.
+pub struct Shortcuts<K> {
+    registry: Rc<RefCell<Registry<K>>>,
+    /// Ids of the shortcuts pressed, if there is a document.
+    fired: Option<QueuedListener<K>>,
+}
+
+impl<K: Clone + PartialEq + 'static> Default for Shortcuts<K> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<K: Clone + PartialEq + 'static> Shortcuts<K> {
+    /// Create an empty registry, listening for keys pressed anywhere in
+    /// the document.
+    pub fn new() -> Self {
+        let registry = Rc::new(RefCell::new(Registry {
+            bindings: vec![],
+            scopes: vec![],
+            pressed: vec![],
+            last_pressed_at: 0.0,
+        }));
+        let fired = web_sys::window()
+            .and_then(|w| w.document())
+            .map(|document| {
+                let registry = registry.clone();
+                QueuedListener::new(&document, &["keydown"], move |ev| {
+                    let ev = ev.dyn_into::<web_sys::KeyboardEvent>().ok()?;
+                    registry.borrow_mut().press(&ev)
+                })
+            });
+        Self { registry, fired }
+    }
+
+    fn insert(&mut self, id: K, shortcut: &str, scope: Option<&str>) -> Result<(), Error> {
+        let sequence = shortcut
+            .split(" then ")
+            .map(KeyCombo::parse)
+            .collect::<Result<Vec<_>, _>>()?;
+        self.registry.borrow_mut().bindings.push(Binding {
+            id,
+            sequence,
+            scope: scope.map(str::to_string),
+        });
+        Ok(())
+    }
+
+    /// Add a global shortcut.
+    ///
+    /// `shortcut` is a [`KeyCombo`] like `"mod+k"`, or a sequence of them
+    /// separated by `" then "`, like `"g then i"`.
+    pub fn add(&mut self, id: K, shortcut: &str) -> Result<(), Error> {
+        self.insert(id, shortcut, None)
+    }
+
+    /// Add a shortcut that only fires while `scope` is the innermost scope.
+    pub fn add_scoped(&mut self, id: K, shortcut: &str, scope: &str) -> Result<(), Error> {
+        self.insert(id, shortcut, Some(scope))
+    }
+
+    /// Remove every shortcut with the given id.
+    pub fn remove(&mut self, id: &K) {
+        self.registry
+            .borrow_mut()
+            .bindings
+            .retain(|binding| &binding.id != id);
+    }
+
+    /// Enter `scope`, making it the innermost scope.
+    pub fn push_scope(&mut self, scope: impl Into<String>) {
+        let mut registry = self.registry.borrow_mut();
+        registry.scopes.push(scope.into());
+        registry.pressed.clear();
+    }
+
+    /// Leave the innermost scope, returning it.
+    pub fn pop_scope(&mut self) -> Option<String> {
+        let mut registry = self.registry.borrow_mut();
+        registry.pressed.clear();
+        registry.scopes.pop()
+    }
+
+    /// Returns the innermost scope, if any.
+    pub fn scope(&self) -> Option<String> {
+        self.registry.borrow().scopes.last().cloned()
+    }
+
+    /// Await the next shortcut pressed anywhere in the document and return
+    /// its id.
+    pub async fn step(&mut self) -> K {
+        match &self.fired {
+            Some(fired) => fired.next().await,
+            None => std::future::pending().await,
+        }
+    }
+}
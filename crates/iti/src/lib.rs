@@ -13,16 +13,27 @@
 use mogwai::web::prelude::*;
 use wasm_bindgen::prelude::*;
 
+pub mod a11y;
+pub mod animate;
 pub mod assets;
+pub mod bus;
+pub mod capabilities;
 pub mod color;
 pub mod components;
 pub mod error;
 pub mod id;
+pub mod input;
+pub mod router;
+pub mod state;
 pub mod storage;
+pub mod theme;
+pub mod util;
 
 #[cfg(feature = "library")]
 mod library;
 
+pub use theme::{set_direction, Direction};
+
 #[cfg(feature = "library")]
 #[wasm_bindgen(start)]
 pub async fn main() {
@@ -1,49 +1,582 @@
 //! Sandboxed component gallery for browsing and testing components in isolation.
+use std::collections::VecDeque;
+
 use futures_lite::FutureExt;
 use js_sys::wasm_bindgen::UnwrapThrowExt;
-use mogwai::{prelude::*, web::body};
-
-use crate::components::{
-    button::library::ButtonLibraryItem,
-    button_group::library::ButtonGroupLibraryItem,
-    checkbox::library::CheckboxLibraryItem,
-    dropdown::library::DropdownLibraryItem,
-    list::{library::ListLibraryItem, List, ListEvent},
-    modal::library::ModalLibraryItem,
-    pane::{library::PaneRetainLibraryItem, RestartPanes},
-    platinum_kit::OverhaulLibraryItem,
-    progress::library::ProgressLibraryItem,
-    radio::library::RadioLibraryItem,
-    select::library::SelectLibraryItem,
-    slider::library::SliderLibraryItem,
-    toast::library::ToastLibraryItem,
+use mogwai::{
+    future::{race_all, MogwaiFutureExt},
+    prelude::*,
+    web::body,
+};
+
+use crate::{
+    bus::{Bus, Subscriber},
+    components::{
+        alert::library::AlertLibraryItem,
+        button::{library::ButtonLibraryItem, Button, ButtonSize},
+        button_group::library::ButtonGroupLibraryItem,
+        card::library::CardLibraryItem,
+        checkbox::{library::CheckboxLibraryItem, Checkbox},
+        color_mode::ColorModeToggle,
+        dropdown::library::DropdownLibraryItem,
+        list::{library::ListLibraryItem, List, ListEvent, ListItem, SelectionMode},
+        modal::library::ModalLibraryItem,
+        pane::library::PaneRetainLibraryItem,
+        platinum_kit::OverhaulLibraryItem,
+        progress::library::ProgressLibraryItem,
+        radio::library::RadioLibraryItem,
+        select::{library::SelectLibraryItem, Select},
+        slider::library::SliderLibraryItem,
+        toast::library::ToastLibraryItem,
+    },
+    router::{current_route, Route, Router},
+    storage::Key,
+    util::events::{debounced, Debounced},
 };
 
+/// Namespace of the library's storage keys.
+pub(crate) const STORAGE_NAMESPACE: &str = "iti-library";
+
+/// Index of the library item shown last.
+const SELECTED_ITEM: Key<usize> = Key::new(STORAGE_NAMESPACE, "selected-item");
+
+/// The route of a library item, e.g. `#/components::Modal`, so that a
+/// sandbox can be bookmarked and the back button returns to the previous
+/// one.
+#[derive(Clone, Debug, PartialEq)]
+pub struct LibraryRoute(String);
+
+impl LibraryRoute {
+    /// Create the route of the item named `name`, dropping generics and
+    /// replacing anything that isn't a letter, digit or `:` with `-`, e.g.
+    /// `"components::Panes<T> (Retain)"` becomes `components::Panes-Retain`.
+    pub fn new(name: &str) -> Self {
+        let mut slug = String::new();
+        let mut generics = 0;
+        for c in name.chars() {
+            match c {
+                '<' => generics += 1,
+                '>' => generics -= 1,
+                _ if generics > 0 => {}
+                c if c.is_ascii_alphanumeric() || c == ':' => slug.push(c),
+                _ if !slug.is_empty() && !slug.ends_with('-') => slug.push('-'),
+                _ => {}
+            }
+        }
+        LibraryRoute(slug.trim_end_matches('-').to_string())
+    }
+}
+
+impl Route for LibraryRoute {
+    fn from_path(path: &str) -> Option<Self> {
+        let slug = path.strip_prefix('/')?;
+        (!slug.is_empty()).then(|| LibraryRoute(slug.to_string()))
+    }
+
+    fn to_path(&self) -> String {
+        format!("/{}", self.0)
+    }
+}
+
+/// The value of a control in a [`Knobs`] panel.
+#[derive(Clone, Debug, PartialEq)]
+pub enum KnobValue {
+    Bool(bool),
+    /// Index of the chosen option.
+    Choice(usize),
+    Text(String),
+    Number(f64),
+}
+
+/// Event produced when a control in a [`Knobs`] panel changes.
+#[derive(Clone, Debug, PartialEq)]
+pub struct KnobEvent {
+    /// Index of the control, in the order the controls were added.
+    pub index: usize,
+    pub value: KnobValue,
+}
+
+/// A typed handle to a control in a [`Knobs`] panel.
+pub struct Knob<T> {
+    index: usize,
+    from_value: Box<dyn Fn(&KnobValue) -> Option<T>>,
+}
+
+impl<T> Knob<T> {
+    pub fn index(&self) -> usize {
+        self.index
+    }
+
+    /// Returns the new value if `event` is a change of this control.
+    pub fn changed(&self, event: &KnobEvent) -> Option<T> {
+        if event.index == self.index {
+            (self.from_value)(&event.value)
+        } else {
+            None
+        }
+    }
+}
+
+/// A text or number `<input>` in a [`Knobs`] panel.
+struct KnobInput<V: View> {
+    row: V::Element,
+    input: V::Element,
+    on_input: V::EventListener,
+}
+
+impl<V: View> KnobInput<V> {
+    fn new(label: &str, input_type: &str, value: String) -> Self {
+        let label_text = V::Text::new(label);
+        rsx! {
+            let row = div(class = "library-knob") {
+                label(class = "form-label") {
+                    {label_text}
+                }
+                let input = input(
+                    type = input_type,
+                    class = "form-control form-control-sm",
+                    value = value,
+                    on:input = on_input,
+                ) {}
+            }
+        }
+        Self {
+            row,
+            input,
+            on_input,
+        }
+    }
+}
+
+enum KnobControl<V: View> {
+    Toggle(Checkbox<V>),
+    Choice { row: V::Element, select: Select<V> },
+    Text(KnobInput<V>),
+    Number(KnobInput<V>),
+}
+
+impl<V: View> KnobControl<V> {
+    /// Awaits the next change, returning `None` if the new value can't be
+    /// read, e.g. a number input that was cleared.
+    async fn step(&mut self) -> Option<KnobValue> {
+        match self {
+            KnobControl::Toggle(checkbox) => Some(KnobValue::Bool(checkbox.step().await.checked)),
+            KnobControl::Choice { select, .. } => {
+                Some(KnobValue::Choice(select.step().await.index))
+            }
+            KnobControl::Text(input) => {
+                input.on_input.next().await;
+                input
+                    .input
+                    .dyn_el(|el: &web_sys::HtmlInputElement| el.value())
+                    .map(KnobValue::Text)
+            }
+            KnobControl::Number(input) => {
+                input.on_input.next().await;
+                input
+                    .input
+                    .dyn_el(|el: &web_sys::HtmlInputElement| el.value_as_number())
+                    .filter(|n| !n.is_nan())
+                    .map(KnobValue::Number)
+            }
+        }
+    }
+}
+
+/// A panel of controls bound to a sandboxed component's setters.
+///
+/// Each control is added with a label and an initial value, returning a
+/// typed [`Knob`] that picks its changes out of [`Knobs::step`]'s events:
+///
+/// ```ignore
+/// let mut knobs = Knobs::<V>::default();
+/// let outline = knobs.toggle("Outline", false);
+/// let flavor = knobs.select("Flavor", [("Primary", Flavor::Primary), ("Danger", Flavor::Danger)], 0);
+///
+/// let event = knobs.step().await;
+/// if let Some(is_outline) = outline.changed(&event) {
+///     button.set_is_outline(is_outline);
+/// }
+/// if let Some(flavor) = flavor.changed(&event) {
+///     button.set_flavor(Some(flavor));
+/// }
+/// ```
+#[derive(ViewChild)]
+pub struct Knobs<V: View> {
+    #[child]
+    wrapper: V::Element,
+    controls: Vec<KnobControl<V>>,
+}
+
+impl<V: View> Default for Knobs<V> {
+    fn default() -> Self {
+        rsx! {
+            let wrapper = div(class = "library-knobs") {
+                h4() { "Knobs" }
+            }
+        }
+        Self {
+            wrapper,
+            controls: vec![],
+        }
+    }
+}
+
+impl<V: View> Knobs<V> {
+    fn push<T>(
+        &mut self,
+        control: KnobControl<V>,
+        from_value: impl Fn(&KnobValue) -> Option<T> + 'static,
+    ) -> Knob<T> {
+        match &control {
+            KnobControl::Toggle(checkbox) => self.wrapper.append_child(checkbox),
+            KnobControl::Choice { row, .. } => self.wrapper.append_child(row),
+            KnobControl::Text(input) | KnobControl::Number(input) => {
+                self.wrapper.append_child(&input.row)
+            }
+        }
+        self.controls.push(control);
+        Knob {
+            index: self.controls.len() - 1,
+            from_value: Box::new(from_value),
+        }
+    }
+
+    /// Add a checkbox.
+    pub fn toggle(&mut self, label: impl AsRef<str>, value: bool) -> Knob<bool> {
+        let checkbox = Checkbox::new(label, value);
+        self.push(KnobControl::Toggle(checkbox), |value| match value {
+            KnobValue::Bool(b) => Some(*b),
+            _ => None,
+        })
+    }
+
+    /// Add a select of `options`, each a label and the value it stands
+    /// for, with the option at `selected` chosen.
+    pub fn select<T: Clone + 'static>(
+        &mut self,
+        label: impl AsRef<str>,
+        options: impl IntoIterator<Item = (impl AsRef<str>, T)>,
+        selected: usize,
+    ) -> Knob<T> {
+        let mut select = Select::new(None);
+        let values = options
+            .into_iter()
+            .map(|(option_label, value)| {
+                select.push_label(option_label);
+                value
+            })
+            .collect::<Vec<_>>();
+        select.set_selected_index(selected);
+        let label_text = V::Text::new(label);
+        rsx! {
+            let row = div(class = "library-knob") {
+                label(class = "form-label") {
+                    {label_text}
+                }
+                {&select}
+            }
+        }
+        self.push(
+            KnobControl::Choice { row, select },
+            move |value| match value {
+                KnobValue::Choice(index) => values.get(*index).cloned(),
+                _ => None,
+            },
+        )
+    }
+
+    /// Add a text input.
+    pub fn text(&mut self, label: impl AsRef<str>, value: impl AsRef<str>) -> Knob<String> {
+        let input = KnobInput::new(label.as_ref(), "text", value.as_ref().to_string());
+        self.push(KnobControl::Text(input), |value| match value {
+            KnobValue::Text(text) => Some(text.clone()),
+            _ => None,
+        })
+    }
+
+    /// Add a number input.
+    pub fn number(&mut self, label: impl AsRef<str>, value: f64) -> Knob<f64> {
+        let input = KnobInput::new(label.as_ref(), "number", value.to_string());
+        self.push(KnobControl::Number(input), |value| match value {
+            KnobValue::Number(n) => Some(*n),
+            _ => None,
+        })
+    }
+
+    /// Awaits the next change to any control.
+    ///
+    /// Never resolves if there are no controls.
+    pub async fn step(&mut self) -> KnobEvent {
+        if self.controls.is_empty() {
+            return std::future::pending().await;
+        }
+        loop {
+            let changes = self
+                .controls
+                .iter_mut()
+                .enumerate()
+                .map(|(index, control)| control.step().map(move |value| (index, value)));
+            if let (index, Some(value)) = race_all(changes).await {
+                return KnobEvent { index, value };
+            }
+        }
+    }
+}
+
+/// Most entries an [`EventLog`] keeps before dropping the oldest.
+const EVENT_LOG_CAPACITY: usize = 100;
+
+/// An event emitted by a sandboxed component, e.g. a click or a
+/// selection.
+#[derive(Clone, Debug, PartialEq)]
+pub struct SandboxEvent {
+    /// The component that emitted the event, e.g. `"Modal"`.
+    pub source: String,
+    pub message: String,
+}
+
+/// Record an event of a sandboxed component in the gallery's
+/// [`EventLog`].
+///
+/// Sent on the global [`Bus`], so sandboxes don't need a reference to the
+/// log.
+pub fn log_event(source: impl AsRef<str>, message: impl AsRef<str>) {
+    Bus::<SandboxEvent>::global().send(SandboxEvent {
+        source: source.as_ref().to_string(),
+        message: message.as_ref().to_string(),
+    });
+}
+
+/// Returns the current local time as `HH:MM:SS.mmm`.
+fn timestamp() -> String {
+    let now = js_sys::Date::new_0();
+    format!(
+        "{:02}:{:02}:{:02}.{:03}",
+        now.get_hours(),
+        now.get_minutes(),
+        now.get_seconds(),
+        now.get_milliseconds()
+    )
+}
+
+/// A timestamped list of the events sandboxed components emit, shown
+/// under the gallery's sandboxes.
+///
+/// Sandboxes add to it with [`log_event`].
+#[derive(ViewChild)]
+pub struct EventLog<V: View> {
+    #[child]
+    wrapper: V::Element,
+    list: V::Element,
+    entries: VecDeque<V::Element>,
+    clear_button: Button<V>,
+    events: Subscriber<SandboxEvent>,
+}
+
+impl<V: View> Default for EventLog<V> {
+    fn default() -> Self {
+        let mut clear_button = Button::new("Clear", None);
+        clear_button.set_has_icon(false);
+        clear_button.set_size(ButtonSize::Small);
+
+        rsx! {
+            let wrapper = div(class = "library-event-log") {
+                div(class = "d-flex align-items-center gap-2 mb-2") {
+                    h4(class = "mb-0") { "Events" }
+                    {&clear_button}
+                }
+                let list = ul(class = "list-group library-event-log-entries") {}
+            }
+        }
+
+        Self {
+            wrapper,
+            list,
+            entries: VecDeque::new(),
+            clear_button,
+            events: Bus::<SandboxEvent>::global().subscribe(),
+        }
+    }
+}
+
+impl<V: View> EventLog<V> {
+    /// Returns the number of entries.
+    pub fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    /// Returns `true` if there are no entries.
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+
+    /// Add an entry for `event`, stamped with the current time, dropping
+    /// the oldest entry if the log is full.
+    pub fn push(&mut self, event: &SandboxEvent) {
+        let time = V::Text::new(timestamp());
+        let source = V::Text::new(&event.source);
+        let message = V::Text::new(&event.message);
+        rsx! {
+            let entry = li(class = "list-group-item") {
+                span(class = "library-event-time") {
+                    {time}
+                }
+                strong() {
+                    {source}
+                }
+                " "
+                {message}
+            }
+        }
+        self.list.append_child(&entry);
+        self.entries.push_back(entry);
+        if self.entries.len() > EVENT_LOG_CAPACITY {
+            if let Some(oldest) = self.entries.pop_front() {
+                self.list.remove_child(&oldest);
+            }
+        }
+        // Keep the newest entry in view.
+        self.list.dyn_el(|el: &web_sys::Element| {
+            el.set_scroll_top(el.scroll_height());
+        });
+    }
+
+    /// Remove every entry.
+    pub fn clear(&mut self) {
+        for entry in self.entries.drain(..) {
+            self.list.remove_child(&entry);
+        }
+    }
+
+    /// Awaits the next event, or a click of the clear button, and updates
+    /// the log.
+    pub async fn step(&mut self) {
+        let event = self
+            .events
+            .step()
+            .map(Some)
+            .or(self.clear_button.step().map(|_| None))
+            .await;
+        match event {
+            Some(event) => self.push(&event),
+            None => self.clear(),
+        }
+    }
+}
+
+/// Milliseconds the search box waits for typing to pause before filtering.
+const SEARCH_DEBOUNCE_MILLIS: u64 = 150;
+
+/// An entry in the library's list: a component, or the heading of a
+/// category of components.
 #[derive(ViewChild)]
 pub struct LibraryListItem<V: View> {
     #[child]
     label: V::Element,
+    category: String,
+    title: String,
+    is_heading: bool,
+    /// The title, split around the highlighted search match.
+    before: V::Text,
+    matched: V::Text,
+    after: V::Text,
 }
 
 impl<V: View> LibraryListItem<V> {
-    pub fn new(title: impl AsRef<str>) -> Self {
-        let text = V::Text::new(title);
+    fn build(category: &str, title: &str, is_heading: bool) -> Self {
+        let before = V::Text::new(title);
+        let matched = V::Text::new("");
+        let after = V::Text::new("");
         rsx! {
             let label = label(
-                class = "stretched-link",
-                style:cursor = "pointer"
+                class = if is_heading { "library-category" } else { "stretched-link" },
+                style:cursor = if is_heading { "default" } else { "pointer" },
             ) {
-                {text}
+                {&before}
+                mark(class = "library-match") {
+                    {&matched}
+                }
+                {&after}
+            }
+        }
+        Self {
+            label,
+            category: category.to_string(),
+            title: title.to_string(),
+            is_heading,
+            before,
+            matched,
+            after,
+        }
+    }
+
+    pub fn new(category: impl AsRef<str>, title: impl AsRef<str>) -> Self {
+        Self::build(category.as_ref(), title.as_ref(), false)
+    }
+
+    /// Create the heading of `category`.
+    pub fn heading(category: impl AsRef<str>) -> Self {
+        Self::build(category.as_ref(), category.as_ref(), true)
+    }
+
+    pub fn category(&self) -> &str {
+        &self.category
+    }
+
+    pub fn title(&self) -> &str {
+        &self.title
+    }
+
+    pub fn is_heading(&self) -> bool {
+        self.is_heading
+    }
+
+    /// Returns the byte range of the first match of `query` in the title,
+    /// ignoring ASCII case.
+    fn find(&self, query: &str) -> Option<std::ops::Range<usize>> {
+        if query.is_empty() {
+            return None;
+        }
+        let start = self
+            .title
+            .to_ascii_lowercase()
+            .find(&query.to_ascii_lowercase())?;
+        Some(start..start + query.len())
+    }
+
+    /// Returns `true` if the title or category contains `query`, ignoring
+    /// ASCII case. Everything matches an empty query.
+    pub fn matches(&self, query: &str) -> bool {
+        let query = query.to_ascii_lowercase();
+        self.title.to_ascii_lowercase().contains(&query)
+            || self.category.to_ascii_lowercase().contains(&query)
+    }
+
+    /// Highlight the first match of `query` in the title, or nothing for
+    /// an empty query.
+    pub fn set_highlight(&self, query: &str) {
+        match self.find(query) {
+            Some(range) => {
+                self.before.set_text(&self.title[..range.start]);
+                self.matched.set_text(&self.title[range.clone()]);
+                self.after.set_text(&self.title[range.end..]);
+            }
+            None => {
+                self.before.set_text(&self.title);
+                self.matched.set_text("");
+                self.after.set_text("");
             }
         }
-        Self { label }
     }
 }
 
 pub enum LibraryListPane<V: View> {
     Default(V::Element),
+    Alert(AlertLibraryItem<V>),
     Button(ButtonLibraryItem<V>),
     ButtonGroup(ButtonGroupLibraryItem<V>),
+    Card(CardLibraryItem<V>),
     Checkbox(CheckboxLibraryItem<V>),
     Dropdown(DropdownLibraryItem<V>),
     List(ListLibraryItem<V>),
@@ -72,8 +605,10 @@ impl<V: View> ViewChild<V> for LibraryListPane<V> {
     ) -> AppendArg<V, impl Iterator<Item = std::borrow::Cow<'_, <V as View>::Node>>> {
         match self {
             LibraryListPane::Default(el) => el.as_boxed_append_arg(),
+            LibraryListPane::Alert(item) => item.as_boxed_append_arg(),
             LibraryListPane::Button(item) => item.as_boxed_append_arg(),
             LibraryListPane::ButtonGroup(item) => item.as_boxed_append_arg(),
+            LibraryListPane::Card(item) => item.as_boxed_append_arg(),
             LibraryListPane::Checkbox(item) => item.as_boxed_append_arg(),
             LibraryListPane::Dropdown(item) => item.as_boxed_append_arg(),
             LibraryListPane::List(item) => item.as_boxed_append_arg(),
@@ -94,8 +629,10 @@ impl<V: View> LibraryListPane<V> {
         let body = body();
         body.set_style("background-color", crate::color::LAVENDER);
         match self {
+            LibraryListPane::Alert(item) => item.step().await,
             LibraryListPane::Button(item) => item.step().await,
             LibraryListPane::ButtonGroup(item) => item.step().await,
+            LibraryListPane::Card(item) => item.step().await,
             LibraryListPane::Checkbox(item) => item.step().await,
             LibraryListPane::Dropdown(item) => item.step().await,
             LibraryListPane::List(item) => item.step().await,
@@ -116,93 +653,129 @@ impl<V: View> LibraryListPane<V> {
 
 /// The component library gallery.
 ///
-/// Presents a list of all components on the left and the selected component's
-/// sandbox on the right. Uses [`RestartPanes`] so each component is freshly
-/// recreated when selected.
+/// Presents a list of all components on the left, grouped by category under
+/// a search box, and the selected component's sandbox on the right. Uses
+/// a [`Router`] so each component is freshly recreated when selected, and
+/// the selected component is kept in the URL's hash. The sandbox's events
+/// are listed in an [`EventLog`] under it.
 #[derive(ViewChild)]
 pub struct Library<V: View> {
     #[child]
     pub main: V::Element,
+    search: V::Element,
+    search_input: Debounced<V>,
     library_list: List<V, LibraryListItem<V>>,
-    right_column: RestartPanes<V, LibraryListPane<V>>,
-    right_column_pane_ids: Vec<crate::id::Id<LibraryListPane<V>>>,
+    right_column: Router<V, LibraryRoute, LibraryListPane<V>>,
+    event_log: EventLog<V>,
+    /// The route of each list item, `None` for category headings.
+    routes: Vec<Option<LibraryRoute>>,
+    /// Listeners for the window's `hashchange` and `popstate` events,
+    /// kept between steps so that no change of the URL is missed.
+    url_changes: Option<(
+        <mogwai::web::Web as View>::EventListener,
+        <mogwai::web::Web as View>::EventListener,
+    )>,
 }
 
 impl<V: View> Default for Library<V> {
     fn default() -> Self {
         rsx! {
-            let right_column_wrapper = div(class = "col") {}
+            let right_column_wrapper = div() {}
         }
 
-        let right_column = RestartPanes::new(right_column_wrapper, LibraryListPane::default());
+        let right_column = Router::new(right_column_wrapper, LibraryListPane::default);
 
         rsx! {
             let main = main(class = "container-fluid mt-3") {
                 div(class = "row") {
                     div(class = "col-auto") {
+                        let search = input(
+                            type = "search",
+                            class = "form-control form-control-sm mb-2",
+                            placeholder = "Search components...",
+                            aria_label = "Search components",
+                            on:input = search_input,
+                        ) {}
                         let library_list = {List::default()}
                     }
-                    {&right_column}
+                    div(class = "col") {
+                        {&right_column}
+                        let event_log = {EventLog::default()}
+                    }
                 }
             }
         }
 
         let mut lib = Self {
             main,
+            search,
+            search_input: debounced::<V>(search_input, SEARCH_DEBOUNCE_MILLIS),
             library_list,
             right_column,
-            right_column_pane_ids: vec![],
+            event_log,
+            routes: vec![],
+            url_changes: web_sys::window()
+                .map(|window| (window.listen("hashchange"), window.listen("popstate"))),
         };
+        lib.library_list.set_selection_mode(SelectionMode::Single);
+
+        lib.add_item("Feedback", "components::Alert", || {
+            LibraryListPane::Alert(Default::default())
+        });
 
-        lib.add_item("components::Button", || {
+        lib.add_item("Actions", "components::Button", || {
             LibraryListPane::Button(Default::default())
         });
 
-        lib.add_item("components::ButtonGroup<T>", || {
+        lib.add_item("Actions", "components::ButtonGroup<T>", || {
             LibraryListPane::ButtonGroup(Default::default())
         });
 
-        lib.add_item("components::Checkbox", || {
+        lib.add_item("Layout", "components::Card", || {
+            LibraryListPane::Card(Default::default())
+        });
+
+        lib.add_item("Forms", "components::Checkbox", || {
             LibraryListPane::Checkbox(Default::default())
         });
 
-        lib.add_item("components::Dropdown", || {
+        lib.add_item("Actions", "components::Dropdown", || {
             LibraryListPane::Dropdown(Default::default())
         });
 
-        lib.add_item("components::List<T>", || {
+        lib.add_item("Layout", "components::List<T>", || {
             LibraryListPane::List(Default::default())
         });
 
-        lib.add_item("components::Modal", || {
+        lib.add_item("Feedback", "components::Modal", || {
             LibraryListPane::Modal(Default::default())
         });
 
-        lib.add_item("components::Progress", || {
+        lib.add_item("Feedback", "components::Progress", || {
             LibraryListPane::Progress(Default::default())
         });
 
-        lib.add_item("components::RadioGroup", || {
+        lib.add_item("Forms", "components::RadioGroup", || {
             LibraryListPane::Radio(Default::default())
         });
 
-        lib.add_item("components::Select", || {
+        lib.add_item("Forms", "components::Select", || {
             LibraryListPane::Select(Default::default())
         });
 
-        lib.add_item("components::Slider", || {
+        lib.add_item("Forms", "components::Slider", || {
             LibraryListPane::Slider(Default::default())
         });
 
-        lib.add_item("components::Panes<T> (Retain)", || {
+        lib.add_item("Layout", "components::Panes<T> (Retain)", || {
             LibraryListPane::PaneRetain(Default::default())
         });
 
-        lib.add_item("components::Toast", || {
+        lib.add_item("Feedback", "components::Toast", || {
             LibraryListPane::Toast(Default::default())
         });
 
-        lib.add_item("Platinum Kit", || {
+        lib.add_item("Themes", "Platinum Kit", || {
             LibraryListPane::Overhaul(Default::default())
         });
 
@@ -211,44 +784,171 @@ impl<V: View> Default for Library<V> {
 }
 
 impl<V: View> Library<V> {
-    pub fn add_item(&mut self, name: &str, f: impl FnMut() -> LibraryListPane<V> + 'static) {
-        let item = LibraryListItem::new(name);
-        self.library_list.push(item);
-        let id = self.right_column.add_pane(f);
-        self.right_column_pane_ids.push(id);
+    /// Add a component to the end of `category`, creating the category's
+    /// heading at the end of the list if it is new.
+    pub fn add_item(
+        &mut self,
+        category: &str,
+        name: &str,
+        f: impl FnMut() -> LibraryListPane<V> + 'static,
+    ) {
+        let last_in_category = self
+            .library_list
+            .iter()
+            .enumerate()
+            .filter(|(_, item)| item.inner().category() == category)
+            .map(|(index, _)| index)
+            .last();
+        let index = match last_in_category {
+            Some(index) => index + 1,
+            None => {
+                self.library_list.push(LibraryListItem::heading(category));
+                if let Some(heading) = self.library_list.get_mut(self.library_list.len() - 1) {
+                    heading.set_disabled(true);
+                }
+                self.routes.push(None);
+                self.library_list.len()
+            }
+        };
+        self.library_list
+            .insert(index, LibraryListItem::new(category, name));
+        let route = LibraryRoute::new(name);
+        self.right_column.add_route(route.clone(), f);
+        self.routes.insert(index, Some(route));
     }
 
-    pub fn deselect_all(&mut self) {
-        for item in self.library_list.iter_mut() {
-            item.set_is_active(false);
+    /// Show only the components whose name or category contains `query`,
+    /// ignoring case, with the match highlighted. An empty query shows
+    /// every component.
+    pub fn set_search(&mut self, query: &str) {
+        let query = query.trim().to_string();
+        for item in self.library_list.iter() {
+            item.inner().set_highlight(&query);
         }
+        if query.is_empty() {
+            self.library_list.clear_filter();
+            return;
+        }
+        let categories = self
+            .library_list
+            .iter()
+            .map(ListItem::inner)
+            .filter(|item| !item.is_heading() && item.matches(&query))
+            .map(|item| item.category().to_string())
+            .collect::<Vec<_>>();
+        self.library_list.set_filter(move |item| {
+            if item.is_heading() {
+                categories
+                    .iter()
+                    .any(|category| category == item.category())
+            } else {
+                item.matches(&query)
+            }
+        });
+    }
+
+    pub fn deselect_all(&mut self) {
+        self.library_list.clear_selection();
     }
 
+    /// Select the component at `index` and show its sandbox, navigating
+    /// to its route. Headings can't be selected.
     pub fn select_item(&mut self, index: usize) {
-        self.deselect_all();
-        if let Some(item) = self.library_list.get_mut(index) {
-            item.set_is_active(true);
-            if let Some(id) = self.right_column_pane_ids.get(index) {
-                let _ = self.right_column.select(id);
+        if let Some(Some(route)) = self.routes.get(index) {
+            self.library_list.set_selected([index]);
+            self.right_column.navigate(route.clone());
+            self.event_log.clear();
+            Self::save_selected_item(index);
+        }
+    }
+
+    /// Select the list item of the route in the URL's hash, whose sandbox
+    /// the router shows. Returns `false` if the hash has no known route.
+    pub fn select_current_route(&mut self) -> bool {
+        let route = current_route::<LibraryRoute>();
+        let index =
+            route.and_then(|route| self.routes.iter().position(|r| r.as_ref() == Some(&route)));
+        match index {
+            Some(index) => {
+                self.library_list.set_selected([index]);
+                Self::save_selected_item(index);
+                true
             }
+            None => false,
+        }
+    }
+
+    fn save_selected_item(index: usize) {
+        if V::is_view::<mogwai::web::Web>() {
+            SELECTED_ITEM.set(&index).unwrap_throw();
         }
     }
 
     pub async fn step(&mut self) {
+        enum Action<V: View> {
+            Pane,
+            List(ListEvent<V>),
+            Search,
+            UrlChanged,
+        }
+
         let pane_fut = async {
             self.right_column.current_pane_mut().step().await;
-            None
+            Action::Pane
         };
         let list_fut = async {
             let event = self.library_list.step().await;
-            Some(event)
+            Action::List(event)
+        };
+        let search_fut = async {
+            self.search_input.step().await;
+            Action::Search
+        };
+        // Logging an event must not cancel the sandbox's step, so the log
+        // is stepped for as long as the race runs and never wins it.
+        let event_log_fut = async {
+            loop {
+                self.event_log.step().await;
+            }
         };
-        if let Some(ListEvent { index, event: _ }) = pane_fut.or(list_fut).await {
-            log::info!("loading index {index}");
-            self.select_item(index);
-            if V::is_view::<mogwai::web::Web>() {
-                crate::storage::set_item("selected-item", &index).unwrap_throw();
+        // The router's own step can't be raced against its current pane's,
+        // so listen for the URL changing here and sync the router after.
+        let url_fut = async {
+            match &self.url_changes {
+                Some((hashchange, popstate)) => {
+                    let _ = hashchange.next().or(popstate.next()).await;
+                }
+                None => std::future::pending().await,
+            }
+            Action::UrlChanged
+        };
+        match pane_fut
+            .or(list_fut)
+            .or(search_fut)
+            .or(event_log_fut)
+            .or(url_fut)
+            .await
+        {
+            Action::List(ListEvent::SelectionChanged { index, .. }) => {
+                log::info!("loading index {index}");
+                self.select_item(index);
+            }
+            Action::UrlChanged => {
+                if self.right_column.sync() {
+                    self.event_log.clear();
+                    if !self.select_current_route() {
+                        self.deselect_all();
+                    }
+                }
             }
+            Action::Search => {
+                let query = self
+                    .search
+                    .dyn_el(|el: &web_sys::HtmlInputElement| el.value())
+                    .unwrap_or_default();
+                self.set_search(&query);
+            }
+            Action::Pane | Action::List(_) => {}
         }
     }
 }
@@ -260,15 +960,20 @@ pub async fn main() {
     log::info!("Starting up the iti component library...");
 
     let mut lib = Library::<Web>::default();
-    let storage = mogwai::web::window()
-        .local_storage()
-        .unwrap_throw()
-        .unwrap_throw();
-    if let Some(item_index_str) = storage.get_item("selected-item").unwrap_throw() {
-        let index: usize = item_index_str.parse().unwrap_throw();
-        lib.select_item(index);
+    SELECTED_ITEM
+        .migrate_from("selected-item")
+        .unwrap_or_else(|e| log::warn!("could not migrate '{SELECTED_ITEM}': {e}"));
+    if !lib.select_current_route() {
+        if let Some(index) = SELECTED_ITEM.get().unwrap_throw() {
+            lib.select_item(index);
+        }
     }
 
+    let mut color_mode = ColorModeToggle::<Web>::default();
+    color_mode.set_style("position", "fixed");
+    color_mode.set_style("top", "0.5em");
+    color_mode.set_style("right", "0.5em");
+    mogwai::web::body().append_child(&color_mode);
     mogwai::web::body().append_child(&lib);
 
     wasm_bindgen_futures::spawn_local(async move {
@@ -276,4 +981,9 @@ pub async fn main() {
             lib.step().await;
         }
     });
+    wasm_bindgen_futures::spawn_local(async move {
+        loop {
+            color_mode.step().await;
+        }
+    });
 }
@@ -0,0 +1,230 @@
+//! Hash-based routing for single-page apps.
+//!
+//! Routes are app-defined types implementing [`Route`], kept in the URL's
+//! hash (`#/users/3`) so they work without any server configuration. A
+//! [`Router`] shows a pane for the current route, rebuilding it from a
+//! factory on each navigation like [`RestartPanes`].
+//!
+//! ```ignore
+//! #[derive(Clone, PartialEq)]
+//! enum Page {
+//!     Home,
+//!     Settings,
+//! }
+//!
+//! impl Route for Page {
+//!     fn from_path(path: &str) -> Option<Self> {
+//!         match path {
+//!             "/" => Some(Page::Home),
+//!             "/settings" => Some(Page::Settings),
+//!             _ => None,
+//!         }
+//!     }
+//!
+//!     fn to_path(&self) -> String {
+//!         match self {
+//!             Page::Home => "/".into(),
+//!             Page::Settings => "/settings".into(),
+//!         }
+//!     }
+//! }
+//! ```
+//!
+//! State that belongs in the query string rather than the route, such as a
+//! filter or page number, can be kept there with [`query::QueryParam`].
+
+pub mod query;
+
+use mogwai::prelude::*;
+
+use crate::{components::pane::RestartPanes, id::Id, util::queue::QueuedListener};
+
+/// A location in the app, parsed from and written to the URL's hash.
+pub trait Route: Clone + PartialEq + 'static {
+    /// Parse a path such as `"/users/3"`, returning `None` if it isn't a
+    /// route of this type.
+    fn from_path(path: &str) -> Option<Self>;
+
+    /// Returns the path of this route, starting with `/`.
+    fn to_path(&self) -> String;
+}
+
+/// Returns the path in the URL's hash, or `"/"` if there is none.
+pub fn current_path() -> String {
+    let hash = web_sys::window()
+        .and_then(|w| w.location().hash().ok())
+        .unwrap_or_default();
+    match hash.trim_start_matches('#') {
+        "" => "/".to_string(),
+        path => path.to_string(),
+    }
+}
+
+/// Returns the route in the URL's hash, if it parses as an `R`.
+pub fn current_route<R: Route>() -> Option<R> {
+    R::from_path(&current_path())
+}
+
+/// Returns the `href` of a link to `route`, e.g. `"#/settings"`.
+pub fn href(route: &impl Route) -> String {
+    format!("#{}", route.to_path())
+}
+
+/// Navigate to `route` by setting the URL's hash.
+///
+/// This adds a history entry, so the back button returns to the previous
+/// route.
+pub fn navigate(route: &impl Route) {
+    if let Some(window) = web_sys::window() {
+        let _ = window.location().set_hash(&route.to_path());
+    }
+}
+
+/// Create an `<a>` linking to `route`.
+pub fn link<V: View>(route: &impl Route, text: impl AsRef<str>) -> V::Element {
+    let text = V::Text::new(text);
+    rsx! {
+        let link = a() {
+            {text}
+        }
+    }
+    link.set_property("href", &href(route));
+    link
+}
+
+/// Shows a pane for the route in the URL's hash.
+///
+/// Each route added with [`Router::add_route`] has a pane factory; the pane
+/// is built fresh each time the route is navigated to. Paths that don't
+/// match any added route show the "not found" pane.
+pub struct Router<V: View, R, T> {
+    panes: RestartPanes<V, T>,
+    routes: Vec<(R, Id<T>)>,
+    not_found: Id<T>,
+    route: Option<R>,
+    /// `hashchange` and `popstate` events, if there is a window.
+    url_changes: Option<QueuedListener<()>>,
+}
+
+impl<V: View, R: Route, T: ViewChild<V>> ViewChild<V> for Router<V, R, T> {
+    fn as_append_arg(
+        &self,
+    ) -> AppendArg<V, impl Iterator<Item = std::borrow::Cow<'_, <V as View>::Node>>> {
+        self.panes.as_append_arg()
+    }
+}
+
+impl<V: View, R: Route, T: ViewChild<V>> Router<V, R, T> {
+    /// Create a router showing its panes in `wrapper`.
+    ///
+    /// `not_found` builds the pane shown for paths that don't match a route.
+    pub fn new(wrapper: V::Element, mut not_found: impl FnMut() -> T + 'static) -> Self {
+        let mut panes = RestartPanes::new(wrapper, not_found());
+        let not_found = panes.add_pane(not_found);
+        Self {
+            panes,
+            routes: vec![],
+            not_found,
+            route: None,
+            url_changes: web_sys::window().map(|window| {
+                QueuedListener::new(&window, &["hashchange", "popstate"], |_| Some(()))
+            }),
+        }
+    }
+
+    /// Add a route whose pane is built by `create`.
+    ///
+    /// If the URL is already at `route` its pane is shown right away.
+    pub fn add_route(&mut self, route: R, create: impl FnMut() -> T + 'static) {
+        let id = self.panes.add_pane(create);
+        self.routes.push((route, id));
+        let current = current_route::<R>();
+        if current.is_some() && current != self.route {
+            self.show(current);
+        }
+    }
+
+    fn show(&mut self, route: Option<R>) {
+        let id = route
+            .as_ref()
+            .and_then(|route| self.routes.iter().find(|(r, _)| r == route))
+            .map(|(_, id)| id.clone())
+            .unwrap_or_else(|| self.not_found.clone());
+        let found = id != self.not_found;
+        let _ = self.panes.select(&id);
+        self.route = if found { route } else { None };
+    }
+
+    /// Returns the route being shown, or `None` if the "not found" pane is
+    /// shown.
+    pub fn route(&self) -> Option<&R> {
+        self.route.as_ref()
+    }
+
+    /// Navigate to `route` and show its pane.
+    pub fn navigate(&mut self, route: R) {
+        navigate(&route);
+        self.show(Some(route));
+    }
+
+    /// Returns the pane being shown.
+    pub fn current_pane(&self) -> &T {
+        self.panes.current_pane()
+    }
+
+    /// Returns the pane being shown.
+    pub fn current_pane_mut(&mut self) -> &mut T {
+        self.panes.current_pane_mut()
+    }
+
+    /// Returns the panes container, e.g. to keep panes alive with
+    /// [`RestartPanes::set_keep_alive`].
+    pub fn get_panes_mut(&mut self) -> &mut RestartPanes<V, T> {
+        &mut self.panes
+    }
+
+    /// Show the pane for the route in the URL, unless it is shown already.
+    /// Returns `true` if the pane was shown.
+    ///
+    /// A URL without a known route shows the "not found" pane, unless it
+    /// is shown already.
+    ///
+    /// [`Router::step`] calls this whenever the URL changes. Call it
+    /// yourself after listening for `hashchange` when the router's `step`
+    /// can't be raced, e.g. against its own current pane's `step`.
+    /// Changes the router heard in the meantime are then skipped by its
+    /// next `step`, as the pane for them is shown already.
+    pub fn sync(&mut self) -> bool {
+        let route = current_route::<R>();
+        let known = route
+            .as_ref()
+            .filter(|route| self.routes.iter().any(|(r, _)| r == *route))
+            .cloned();
+        if known != self.route {
+            self.show(known);
+            true
+        } else {
+            false
+        }
+    }
+
+    /// Await the URL changing to another route, show its pane and return
+    /// it.
+    ///
+    /// Returns `None` when the new path doesn't match any added route and
+    /// the "not found" pane is shown.
+    pub async fn step(&mut self) -> Option<R> {
+        loop {
+            match &self.url_changes {
+                Some(url_changes) => {
+                    url_changes.next().await;
+                    url_changes.clear();
+                }
+                None => std::future::pending().await,
+            }
+            if self.sync() {
+                return self.route.clone();
+            }
+        }
+    }
+}
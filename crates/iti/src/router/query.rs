@@ -0,0 +1,119 @@
+//! Component state kept in the URL's query string.
+//!
+//! A [`QueryParam`] mirrors a value, such as the selected tab or the
+//! current page of a table, in a named query parameter (`?page=3`). The
+//! value is restored from the URL when the page loads, so it survives
+//! reloads and can be shared as a link — like the localStorage persistence
+//! in [`crate::storage`], but visible in the address bar.
+//!
+//! Values are stored as JSON, except that strings are stored as-is, so
+//! `?filter=red` rather than `?filter="red"`.
+
+use mogwai::prelude::*;
+use serde::{de::DeserializeOwned, Serialize};
+use wasm_bindgen::JsValue;
+
+fn location_url() -> Option<web_sys::Url> {
+    let href = web_sys::window()?.location().href().ok()?;
+    web_sys::Url::new(&href).ok()
+}
+
+/// Returns the raw value of the query parameter `name`, if present.
+pub fn get_param(name: &str) -> Option<String> {
+    location_url()?.search_params().get(name)
+}
+
+/// Set the query parameter `name`, or remove it if `value` is `None`.
+///
+/// The URL is changed with `history.replaceState`, so no history entry is
+/// added and the page doesn't reload.
+pub fn set_param(name: &str, value: Option<&str>) {
+    let Some(url) = location_url() else {
+        return;
+    };
+    let params = url.search_params();
+    match value {
+        Some(value) => params.set(name, value),
+        None => params.delete(name),
+    }
+    if let Some(history) = web_sys::window().and_then(|w| w.history().ok()) {
+        let _ = history.replace_state_with_url(&JsValue::NULL, "", Some(&url.href()));
+    }
+}
+
+fn decode<T: DeserializeOwned>(raw: &str) -> Option<T> {
+    serde_json::from_str(raw)
+        .or_else(|_| serde_json::from_value(serde_json::Value::String(raw.to_string())))
+        .ok()
+}
+
+fn encode<T: Serialize>(value: &T) -> Option<String> {
+    match serde_json::to_value(value) {
+        Ok(serde_json::Value::String(s)) => Some(s),
+        Ok(json) => Some(json.to_string()),
+        Err(e) => {
+            log::warn!("could not serialize query value: {e}");
+            None
+        }
+    }
+}
+
+/// A value mirrored in a named query parameter.
+///
+/// While the value equals its default the parameter is left out of the
+/// URL.
+pub struct QueryParam<T> {
+    name: String,
+    default: T,
+    value: Proxy<T>,
+}
+
+impl<T: Clone + PartialEq + Serialize + DeserializeOwned> QueryParam<T> {
+    /// Create a parameter called `name`, restoring its value from the URL.
+    ///
+    /// If the parameter is missing or can't be parsed as a `T` the value is
+    /// `default`.
+    pub fn new(name: impl Into<String>, default: T) -> Self {
+        let name = name.into();
+        let value = get_param(&name)
+            .and_then(|raw| decode(&raw))
+            .unwrap_or_else(|| default.clone());
+        Self {
+            name,
+            default,
+            value: Proxy::new(value),
+        }
+    }
+
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    pub fn get(&self) -> &T {
+        &self.value
+    }
+
+    /// Set the value and write it to the URL.
+    pub fn set(&mut self, value: T) {
+        if value == self.default {
+            set_param(&self.name, None);
+        } else if let Some(raw) = encode(&value) {
+            set_param(&self.name, Some(&raw));
+        }
+        self.value.set(value);
+    }
+
+    /// Restore the default value and remove the parameter from the URL.
+    pub fn reset(&mut self) {
+        self.set(self.default.clone());
+    }
+
+    /// Returns the reactive value, for binding in `rsx!`.
+    ///
+    /// ## Note
+    /// Change the value with [`QueryParam::set`], which also writes it to
+    /// the URL.
+    pub fn get_proxy_mut(&mut self) -> &mut Proxy<T> {
+        &mut self.value
+    }
+}
@@ -0,0 +1,195 @@
+//! Undo and redo for app models.
+//!
+//! [`History`] owns a model and records each change to it, either as a
+//! snapshot of the previous model ([`History::push`]) or as a reversible
+//! [`Command`] ([`History::apply`]). The two can be mixed freely.
+//!
+//! For ready-made buttons see
+//! [`UndoRedoButtons`](crate::components::undo::UndoRedoButtons).
+
+use std::collections::VecDeque;
+
+/// A reversible change to a model of type `T`.
+pub trait Command<T> {
+    /// Make the change.
+    fn apply(&mut self, model: &mut T);
+
+    /// Undo a change made by [`Command::apply`].
+    fn revert(&mut self, model: &mut T);
+}
+
+enum Entry<T> {
+    /// The model as it was on the other side of this entry.
+    Snapshot(T),
+    Command(Box<dyn Command<T>>),
+}
+
+/// A change to a [`History`], reported by [`History::take_event`].
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum HistoryEvent {
+    /// A change was recorded with [`History::push`] or [`History::apply`].
+    Changed,
+    Undone,
+    Redone,
+    /// The undo and redo stacks were emptied with [`History::clear`].
+    Cleared,
+}
+
+/// A model with undo and redo stacks.
+pub struct History<T> {
+    model: T,
+    undo: VecDeque<Entry<T>>,
+    redo: Vec<Entry<T>>,
+    limit: Option<usize>,
+    /// Queued events, or `None` if events are off.
+    events: Option<VecDeque<HistoryEvent>>,
+}
+
+impl<T: Default> Default for History<T> {
+    fn default() -> Self {
+        Self::new(T::default())
+    }
+}
+
+impl<T> History<T> {
+    pub fn new(model: T) -> Self {
+        Self {
+            model,
+            undo: VecDeque::new(),
+            redo: vec![],
+            limit: None,
+            events: None,
+        }
+    }
+
+    /// Returns the model.
+    pub fn get(&self) -> &T {
+        &self.model
+    }
+
+    /// Keep at most `limit` changes to undo, dropping the oldest, or any
+    /// number with `None` (the default).
+    pub fn set_limit(&mut self, limit: Option<usize>) {
+        self.limit = limit;
+        self.enforce_limit();
+    }
+
+    pub fn limit(&self) -> Option<usize> {
+        self.limit
+    }
+
+    fn enforce_limit(&mut self) {
+        if let Some(limit) = self.limit {
+            while self.undo.len() > limit {
+                self.undo.pop_front();
+            }
+        }
+    }
+
+    fn record(&mut self, entry: Entry<T>) {
+        self.undo.push_back(entry);
+        self.redo.clear();
+        self.enforce_limit();
+        self.emit(HistoryEvent::Changed);
+    }
+
+    /// Replace the model with `model`, recording a snapshot of the old one.
+    ///
+    /// This clears the redo stack.
+    pub fn push(&mut self, model: T) {
+        let old = std::mem::replace(&mut self.model, model);
+        self.record(Entry::Snapshot(old));
+    }
+
+    /// Apply `command` to the model and record it.
+    ///
+    /// This clears the redo stack.
+    pub fn apply(&mut self, mut command: impl Command<T> + 'static) {
+        command.apply(&mut self.model);
+        self.record(Entry::Command(Box::new(command)));
+    }
+
+    /// Returns `true` if there is a change to undo.
+    pub fn can_undo(&self) -> bool {
+        !self.undo.is_empty()
+    }
+
+    /// Returns `true` if there is an undone change to redo.
+    pub fn can_redo(&self) -> bool {
+        !self.redo.is_empty()
+    }
+
+    /// Undo the last change, returning `false` if there was none.
+    pub fn undo(&mut self) -> bool {
+        let Some(entry) = self.undo.pop_back() else {
+            return false;
+        };
+        let entry = self.reverse(entry, false);
+        self.redo.push(entry);
+        self.emit(HistoryEvent::Undone);
+        true
+    }
+
+    /// Redo the last undone change, returning `false` if there was none.
+    pub fn redo(&mut self) -> bool {
+        let Some(entry) = self.redo.pop() else {
+            return false;
+        };
+        let entry = self.reverse(entry, true);
+        self.undo.push_back(entry);
+        self.emit(HistoryEvent::Redone);
+        true
+    }
+
+    /// Cross `entry`, returning the entry that crosses back.
+    fn reverse(&mut self, entry: Entry<T>, is_redo: bool) -> Entry<T> {
+        match entry {
+            Entry::Snapshot(model) => Entry::Snapshot(std::mem::replace(&mut self.model, model)),
+            Entry::Command(mut command) => {
+                if is_redo {
+                    command.apply(&mut self.model);
+                } else {
+                    command.revert(&mut self.model);
+                }
+                Entry::Command(command)
+            }
+        }
+    }
+
+    /// Forget every recorded change, keeping the model as it is.
+    pub fn clear(&mut self) {
+        self.undo.clear();
+        self.redo.clear();
+        self.emit(HistoryEvent::Cleared);
+    }
+
+    fn emit(&mut self, event: HistoryEvent) {
+        if let Some(events) = self.events.as_mut() {
+            events.push_back(event);
+        }
+    }
+
+    /// Turn [`HistoryEvent`]s on or off. They are off by default.
+    ///
+    /// Turning them off drops any events not yet taken with
+    /// [`History::take_event`].
+    pub fn set_has_events(&mut self, has_events: bool) {
+        if has_events != self.events.is_some() {
+            self.events = has_events.then(VecDeque::new);
+        }
+    }
+
+    pub fn has_events(&self) -> bool {
+        self.events.is_some()
+    }
+
+    /// Take the oldest change to the history not yet taken, if any.
+    ///
+    /// Changes are queued as they are made, so calling this until it
+    /// returns `None` after each mutation sees every one of them. Always
+    /// returns `None` unless events were turned on with
+    /// [`History::set_has_events`].
+    pub fn take_event(&mut self) -> Option<HistoryEvent> {
+        self.events.as_mut()?.pop_front()
+    }
+}
@@ -0,0 +1,228 @@
+//! Typed object stores in IndexedDB.
+//!
+//! For data too large or too structured for localStorage, e.g. cached API
+//! responses. Like the rest of [`storage`](super), values are stored as
+//! JSON, under string keys:
+//!
+//! ```ignore
+//! let db = idb::Database::open("my-app", 1, &["users"]).await?;
+//! let users = db.store::<User>("users");
+//! users.put("ada", &ada).await?;
+//! let ada = users.get("ada").await?;
+//! ```
+
+use std::marker::PhantomData;
+
+use mogwai::web::prelude::wasm_bindgen_futures::JsFuture;
+use serde::{de::DeserializeOwned, Serialize};
+use snafu::prelude::*;
+use wasm_bindgen::{prelude::Closure, JsCast, JsValue};
+
+/// All IndexedDB errors.
+#[derive(Debug, Snafu)]
+pub enum Error {
+    #[snafu(display("No IndexedDB"))]
+    NoIndexedDb,
+    #[snafu(display("IndexedDB request failed: {message}"))]
+    Request { message: String },
+    #[snafu(display("Error parsing IndexedDB value for key:'{key}' value:'{value}': {source}"))]
+    Parse {
+        key: String,
+        value: String,
+        source: serde_json::Error,
+    },
+    #[snafu(display("Error serializing IndexedDB value for key:'{key}': {source}"))]
+    Serialize {
+        key: String,
+        source: serde_json::Error,
+    },
+}
+
+/// Returns an error for a failed call into the IndexedDB API.
+fn js_error(e: JsValue) -> Error {
+    let message = e
+        .dyn_ref::<js_sys::Error>()
+        .map(|e| String::from(e.message()))
+        .unwrap_or_else(|| format!("{e:?}"));
+    Error::Request { message }
+}
+
+/// Await `request`, returning its result.
+async fn complete(request: &web_sys::IdbRequest) -> Result<JsValue, Error> {
+    let promise = js_sys::Promise::new(&mut |resolve, reject| {
+        request.set_onsuccess(Some(&resolve));
+        request.set_onerror(Some(&reject));
+    });
+    let outcome = JsFuture::from(promise).await;
+    request.set_onsuccess(None);
+    request.set_onerror(None);
+    match outcome {
+        Ok(_) => request.result().map_err(js_error),
+        Err(_) => {
+            let message = request
+                .error()
+                .ok()
+                .flatten()
+                .map(|e| e.message())
+                .unwrap_or_else(|| "unknown error".to_string());
+            RequestSnafu { message }.fail()
+        }
+    }
+}
+
+/// An open IndexedDB database.
+pub struct Database {
+    db: web_sys::IdbDatabase,
+}
+
+impl Database {
+    /// Open the database `name`, creating it if needed.
+    ///
+    /// Bump `version` whenever `stores` changes: stores that don't exist
+    /// yet are only created while the database is upgraded to a new
+    /// version. Stores are never deleted.
+    pub async fn open(name: &str, version: u32, stores: &[&str]) -> Result<Self, Error> {
+        let factory = web_sys::window()
+            .and_then(|window| window.indexed_db().ok()?)
+            .context(NoIndexedDbSnafu)?;
+        let request = factory.open_with_u32(name, version).map_err(js_error)?;
+
+        let stores: Vec<String> = stores.iter().map(|s| s.to_string()).collect();
+        let on_upgrade = Closure::<dyn FnMut(JsValue)>::new({
+            let request = request.clone();
+            move |_| {
+                let Ok(db) = request.result() else {
+                    return;
+                };
+                let db: web_sys::IdbDatabase = db.unchecked_into();
+                let existing = db.object_store_names();
+                for store in stores.iter().filter(|s| !existing.contains(s)) {
+                    if let Err(e) = db.create_object_store(store) {
+                        log::warn!("could not create object store '{store}': {e:?}");
+                    }
+                }
+            }
+        });
+        request.set_onupgradeneeded(Some(on_upgrade.as_ref().unchecked_ref()));
+        let db = complete(&request).await;
+        request.set_onupgradeneeded(None);
+
+        Ok(Self {
+            db: db?.unchecked_into(),
+        })
+    }
+
+    /// Returns the object store `name`, holding values of type `T`.
+    ///
+    /// ## Note
+    /// The store must have been created by [`Database::open`]; otherwise
+    /// every request on it fails.
+    pub fn store<T>(&self, name: &str) -> Store<T> {
+        Store {
+            db: self.db.clone(),
+            name: name.to_string(),
+            _value: PhantomData,
+        }
+    }
+
+    /// Close the connection. Requests on its stores fail afterwards.
+    pub fn close(&self) {
+        self.db.close();
+    }
+}
+
+/// An object store with JSON values of type `T`, from
+/// [`Database::store`].
+pub struct Store<T> {
+    db: web_sys::IdbDatabase,
+    name: String,
+    _value: PhantomData<T>,
+}
+
+impl<T: Serialize + DeserializeOwned> Store<T> {
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    fn object_store(&self, is_writable: bool) -> Result<web_sys::IdbObjectStore, Error> {
+        let mode = if is_writable {
+            web_sys::IdbTransactionMode::Readwrite
+        } else {
+            web_sys::IdbTransactionMode::Readonly
+        };
+        self.db
+            .transaction_with_str_and_mode(&self.name, mode)
+            .and_then(|transaction| transaction.object_store(&self.name))
+            .map_err(js_error)
+    }
+
+    fn parse(key: &str, value: JsValue) -> Result<Option<T>, Error> {
+        let Some(value) = value.as_string() else {
+            return Ok(None);
+        };
+        serde_json::from_str(&value)
+            .map(Some)
+            .with_context(|_| ParseSnafu {
+                key: key.to_string(),
+                value,
+            })
+    }
+
+    /// Retrieve the value stored under `key`.
+    pub async fn get(&self, key: &str) -> Result<Option<T>, Error> {
+        let request = self
+            .object_store(false)?
+            .get(&JsValue::from_str(key))
+            .map_err(js_error)?;
+        Self::parse(key, complete(&request).await?)
+    }
+
+    /// Store `value` under `key`, replacing any value already there.
+    pub async fn put(&self, key: &str, value: &T) -> Result<(), Error> {
+        let value = serde_json::to_string(value).with_context(|_| SerializeSnafu {
+            key: key.to_string(),
+        })?;
+        let request = self
+            .object_store(true)?
+            .put_with_key(&JsValue::from_str(&value), &JsValue::from_str(key))
+            .map_err(js_error)?;
+        complete(&request).await?;
+        Ok(())
+    }
+
+    /// Remove the value stored under `key`, if any.
+    pub async fn delete(&self, key: &str) -> Result<(), Error> {
+        let request = self
+            .object_store(true)?
+            .delete(&JsValue::from_str(key))
+            .map_err(js_error)?;
+        complete(&request).await?;
+        Ok(())
+    }
+
+    /// Remove every value in the store.
+    pub async fn clear(&self) -> Result<(), Error> {
+        let request = self.object_store(true)?.clear().map_err(js_error)?;
+        complete(&request).await?;
+        Ok(())
+    }
+
+    /// Returns every key and value in the store, in key order.
+    pub async fn entries(&self) -> Result<Vec<(String, T)>, Error> {
+        let store = self.object_store(false)?;
+        let keys = store.get_all_keys().map_err(js_error)?;
+        let values = store.get_all().map_err(js_error)?;
+        let keys: js_sys::Array = complete(&keys).await?.unchecked_into();
+        let values: js_sys::Array = complete(&values).await?.unchecked_into();
+        let mut entries = vec![];
+        for (key, value) in keys.iter().zip(values.iter()) {
+            let Some(key) = key.as_string() else {
+                continue;
+            };
+            if let Some(value) = Self::parse(&key, value)? {
+                entries.push((key, value));
+            }
+        }
+        Ok(entries)
+    }
+}
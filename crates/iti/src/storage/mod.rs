@@ -0,0 +1,469 @@
+//! Browser localStorage and sessionStorage abstraction.
+//!
+//! Values are stored as JSON. Items in [`StorageArea::Local`] outlive the
+//! tab, items in [`StorageArea::Session`] are cleared when it closes.
+//! Items stored with [`set_item_with_ttl`] are dropped once they expire.
+//! Larger data belongs in IndexedDB; see [`idb`].
+//!
+//! [`persisted_proxy`] keeps a reactive value in localStorage, saving it on
+//! every change. [`Key`] gives stored values a type and a namespace.
+//! [`watch`] follows changes made to localStorage by other tabs, and
+//! [`versioned`] migrates stored values as their schema evolves.
+
+pub mod idb;
+pub mod versioned;
+
+use std::{borrow::Cow, cell::RefCell, marker::PhantomData, rc::Rc};
+
+use mogwai::prelude::Proxy;
+use snafu::{prelude::*, OptionExt, ResultExt};
+use wasm_bindgen::{prelude::Closure, JsCast, JsValue};
+
+use crate::util::queue::Queue;
+
+/// All storage errors.
+#[derive(Debug, Snafu)]
+pub enum Error {
+    #[snafu(display("DOM API unavailable"))]
+    Dom,
+    #[snafu(display("No {area} storage"))]
+    Storage { area: StorageArea },
+    #[snafu(display("Could not search"))]
+    Search,
+    #[snafu(display("Error parsing storage value for key:'{key}' value:'{value}': {source}"))]
+    Parse {
+        key: String,
+        value: String,
+        source: serde_json::Error,
+    },
+    #[snafu(display("Error serializing storage value for key:'{key}': {source}"))]
+    Serialize {
+        key: String,
+        source: serde_json::Error,
+    },
+    #[snafu(display("Cannot store value for key:'{key}'"))]
+    Store { key: String },
+    #[snafu(display(
+        "Stored value for key:'{key}' is version {version}, newer than the supported {supported}"
+    ))]
+    NewerVersion {
+        key: String,
+        version: u32,
+        supported: u32,
+    },
+    #[snafu(display("No migration for key:'{key}' from version {from}"))]
+    MissingMigration { key: String, from: u32 },
+}
+
+/// A browser storage area.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum StorageArea {
+    /// `localStorage`, kept across sessions.
+    #[default]
+    Local,
+    /// `sessionStorage`, kept until the tab is closed.
+    Session,
+}
+
+impl std::fmt::Display for StorageArea {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(match self {
+            StorageArea::Local => "local",
+            StorageArea::Session => "session",
+        })
+    }
+}
+
+fn get_storage(area: StorageArea) -> Result<web_sys::Storage, Error> {
+    let window = web_sys::window().context(DomSnafu)?;
+    let storage = match area {
+        StorageArea::Local => window.local_storage(),
+        StorageArea::Session => window.session_storage(),
+    };
+    storage
+        .ok()
+        .context(StorageSnafu { area })?
+        .context(StorageSnafu { area })
+}
+
+/// Retrieve a JSON-deserialized value from localStorage.
+pub fn get_item<T: serde::de::DeserializeOwned>(key: impl AsRef<str>) -> Result<Option<T>, Error> {
+    get_item_in(StorageArea::Local, key)
+}
+
+/// Serialize a value to JSON and store it in localStorage.
+pub fn set_item(key: impl AsRef<str>, value: &impl serde::Serialize) -> Result<(), Error> {
+    set_item_in(StorageArea::Local, key, value)
+}
+
+/// Retrieve a JSON-deserialized value from sessionStorage.
+pub fn get_session_item<T: serde::de::DeserializeOwned>(
+    key: impl AsRef<str>,
+) -> Result<Option<T>, Error> {
+    get_item_in(StorageArea::Session, key)
+}
+
+/// Serialize a value to JSON and store it in sessionStorage.
+pub fn set_session_item(key: impl AsRef<str>, value: &impl serde::Serialize) -> Result<(), Error> {
+    set_item_in(StorageArea::Session, key, value)
+}
+
+/// Retrieve a JSON-deserialized value from `area`.
+pub fn get_item_in<T: serde::de::DeserializeOwned>(
+    area: StorageArea,
+    key: impl AsRef<str>,
+) -> Result<Option<T>, Error> {
+    let storage = get_storage(area)?;
+    let Some(string) = storage.get_item(key.as_ref()).ok().context(SearchSnafu)? else {
+        return Ok(None);
+    };
+    let value = decode(&string).with_context(|_| ParseSnafu {
+        key: key.as_ref().to_string(),
+        value: string,
+    })?;
+    if value.is_none() {
+        // Expired, so clean it up.
+        let _ = storage.remove_item(key.as_ref());
+    }
+    Ok(value)
+}
+
+/// Field of an entry stored with a TTL holding its expiry time, in
+/// milliseconds since the epoch.
+const EXPIRES_AT: &str = "$iti_expires_at";
+
+/// Field of an entry stored with a TTL holding its value.
+const VALUE: &str = "$iti_value";
+
+/// Parse a stored value, returning `None` if it has expired.
+fn decode<T: serde::de::DeserializeOwned>(string: &str) -> Result<Option<T>, serde_json::Error> {
+    let json: serde_json::Value = serde_json::from_str(string)?;
+    let expiring = json
+        .as_object()
+        .filter(|object| object.len() == 2)
+        .and_then(|object| Some((object.get(EXPIRES_AT)?.as_f64()?, object.get(VALUE)?)));
+    let json = match expiring {
+        Some((expires_at, _)) if expires_at <= js_sys::Date::now() => return Ok(None),
+        Some((_, value)) => value.clone(),
+        None => json,
+    };
+    serde_json::from_value(json).map(Some)
+}
+
+/// Serialize a value to JSON and store it in localStorage until `ttl` has
+/// passed.
+///
+/// After that [`get_item`] returns `None` and removes it.
+pub fn set_item_with_ttl(
+    key: impl AsRef<str>,
+    value: &impl serde::Serialize,
+    ttl: std::time::Duration,
+) -> Result<(), Error> {
+    let value = serde_json::to_value(value).with_context(|_| SerializeSnafu {
+        key: key.as_ref().to_string(),
+    })?;
+    let expires_at = js_sys::Date::now() + ttl.as_millis() as f64;
+    let mut entry = serde_json::Map::new();
+    entry.insert(EXPIRES_AT.to_string(), expires_at.into());
+    entry.insert(VALUE.to_string(), value);
+    set_item(key, &entry)
+}
+
+/// Serialize a value to JSON and store it in `area`.
+pub fn set_item_in(
+    area: StorageArea,
+    key: impl AsRef<str>,
+    value: &impl serde::Serialize,
+) -> Result<(), Error> {
+    let storage = get_storage(area)?;
+    let value = serde_json::to_string(value).with_context(|_| SerializeSnafu {
+        key: key.as_ref().to_string(),
+    })?;
+    storage
+        .set_item(key.as_ref(), &value)
+        .ok()
+        .with_context(|| StoreSnafu {
+            key: key.as_ref().to_string(),
+        })
+}
+
+/// A typed, namespaced storage key.
+///
+/// The stored key is `"{namespace}:{name}"`, so keys from different
+/// crates or pages don't collide, and every read and write through the
+/// key is checked against `T`:
+///
+/// ```ignore
+/// const SELECTED_ITEM: Key<usize> = Key::new("my-app", "selected-item");
+/// SELECTED_ITEM.set(&3)?;
+/// let index: Option<usize> = SELECTED_ITEM.get()?;
+/// ```
+pub struct Key<T> {
+    namespace: &'static str,
+    name: Cow<'static, str>,
+    _value: PhantomData<fn() -> T>,
+}
+
+impl<T> Clone for Key<T> {
+    fn clone(&self) -> Self {
+        Self {
+            namespace: self.namespace,
+            name: self.name.clone(),
+            _value: PhantomData,
+        }
+    }
+}
+
+impl<T> std::fmt::Debug for Key<T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "Key({self})")
+    }
+}
+
+impl<T> std::fmt::Display for Key<T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}:{}", self.namespace, self.name)
+    }
+}
+
+impl<T> Key<T> {
+    pub const fn new(namespace: &'static str, name: &'static str) -> Self {
+        Self {
+            namespace,
+            name: Cow::Borrowed(name),
+            _value: PhantomData,
+        }
+    }
+
+    /// Create a key whose name is only known at runtime.
+    pub fn with_name(namespace: &'static str, name: impl Into<String>) -> Self {
+        Self {
+            namespace,
+            name: Cow::Owned(name.into()),
+            _value: PhantomData,
+        }
+    }
+
+    pub fn namespace(&self) -> &str {
+        self.namespace
+    }
+
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+}
+
+impl<T: serde::Serialize + serde::de::DeserializeOwned> Key<T> {
+    /// Retrieve the value from localStorage.
+    pub fn get(&self) -> Result<Option<T>, Error> {
+        get_item(self.to_string())
+    }
+
+    /// Store `value` in localStorage.
+    pub fn set(&self, value: &T) -> Result<(), Error> {
+        set_item(self.to_string(), value)
+    }
+
+    /// Store `value` in localStorage until `ttl` has passed; see
+    /// [`set_item_with_ttl`].
+    pub fn set_with_ttl(&self, value: &T, ttl: std::time::Duration) -> Result<(), Error> {
+        set_item_with_ttl(self.to_string(), value, ttl)
+    }
+
+    /// Move a value stored under the un-namespaced `old_key` to this key,
+    /// unless this key already holds one.
+    ///
+    /// Keeps values saved before a key was namespaced. Does nothing once
+    /// `old_key` is gone.
+    pub fn migrate_from(&self, old_key: &str) -> Result<(), Error> {
+        let Some(value) = get_item::<T>(old_key)? else {
+            return Ok(());
+        };
+        if self.get()?.is_none() {
+            self.set(&value)?;
+        }
+        let _ = get_storage(StorageArea::Local)?.remove_item(old_key);
+        Ok(())
+    }
+
+    /// Retrieve the value from `area`.
+    pub fn get_in(&self, area: StorageArea) -> Result<Option<T>, Error> {
+        get_item_in(area, self.to_string())
+    }
+
+    /// Store `value` in `area`.
+    pub fn set_in(&self, area: StorageArea, value: &T) -> Result<(), Error> {
+        set_item_in(area, self.to_string(), value)
+    }
+
+    /// Follow changes to the value made in other tabs; see [`watch`].
+    pub fn watch(&self) -> Watch<T> {
+        watch(self.to_string())
+    }
+
+    /// Returns a [`Persisted`] proxy of the value; see [`persisted_proxy`].
+    pub fn persisted(&self, default: T) -> Persisted<T>
+    where
+        T: 'static,
+    {
+        persisted_proxy(self.to_string(), default)
+    }
+}
+
+/// A [`Proxy`] that is saved to localStorage whenever it changes.
+///
+/// Created with [`persisted_proxy`].
+pub struct Persisted<T> {
+    key: String,
+    proxy: Proxy<T>,
+}
+
+/// Returns a [`Persisted`] proxy holding the value stored under `key`, or
+/// `default` if there is none (or it can't be read).
+///
+/// Every [`Persisted::set`] and [`Persisted::modify`] writes the value
+/// back, so it survives reloads.
+pub fn persisted_proxy<T: serde::Serialize + serde::de::DeserializeOwned + 'static>(
+    key: impl Into<String>,
+    default: T,
+) -> Persisted<T> {
+    let key = key.into();
+    let value = get_item(&key)
+        .inspect_err(|e| log::warn!("could not read '{key}': {e}"))
+        .ok()
+        .flatten()
+        .unwrap_or(default);
+    Persisted {
+        key,
+        proxy: Proxy::new(value),
+    }
+}
+
+impl<T> std::ops::Deref for Persisted<T> {
+    type Target = T;
+
+    fn deref(&self) -> &T {
+        &self.proxy
+    }
+}
+
+impl<T: serde::Serialize + 'static> Persisted<T> {
+    pub fn key(&self) -> &str {
+        &self.key
+    }
+
+    /// Write the value to localStorage.
+    ///
+    /// [`Persisted::set`] and [`Persisted::modify`] do this themselves.
+    pub fn save(&self) -> Result<(), Error> {
+        set_item(&self.key, &*self.proxy)
+    }
+
+    fn save_or_warn(&self) {
+        if let Err(e) = self.save() {
+            log::warn!("could not store '{}': {e}", self.key);
+        }
+    }
+
+    pub fn set(&mut self, value: T) {
+        self.proxy.set(value);
+        self.save_or_warn();
+    }
+
+    pub fn modify(&mut self, f: impl FnOnce(&mut T)) {
+        self.proxy.modify(f);
+        self.save_or_warn();
+    }
+
+    /// Returns the proxy, for binding in `rsx!`.
+    ///
+    /// ## Note
+    /// Changes made through the proxy itself are not saved until the next
+    /// [`Persisted::save`], `set` or `modify`.
+    pub fn get_proxy_mut(&mut self) -> &mut Proxy<T> {
+        &mut self.proxy
+    }
+}
+
+/// Changes to a localStorage item made by other tabs.
+///
+/// Created with [`watch`]. Stops listening when dropped.
+pub struct Watch<T> {
+    key: String,
+    /// New values of the key, `None` if it was removed.
+    queue: Rc<RefCell<Queue<Option<String>>>>,
+    callback: Closure<dyn FnMut(JsValue)>,
+    _value: PhantomData<fn() -> T>,
+}
+
+/// Follow changes to the item stored under `key` in localStorage.
+///
+/// Browsers only report changes made by other tabs and windows of the same
+/// site, which makes this a way to keep several open tabs in sync, e.g. on
+/// the color mode or who is signed in.
+pub fn watch<T: serde::de::DeserializeOwned>(key: impl Into<String>) -> Watch<T> {
+    let key = key.into();
+    let queue = Queue::new();
+    let callback = Closure::<dyn FnMut(JsValue)>::new({
+        let key = key.clone();
+        let queue = queue.clone();
+        move |ev: JsValue| {
+            let Ok(ev) = ev.dyn_into::<web_sys::StorageEvent>() else {
+                return;
+            };
+            let local = get_storage(StorageArea::Local).ok();
+            if ev.storage_area() != local {
+                return;
+            }
+            match ev.key() {
+                Some(changed) if changed == key => queue.borrow_mut().push(ev.new_value()),
+                Some(_) => {}
+                // The storage was cleared.
+                None => queue.borrow_mut().push(None),
+            }
+        }
+    });
+    if let Some(window) = web_sys::window() {
+        let _ =
+            window.add_event_listener_with_callback("storage", callback.as_ref().unchecked_ref());
+    }
+    Watch {
+        key,
+        queue,
+        callback,
+        _value: PhantomData,
+    }
+}
+
+impl<T: serde::de::DeserializeOwned> Watch<T> {
+    pub fn key(&self) -> &str {
+        &self.key
+    }
+
+    /// Await the next change, returning the new value, or `None` if the
+    /// item was removed (or has already expired).
+    ///
+    /// New values that can't be parsed as a `T` are logged and skipped.
+    pub async fn step(&mut self) -> Option<T> {
+        loop {
+            let Some(value) = Queue::next(&self.queue).await else {
+                return None;
+            };
+            match decode(&value) {
+                Ok(value) => return value,
+                Err(e) => log::warn!("could not parse '{}' from another tab: {e}", self.key),
+            }
+        }
+    }
+}
+
+impl<T> Drop for Watch<T> {
+    fn drop(&mut self) {
+        if let Some(window) = web_sys::window() {
+            let _ = window.remove_event_listener_with_callback(
+                "storage",
+                self.callback.as_ref().unchecked_ref(),
+            );
+        }
+    }
+}
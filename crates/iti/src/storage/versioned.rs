@@ -0,0 +1,131 @@
+//! Stored values with a schema version.
+//!
+//! A struct saved by one release of an app may not parse in the next one
+//! once its fields change, and would silently fall back to its default.
+//! [`Versioned`] stores a schema version next to the value and runs the
+//! registered migrations, one version at a time, to bring old values up to
+//! date:
+//!
+//! ```ignore
+//! // Version 1 renamed `width` to `sidebar_width`.
+//! let layout = Versioned::<Layout>::new("my-app:layout", 1).with_migration(0, |mut json| {
+//!     if let Some(width) = json.get_mut("width").map(serde_json::Value::take) {
+//!         json["sidebar_width"] = width;
+//!     }
+//!     json
+//! });
+//! let saved = layout.get()?;
+//! ```
+//!
+//! Values stored without a version, e.g. with [`set_item`], count as
+//! version 0.
+
+use std::{collections::HashMap, marker::PhantomData};
+
+use serde_json::Value;
+use snafu::prelude::*;
+
+use super::{
+    get_item, set_item, Error, MissingMigrationSnafu, NewerVersionSnafu, ParseSnafu, SerializeSnafu,
+};
+
+/// Field of a versioned entry holding its schema version.
+const VERSION: &str = "$iti_version";
+
+type Migration = Box<dyn Fn(Value) -> Value>;
+
+/// A localStorage item of type `T` at schema version `version`.
+pub struct Versioned<T> {
+    key: String,
+    version: u32,
+    /// Migrations by the version they migrate from.
+    migrations: HashMap<u32, Migration>,
+    _value: PhantomData<fn() -> T>,
+}
+
+impl<T: serde::Serialize + serde::de::DeserializeOwned> Versioned<T> {
+    /// Create a versioned item under `key`, whose current schema is
+    /// `version`.
+    pub fn new(key: impl Into<String>, version: u32) -> Self {
+        Self {
+            key: key.into(),
+            version,
+            migrations: HashMap::new(),
+            _value: PhantomData,
+        }
+    }
+
+    /// Register `migrate` to turn the JSON of a version `from` value into
+    /// the JSON of a version `from + 1` value.
+    pub fn with_migration(mut self, from: u32, migrate: impl Fn(Value) -> Value + 'static) -> Self {
+        self.migrations.insert(from, Box::new(migrate));
+        self
+    }
+
+    pub fn key(&self) -> &str {
+        &self.key
+    }
+
+    pub fn version(&self) -> u32 {
+        self.version
+    }
+
+    /// Retrieve the value, migrating it to the current version first.
+    ///
+    /// A migrated value is stored back at the current version. Errs if the
+    /// stored value is newer than the current version, or if a migration it
+    /// needs was not registered.
+    pub fn get(&self) -> Result<Option<T>, Error> {
+        let Some(json) = get_item::<Value>(&self.key)? else {
+            return Ok(None);
+        };
+        let versioned = json
+            .as_object()
+            .filter(|object| object.len() == 2)
+            .and_then(|object| {
+                let version = u32::try_from(object.get(VERSION)?.as_u64()?).ok()?;
+                Some((version, object.get(super::VALUE)?.clone()))
+            });
+        let (mut version, mut json) = versioned.unwrap_or((0, json));
+        ensure!(
+            version <= self.version,
+            NewerVersionSnafu {
+                key: &self.key,
+                version,
+                supported: self.version,
+            }
+        );
+
+        let is_migrated = version < self.version;
+        while version < self.version {
+            let migrate = self
+                .migrations
+                .get(&version)
+                .context(MissingMigrationSnafu {
+                    key: &self.key,
+                    from: version,
+                })?;
+            json = migrate(json);
+            version += 1;
+        }
+
+        let value = serde_json::from_value(json.clone()).with_context(|_| ParseSnafu {
+            key: &self.key,
+            value: json.to_string(),
+        })?;
+        if is_migrated {
+            self.set(&value)?;
+        }
+        Ok(Some(value))
+    }
+
+    /// Store `value` at the current version.
+    pub fn set(&self, value: &T) -> Result<(), Error> {
+        let value =
+            serde_json::to_value(value).with_context(|_| SerializeSnafu { key: &self.key })?;
+        let mut entry = serde_json::Map::new();
+        entry.insert(VERSION.to_string(), self.version.into());
+        entry.insert(super::VALUE.to_string(), value);
+        set_item(&self.key, &entry)
+    }
+}
@@ -0,0 +1,446 @@
+//! Light and dark color modes.
+//!
+//! [`ColorModeManager`] sets Bootstrap 5.3's `data-bs-theme` attribute on
+//! `<html>`, which switches iti.css between its light and dark palettes.
+//! The chosen [`ColorMode`] is persisted with [`crate::storage`], and
+//! [`ColorMode::Auto`] follows the system's `prefers-color-scheme`.
+//!
+//! For a ready-made control see
+//! [`ColorModeToggle`](crate::components::color_mode::ColorModeToggle).
+//!
+//! The palette itself can be changed at runtime with [`set_token`], or all
+//! at once with [`ThemeOverrides`], and [`set_direction`] lays the page out
+//! right-to-left.
+
+use mogwai::prelude::*;
+use wasm_bindgen::JsCast;
+
+use crate::util::queue::QueuedListener;
+
+/// Storage key used by [`ColorModeManager::new`].
+pub const STORAGE_KEY: &str = "iti-color-mode";
+
+const PREFERS_DARK: &str = "(prefers-color-scheme: dark)";
+
+/// A color mode preference.
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub enum ColorMode {
+    Light,
+    Dark,
+    /// Follow the system's `prefers-color-scheme`.
+    #[default]
+    Auto,
+}
+
+impl std::fmt::Display for ColorMode {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl ColorMode {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            ColorMode::Light => "light",
+            ColorMode::Dark => "dark",
+            ColorMode::Auto => "auto",
+        }
+    }
+
+    fn from_str(s: &str) -> Option<Self> {
+        match s {
+            "light" => Some(ColorMode::Light),
+            "dark" => Some(ColorMode::Dark),
+            "auto" => Some(ColorMode::Auto),
+            _ => None,
+        }
+    }
+
+    /// Returns the mode after this one, cycling light → dark → auto.
+    pub fn next(&self) -> Self {
+        match self {
+            ColorMode::Light => ColorMode::Dark,
+            ColorMode::Dark => ColorMode::Auto,
+            ColorMode::Auto => ColorMode::Light,
+        }
+    }
+
+    /// Resolve [`ColorMode::Auto`] to [`ColorMode::Light`] or
+    /// [`ColorMode::Dark`] using the system preference.
+    pub fn resolve(&self) -> Self {
+        match self {
+            ColorMode::Auto => system_mode(),
+            mode => *mode,
+        }
+    }
+}
+
+fn prefers_dark_query() -> Option<web_sys::MediaQueryList> {
+    web_sys::window()?.match_media(PREFERS_DARK).ok()?
+}
+
+/// Returns the system color mode, either [`ColorMode::Light`] or
+/// [`ColorMode::Dark`].
+pub fn system_mode() -> ColorMode {
+    match prefers_dark_query() {
+        Some(query) if query.matches() => ColorMode::Dark,
+        _ => ColorMode::Light,
+    }
+}
+
+fn set_theme_attribute(mode: ColorMode) {
+    let Some(root) = web_sys::window()
+        .and_then(|w| w.document())
+        .and_then(|d| d.document_element())
+    else {
+        return;
+    };
+    let _ = root.set_attribute("data-bs-theme", mode.resolve().as_str());
+}
+
+/// Applies and persists the page's [`ColorMode`].
+///
+/// Creating a manager reads the stored mode (defaulting to
+/// [`ColorMode::Auto`]) and applies it immediately.
+pub struct ColorModeManager {
+    mode: Proxy<ColorMode>,
+    storage_key: Option<String>,
+    /// Changes of `prefers-color-scheme`, if the browser supports it.
+    scheme_changes: Option<QueuedListener<()>>,
+}
+
+impl Default for ColorModeManager {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl ColorModeManager {
+    /// Create a manager that persists the mode under [`STORAGE_KEY`].
+    pub fn new() -> Self {
+        Self::with_storage_key(Some(STORAGE_KEY))
+    }
+
+    /// Create a manager that persists the mode under `storage_key`, or not
+    /// at all if it is `None`.
+    pub fn with_storage_key(storage_key: Option<&str>) -> Self {
+        let stored = storage_key.and_then(|key| {
+            crate::storage::get_item::<String>(key)
+                .unwrap_or_else(|e| {
+                    log::warn!("could not read the color mode: {e}");
+                    None
+                })
+                .and_then(|s| ColorMode::from_str(&s))
+        });
+        let mode = stored.unwrap_or_default();
+        set_theme_attribute(mode);
+        Self {
+            mode: Proxy::new(mode),
+            storage_key: storage_key.map(str::to_string),
+            scheme_changes: prefers_dark_query()
+                .map(|query| QueuedListener::new(&query, &["change"], |_| Some(()))),
+        }
+    }
+
+    /// Returns the chosen mode, which may be [`ColorMode::Auto`].
+    pub fn mode(&self) -> ColorMode {
+        *self.mode
+    }
+
+    /// Returns the mode in effect, either [`ColorMode::Light`] or
+    /// [`ColorMode::Dark`].
+    pub fn resolved_mode(&self) -> ColorMode {
+        self.mode.resolve()
+    }
+
+    /// Apply `mode` to the page and persist it.
+    ///
+    /// The mode is applied even if persisting it fails.
+    pub fn set_mode(&mut self, mode: ColorMode) -> Result<(), crate::storage::Error> {
+        set_theme_attribute(mode);
+        self.mode.set(mode);
+        if let Some(key) = &self.storage_key {
+            crate::storage::set_item(key, &mode.as_str())?;
+        }
+        Ok(())
+    }
+
+    /// Returns the reactive mode, for binding in `rsx!`.
+    ///
+    /// ## Note
+    /// Change the mode with [`ColorModeManager::set_mode`], which also
+    /// applies and persists it.
+    pub fn get_proxy_mut(&mut self) -> &mut Proxy<ColorMode> {
+        &mut self.mode
+    }
+
+    /// Await a change of the system color scheme while the mode is
+    /// [`ColorMode::Auto`], re-apply it, and return the new resolved mode.
+    ///
+    /// Never resolves if the browser doesn't support `prefers-color-scheme`.
+    pub async fn step(&self) -> ColorMode {
+        let Some(scheme_changes) = &self.scheme_changes else {
+            return std::future::pending().await;
+        };
+        loop {
+            scheme_changes.next().await;
+            if *self.mode == ColorMode::Auto {
+                set_theme_attribute(ColorMode::Auto);
+                return self.resolved_mode();
+            }
+        }
+    }
+}
+
+/// A semantic CSS custom property from iti.css.
+///
+/// Components are styled through these, so overriding one reskins every
+/// component that uses it.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Token {
+    /// `--iti-bg`, the window background.
+    Bg,
+    /// `--iti-bg-light`, card bodies and input fields.
+    BgLight,
+    /// `--iti-bg-medium`, card headers and button faces.
+    BgMedium,
+    /// `--iti-bg-dark`, pressed surfaces.
+    BgDark,
+    /// `--iti-border-light`, the highlight edge of bevels.
+    BorderLight,
+    /// `--iti-border-dark`, the shadow edge of bevels.
+    BorderDark,
+    /// `--iti-text`.
+    Text,
+    /// `--iti-text-muted`.
+    TextMuted,
+    /// `--iti-primary`.
+    Primary,
+    /// `--iti-accent`.
+    Accent,
+    /// `--iti-selection`, the selected item background.
+    Selection,
+    /// `--iti-selection-text`.
+    SelectionText,
+    /// `--iti-highlight`, the hovered item background.
+    Highlight,
+    /// `--iti-highlight-text`.
+    HighlightText,
+    /// `--iti-secondary`.
+    Secondary,
+    /// `--iti-success`.
+    Success,
+    /// `--iti-danger`.
+    Danger,
+    /// `--iti-warning`.
+    Warning,
+    /// `--iti-info`.
+    Info,
+    /// `--iti-light`.
+    Light,
+    /// `--iti-dark`.
+    Dark,
+    /// `--iti-font-body`.
+    FontBody,
+    /// `--iti-font-ui`, the Chicago font used for UI chrome.
+    FontUi,
+    /// `--iti-font-editorial`.
+    FontEditorial,
+    /// Any other custom property, including the leading `--`,
+    /// e.g. `Token::Custom("--azul")`.
+    Custom(&'static str),
+}
+
+impl std::fmt::Display for Token {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl Token {
+    /// Returns the name of the custom property, e.g. `"--iti-primary"`.
+    pub fn as_str(&self) -> &str {
+        match self {
+            Token::Bg => "--iti-bg",
+            Token::BgLight => "--iti-bg-light",
+            Token::BgMedium => "--iti-bg-medium",
+            Token::BgDark => "--iti-bg-dark",
+            Token::BorderLight => "--iti-border-light",
+            Token::BorderDark => "--iti-border-dark",
+            Token::Text => "--iti-text",
+            Token::TextMuted => "--iti-text-muted",
+            Token::Primary => "--iti-primary",
+            Token::Accent => "--iti-accent",
+            Token::Selection => "--iti-selection",
+            Token::SelectionText => "--iti-selection-text",
+            Token::Highlight => "--iti-highlight",
+            Token::HighlightText => "--iti-highlight-text",
+            Token::Secondary => "--iti-secondary",
+            Token::Success => "--iti-success",
+            Token::Danger => "--iti-danger",
+            Token::Warning => "--iti-warning",
+            Token::Info => "--iti-info",
+            Token::Light => "--iti-light",
+            Token::Dark => "--iti-dark",
+            Token::FontBody => "--iti-font-body",
+            Token::FontUi => "--iti-font-ui",
+            Token::FontEditorial => "--iti-font-editorial",
+            Token::Custom(name) => name,
+        }
+    }
+}
+
+fn root_style() -> Option<web_sys::CssStyleDeclaration> {
+    let root = web_sys::window()?.document()?.document_element()?;
+    Some(root.dyn_into::<web_sys::HtmlElement>().ok()?.style())
+}
+
+/// Set `token` to `value` on `<html>`, overriding the stylesheet.
+///
+/// ## Note
+/// Inline values win over both color modes, so a token set here stays the
+/// same in light and dark mode. Use [`ThemeOverrides::to_css`] to override
+/// only one of them.
+pub fn set_token(token: Token, value: impl AsRef<str>) {
+    if let Some(style) = root_style() {
+        let _ = style.set_property(token.as_str(), value.as_ref());
+    }
+}
+
+/// Remove a value set with [`set_token`], restoring the stylesheet's.
+pub fn remove_token(token: Token) {
+    if let Some(style) = root_style() {
+        let _ = style.remove_property(token.as_str());
+    }
+}
+
+/// Returns the value of `token` in effect on `<html>`, if any.
+pub fn get_token(token: Token) -> Option<String> {
+    let window = web_sys::window()?;
+    let root = window.document()?.document_element()?;
+    let value = window
+        .get_computed_style(&root)
+        .ok()??
+        .get_property_value(token.as_str())
+        .ok()?;
+    let value = value.trim();
+    (!value.is_empty()).then(|| value.to_string())
+}
+
+/// A set of token values, applied together.
+///
+/// Build one at startup and [`apply`](ThemeOverrides::apply) it to reskin
+/// iti without a custom stylesheet:
+///
+/// ```ignore
+/// ThemeOverrides::default()
+///     .with(Token::Primary, "#0B5FFF")
+///     .with(Token::FontUi, "system-ui, sans-serif")
+///     .apply();
+/// ```
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct ThemeOverrides {
+    tokens: Vec<(Token, String)>,
+}
+
+impl ThemeOverrides {
+    /// Add or replace the value of `token`.
+    pub fn with(mut self, token: Token, value: impl Into<String>) -> Self {
+        self.set(token, value);
+        self
+    }
+
+    /// Add or replace the value of `token`.
+    pub fn set(&mut self, token: Token, value: impl Into<String>) {
+        let value = value.into();
+        if let Some((_, v)) = self.tokens.iter_mut().find(|(t, _)| *t == token) {
+            *v = value;
+        } else {
+            self.tokens.push((token, value));
+        }
+    }
+
+    /// Returns the value set for `token`, if any.
+    pub fn get(&self, token: Token) -> Option<&str> {
+        self.tokens
+            .iter()
+            .find_map(|(t, v)| (*t == token).then_some(v.as_str()))
+    }
+
+    /// Set every token with [`set_token`].
+    pub fn apply(&self) {
+        for (token, value) in self.tokens.iter() {
+            set_token(*token, value);
+        }
+    }
+
+    /// Remove every token set by [`ThemeOverrides::apply`].
+    pub fn unapply(&self) {
+        for (token, _) in self.tokens.iter() {
+            remove_token(*token);
+        }
+    }
+
+    /// Returns a CSS rule setting every token for `mode`, for use with
+    /// [`crate::assets::append_style`].
+    ///
+    /// [`ColorMode::Auto`] targets both modes. Append it after iti.css so
+    /// it takes precedence.
+    pub fn to_css(&self, mode: ColorMode) -> String {
+        let selector = match mode {
+            ColorMode::Auto => ":root",
+            ColorMode::Light => ":root:not([data-bs-theme=\"dark\"])",
+            ColorMode::Dark => ":root[data-bs-theme=\"dark\"]",
+        };
+        let mut css = format!("{selector} {{\n");
+        for (token, value) in self.tokens.iter() {
+            css.push_str(&format!("\t{token}: {value};\n"));
+        }
+        css.push_str("}\n");
+        css
+    }
+}
+
+/// Text direction of the page.
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub enum Direction {
+    #[default]
+    Ltr,
+    Rtl,
+}
+
+impl Direction {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Direction::Ltr => "ltr",
+            Direction::Rtl => "rtl",
+        }
+    }
+}
+
+/// Set the `dir` attribute of `<html>`, e.g. to [`Direction::Rtl`] for
+/// Arabic or Hebrew.
+///
+/// iti.css has no separate right-to-left stylesheet to load: its
+/// start/end utilities use logical properties and directional glyphs
+/// (arrows and chevrons) are mirrored under `dir="rtl"`.
+pub fn set_direction(direction: Direction) {
+    if let Some(root) = web_sys::window()
+        .and_then(|w| w.document())
+        .and_then(|d| d.document_element())
+    {
+        let _ = root.set_attribute("dir", direction.as_str());
+    }
+}
+
+/// Returns the direction set on `<html>`, defaulting to [`Direction::Ltr`].
+pub fn direction() -> Direction {
+    let dir = web_sys::window()
+        .and_then(|w| w.document())
+        .and_then(|d| d.document_element())
+        .and_then(|root| root.get_attribute("dir"));
+    match dir.as_deref() {
+        Some("rtl") => Direction::Rtl,
+        _ => Direction::Ltr,
+    }
+}
@@ -0,0 +1,134 @@
+//! Rate limiting for event listeners.
+//!
+//! Events like `input`, `resize` and `scroll` can fire many times a frame.
+//! [`debounced`] and [`throttled`] wrap a listener so a `step` loop only
+//! wakes for some of them. Both keep the event they are holding on to
+//! between steps, so their `step` can be raced like any other listener
+//! without losing it:
+//!
+//! ```ignore
+//! let input = debounced::<V>(self.input.listen("input"), 300);
+//! let ev = input.step().await;
+//! ```
+
+use std::cell::{Cell, RefCell};
+
+use futures_lite::FutureExt;
+use mogwai::{future::MogwaiFutureExt, prelude::*};
+
+use super::time::{now, wait_until};
+
+/// Await the next event from `listener`, or `None` once `deadline` has
+/// passed.
+async fn next_before<V: View>(listener: &V::EventListener, deadline: f64) -> Option<V::Event> {
+    listener
+        .next()
+        .map(Some)
+        .or(wait_until(deadline).map(|_| None))
+        .await
+}
+
+/// A listener that resolves once events stop arriving for a while.
+///
+/// Created with [`debounced`].
+pub struct Debounced<V: View> {
+    listener: V::EventListener,
+    millis: u64,
+    /// The last event of the burst in progress, if any.
+    pending: RefCell<Option<V::Event>>,
+    /// When the burst in progress is over, unless another event arrives.
+    deadline: Cell<f64>,
+}
+
+/// Wrap `listener` so a burst of events resolves once, with the last event
+/// of the burst, after `millis` milliseconds without another event.
+pub fn debounced<V: View>(listener: V::EventListener, millis: u64) -> Debounced<V> {
+    Debounced {
+        listener,
+        millis,
+        pending: RefCell::new(None),
+        deadline: Cell::new(0.0),
+    }
+}
+
+impl<V: View> Debounced<V> {
+    /// Await the last event of the next burst.
+    pub async fn step(&self) -> V::Event {
+        loop {
+            let has_pending = self.pending.borrow().is_some();
+            let ev = if has_pending {
+                next_before::<V>(&self.listener, self.deadline.get()).await
+            } else {
+                Some(self.listener.next().await)
+            };
+            match ev {
+                Some(ev) => {
+                    self.pending.replace(Some(ev));
+                    self.deadline.set(now() + self.millis as f64);
+                }
+                None => {
+                    if let Some(ev) = self.pending.take() {
+                        return ev;
+                    }
+                }
+            }
+        }
+    }
+}
+
+/// A listener that resolves at most once in any period.
+///
+/// Created with [`throttled`].
+pub struct Throttled<V: View> {
+    listener: V::EventListener,
+    millis: u64,
+    /// When the last event was returned, from `Date.now()`.
+    last: Cell<Option<f64>>,
+    /// The latest event held until the period is over, if any.
+    pending: RefCell<Option<V::Event>>,
+}
+
+/// Wrap `listener` so it resolves at most once every `millis`
+/// milliseconds.
+///
+/// An event that arrives sooner is held until the period is over, and
+/// replaced by any event after it, so the last event of a burst is never
+/// lost.
+pub fn throttled<V: View>(listener: V::EventListener, millis: u64) -> Throttled<V> {
+    Throttled {
+        listener,
+        millis,
+        last: Cell::new(None),
+        pending: RefCell::new(None),
+    }
+}
+
+impl<V: View> Throttled<V> {
+    /// Returns when the current period is over.
+    fn period_end(&self) -> f64 {
+        self.last
+            .get()
+            .map_or(0.0, |last| last + self.millis as f64)
+    }
+
+    /// Await the next event, waiting out the rest of the current period.
+    pub async fn step(&self) -> V::Event {
+        loop {
+            let has_pending = self.pending.borrow().is_some();
+            let ev = if has_pending {
+                next_before::<V>(&self.listener, self.period_end()).await
+            } else {
+                Some(self.listener.next().await)
+            };
+            if let Some(ev) = ev {
+                self.pending.replace(Some(ev));
+            }
+            if now() >= self.period_end() {
+                if let Some(ev) = self.pending.take() {
+                    self.last.set(Some(now()));
+                    return ev;
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,5 @@
+//! Small helpers that don't belong to any one component.
+
+pub mod events;
+pub(crate) mod queue;
+pub mod time;
@@ -0,0 +1,122 @@
+//! A queue filled by browser callbacks and drained by `step`.
+
+use std::{
+    cell::RefCell,
+    collections::VecDeque,
+    rc::Rc,
+    task::{Poll, Waker},
+};
+
+use wasm_bindgen::{prelude::Closure, JsCast, JsValue};
+
+/// Items pushed by a JS callback, waiting to be taken by an async `step`.
+///
+/// Shared between the callback and its component with `Rc<RefCell<_>>`.
+pub(crate) struct Queue<T> {
+    items: VecDeque<T>,
+    waker: Option<Waker>,
+}
+
+impl<T> Queue<T> {
+    pub(crate) fn new() -> Rc<RefCell<Self>> {
+        Rc::new(RefCell::new(Self {
+            items: VecDeque::new(),
+            waker: None,
+        }))
+    }
+
+    /// Add `item`, waking the task awaiting [`Queue::next`].
+    pub(crate) fn push(&mut self, item: T) {
+        self.items.push_back(item);
+        if let Some(waker) = self.waker.take() {
+            waker.wake();
+        }
+    }
+
+    /// Returns the number of items waiting to be taken.
+    pub(crate) fn len(&self) -> usize {
+        self.items.len()
+    }
+
+    /// Returns `true` if no items are waiting.
+    pub(crate) fn is_empty(&self) -> bool {
+        self.items.is_empty()
+    }
+
+    /// Await the next item in `queue`.
+    pub(crate) async fn next(queue: &RefCell<Self>) -> T {
+        std::future::poll_fn(|cx| {
+            let mut queue = queue.borrow_mut();
+            match queue.items.pop_front() {
+                Some(item) => Poll::Ready(item),
+                None => {
+                    queue.waker = Some(cx.waker().clone());
+                    Poll::Pending
+                }
+            }
+        })
+        .await
+    }
+}
+
+/// DOM event listeners that stay registered until dropped, queueing what
+/// they hear for an async `step` to take.
+///
+/// Unlike listening inside `step`, no event is missed while the component
+/// is busy between steps, or while its `step` has lost a race.
+pub(crate) struct QueuedListener<T> {
+    target: web_sys::EventTarget,
+    events: &'static [&'static str],
+    callback: Closure<dyn FnMut(JsValue)>,
+    queue: Rc<RefCell<Queue<T>>>,
+}
+
+impl<T: 'static> QueuedListener<T> {
+    /// Listen for each of `events` on `target`, queueing whatever `map`
+    /// returns for them. Events it returns `None` for are skipped.
+    pub(crate) fn new(
+        target: &web_sys::EventTarget,
+        events: &'static [&'static str],
+        mut map: impl FnMut(web_sys::Event) -> Option<T> + 'static,
+    ) -> Self {
+        let queue = Queue::new();
+        let callback = Closure::<dyn FnMut(JsValue)>::new({
+            let queue = queue.clone();
+            move |ev: JsValue| {
+                if let Some(item) = ev.dyn_into::<web_sys::Event>().ok().and_then(&mut map) {
+                    queue.borrow_mut().push(item);
+                }
+            }
+        });
+        for event in events {
+            let _ =
+                target.add_event_listener_with_callback(event, callback.as_ref().unchecked_ref());
+        }
+        Self {
+            target: target.clone(),
+            events,
+            callback,
+            queue,
+        }
+    }
+
+    /// Await the next queued item.
+    pub(crate) async fn next(&self) -> T {
+        Queue::next(&self.queue).await
+    }
+
+    /// Drop every queued item.
+    pub(crate) fn clear(&self) {
+        self.queue.borrow_mut().items.clear();
+    }
+}
+
+impl<T> Drop for QueuedListener<T> {
+    fn drop(&mut self) {
+        for event in self.events {
+            let _ = self
+                .target
+                .remove_event_listener_with_callback(event, self.callback.as_ref().unchecked_ref());
+        }
+    }
+}
@@ -0,0 +1,102 @@
+//! Timers for `step` loops.
+//!
+//! A `wait_millis` future made inside `step` starts over every time the
+//! step is raced against another event and loses. [`Interval`] and
+//! [`Timeout`] keep their schedule between steps instead, so they can be
+//! raced again and again:
+//!
+//! ```ignore
+//! let tick = self.timer.step().map(|_| Action::Tick);
+//! let click = self.button.step().map(|_| Action::Click);
+//! match tick.or(click).await {
+//!     ..
+//! }
+//! ```
+
+use std::cell::Cell;
+
+/// Returns the current time in milliseconds, from `Date.now()`.
+pub(crate) fn now() -> f64 {
+    js_sys::Date::now()
+}
+
+/// Waits out the time left until `deadline`.
+pub(crate) async fn wait_until(deadline: f64) {
+    let remaining = deadline - now();
+    if remaining > 0.0 {
+        mogwai::time::wait_millis(remaining.ceil() as u64).await;
+    }
+}
+
+/// Ticks every `millis` milliseconds.
+///
+/// Ticks that are missed, e.g. while nothing awaits the interval, are not
+/// made up for: the next tick comes at once and the schedule restarts
+/// from it.
+pub struct Interval {
+    millis: u64,
+    /// When the next tick is due.
+    deadline: Cell<f64>,
+}
+
+impl Interval {
+    /// Create an interval whose first tick is `millis` from now.
+    pub fn new(millis: u64) -> Self {
+        Self {
+            millis,
+            deadline: Cell::new(now() + millis as f64),
+        }
+    }
+
+    pub fn millis(&self) -> u64 {
+        self.millis
+    }
+
+    /// Restart the schedule, with the next tick `millis` from now.
+    pub fn reset(&self) {
+        self.deadline.set(now() + self.millis as f64);
+    }
+
+    /// Await the next tick.
+    pub async fn step(&self) {
+        wait_until(self.deadline.get()).await;
+        let deadline = self.deadline.get() + self.millis as f64;
+        self.deadline.set(deadline.max(now()));
+    }
+}
+
+/// A deadline `millis` milliseconds after it was created or reset.
+///
+/// Unlike a `wait_millis` future, [`Timeout::step`] can be awaited any
+/// number of times and always resolves at the same moment.
+pub struct Timeout {
+    deadline: f64,
+}
+
+impl Timeout {
+    pub fn new(millis: u64) -> Self {
+        Self {
+            deadline: now() + millis as f64,
+        }
+    }
+
+    /// Move the deadline to `millis` from now.
+    pub fn reset(&mut self, millis: u64) {
+        self.deadline = now() + millis as f64;
+    }
+
+    /// Returns the milliseconds left, or zero once the deadline has passed.
+    pub fn remaining_millis(&self) -> u64 {
+        (self.deadline - now()).max(0.0).ceil() as u64
+    }
+
+    /// Returns `true` once the deadline has passed.
+    pub fn is_elapsed(&self) -> bool {
+        now() >= self.deadline
+    }
+
+    /// Await the deadline. Resolves at once if it has passed.
+    pub async fn step(&self) {
+        wait_until(self.deadline).await;
+    }
+}
@@ -4,6 +4,14 @@
 //! `window.document.head`. All errors (e.g. no `document`, no `<head>`)
 //! are surfaced via `unwrap_throw()` so they show up in the browser
 //! console as a clear JavaScript exception rather than a generic panic.
+//!
+//! ## Content-Security-Policy
+//! Under a CSP without `'unsafe-inline'` in `style-src`, injected
+//! `<style>` elements are blocked unless they carry the page's nonce. Use
+//! [`append_style_with_nonce`] and [`append_link_with_nonce`] there.
+//! CSS text is set with `textContent`, never `innerHTML`, so no Trusted
+//! Types policy is needed. Blob URLs from [`crate::blob`] additionally
+//! need `blob:` in `font-src`/`img-src`.
 
 use js_sys::wasm_bindgen::{JsCast, UnwrapThrowExt};
 use web_sys::{HtmlLinkElement, HtmlScriptElement, HtmlStyleElement};
@@ -17,27 +25,75 @@ fn head() -> web_sys::HtmlHeadElement {
         .unwrap_throw()
 }
 
-/// Append a `<link rel="stylesheet">` element to `<head>`.
-pub fn append_link(href: &str) {
+fn create_link(href: &str, nonce: Option<&str>, integrity: Option<&str>) -> HtmlLinkElement {
     let document = web_sys::window().unwrap_throw().document().unwrap_throw();
     let link = document
         .create_element("link")
         .unwrap_throw()
         .unchecked_into::<HtmlLinkElement>();
+    if let Some(nonce) = nonce {
+        link.set_attribute("nonce", nonce).unwrap_throw();
+    }
+    if let Some(integrity) = integrity {
+        link.set_integrity(integrity);
+        link.set_cross_origin(Some("anonymous"));
+    }
     link.set_rel("stylesheet");
     link.set_href(href);
-    head().append_child(&link).unwrap_throw();
+    link
 }
 
-/// Append a `<style>` element with the given CSS text to `<head>`.
-pub fn append_style(css: &str) {
+fn create_style(css: &str, nonce: Option<&str>) -> HtmlStyleElement {
     let document = web_sys::window().unwrap_throw().document().unwrap_throw();
     let style = document
         .create_element("style")
         .unwrap_throw()
         .unchecked_into::<HtmlStyleElement>();
+    if let Some(nonce) = nonce {
+        style.set_attribute("nonce", nonce).unwrap_throw();
+    }
     style.set_text_content(Some(css));
-    head().append_child(&style).unwrap_throw();
+    style
+}
+
+/// Append a `<link rel="stylesheet">` element to `<head>`.
+pub fn append_link(href: &str) {
+    head()
+        .append_child(&create_link(href, None, None))
+        .unwrap_throw();
+}
+
+/// Append a `<link rel="stylesheet">` element with a CSP `nonce`
+/// attribute to `<head>`.
+pub fn append_link_with_nonce(href: &str, nonce: &str) {
+    head()
+        .append_child(&create_link(href, Some(nonce), None))
+        .unwrap_throw();
+}
+
+/// Append a `<link rel="stylesheet">` element to `<head>` that the browser
+/// checks against a Subresource Integrity hash (e.g. `"sha384-..."`).
+///
+/// Also sets `crossorigin="anonymous"`, without which browsers refuse
+/// cross-origin stylesheets with an `integrity` attribute. `nonce` is set
+/// as with [`append_link_with_nonce`].
+pub fn append_link_with_integrity(href: &str, integrity: &str, nonce: Option<&str>) {
+    head()
+        .append_child(&create_link(href, nonce, Some(integrity)))
+        .unwrap_throw();
+}
+
+/// Append a `<style>` element with the given CSS text to `<head>`.
+pub fn append_style(css: &str) {
+    head().append_child(&create_style(css, None)).unwrap_throw();
+}
+
+/// Append a `<style>` element with the given CSS text and a CSP `nonce`
+/// attribute to `<head>`.
+pub fn append_style_with_nonce(css: &str, nonce: &str) {
+    head()
+        .append_child(&create_style(css, Some(nonce)))
+        .unwrap_throw();
 }
 
 /// Append a `<script>` element to `<head>`.
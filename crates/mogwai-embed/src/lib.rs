@@ -13,7 +13,8 @@
 //! Three layers of API:
 //!
 //! - [`head::append_link`], [`head::append_style`], [`head::append_script`]
-//!   for raw DOM injection into `<head>`.
+//!   for raw DOM injection into `<head>`, with `_with_nonce` variants of
+//!   the first two for pages with a strict Content-Security-Policy.
 //! - [`blob::create_blob_url`] for one-off Blob URL creation.
 //! - [`blob::AssetRegistry`] for memoised, keyed Blob URL caching across
 //!   many lookups (e.g. one Blob URL per icon glyph).